@@ -10,6 +10,7 @@ use aries_vcx::{
 use aries_vcx_core::{
     self,
     anoncreds::{base_anoncreds::BaseAnonCreds, credx_anoncreds::IndyCredxAnonCreds},
+    anoncreds_registry::{indy::IndyAnoncredsRegistry, router::AnoncredsRegistryRouter},
     ledger::indy_vdr_ledger::DefaultIndyLedgerRead,
     wallet::indy::{
         wallet::{create_and_open_wallet, wallet_configure_issuer},
@@ -175,8 +176,11 @@ impl Agent {
             wallet.clone(),
             connections.clone(),
         ));
+        let anoncreds_registry = Arc::new(AnoncredsRegistryRouter::new().register(Arc::new(
+            IndyAnoncredsRegistry::new(ledger_read.clone(), ledger_write.clone()),
+        )));
         let verifier = Arc::new(ServiceVerifier::new(
-            ledger_read.clone(),
+            anoncreds_registry,
             anoncreds,
             wallet.clone(),
             connections.clone(),
@@ -17,8 +17,8 @@ use aries_vcx::{
     },
 };
 use aries_vcx_core::{
-    anoncreds::credx_anoncreds::IndyCredxAnonCreds, ledger::indy_vdr_ledger::DefaultIndyLedgerRead,
-    wallet::indy::IndySdkWallet,
+    anoncreds::credx_anoncreds::IndyCredxAnonCreds,
+    anoncreds_registry::router::AnoncredsRegistryRouter, wallet::indy::IndySdkWallet,
 };
 
 use super::connection::ServiceConnections;
@@ -44,7 +44,7 @@ impl VerifierWrapper {
 }
 
 pub struct ServiceVerifier {
-    ledger_read: Arc<DefaultIndyLedgerRead>,
+    anoncreds_registry: Arc<AnoncredsRegistryRouter>,
     anoncreds: IndyCredxAnonCreds,
     wallet: Arc<IndySdkWallet>,
     verifiers: ObjectCache<VerifierWrapper>,
@@ -53,7 +53,7 @@ pub struct ServiceVerifier {
 
 impl ServiceVerifier {
     pub fn new(
-        ledger_read: Arc<DefaultIndyLedgerRead>,
+        anoncreds_registry: Arc<AnoncredsRegistryRouter>,
         anoncreds: IndyCredxAnonCreds,
         wallet: Arc<IndySdkWallet>,
         service_connections: Arc<ServiceConnections>,
@@ -61,7 +61,7 @@ impl ServiceVerifier {
         Self {
             service_connections,
             verifiers: ObjectCache::new("verifiers"),
-            ledger_read,
+            anoncreds_registry,
             anoncreds,
             wallet,
         }
@@ -119,7 +119,11 @@ impl ServiceVerifier {
         });
 
         let message = verifier
-            .verify_presentation(self.ledger_read.as_ref(), &self.anoncreds, presentation)
+            .verify_presentation(
+                self.anoncreds_registry.as_ref(),
+                &self.anoncreds,
+                presentation,
+            )
             .await?;
         send_closure(message).await?;
         self.verifiers
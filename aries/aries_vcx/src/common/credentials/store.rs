@@ -0,0 +1,451 @@
+use std::collections::{BTreeSet, HashMap};
+
+use anoncreds_types::data_types::messages::cred_selection::RetrievedCredentialInfo;
+use aries_vcx_core::{
+    anoncreds::base_anoncreds::BaseAnonCreds,
+    errors::error::AriesVcxCoreErrorKind,
+    ledger::base_ledger::AnoncredsLedgerRead,
+    wallet::{
+        base_wallet::{
+            record::Record, record_category::RecordCategory, search_filter::SearchFilter, wql::Wql,
+            BaseWallet,
+        },
+        record_tags::{RecordTag, RecordTags},
+    },
+};
+use futures::future::join_all;
+use time::OffsetDateTime;
+
+use crate::{errors::error::prelude::*, handlers::issuance::holder::Holder};
+
+const CONNECTION_ID_TAG: &str = "connection_id";
+const THREAD_ID_TAG: &str = "thread_id";
+const REVOKED_TAG: &str = "revoked";
+
+/// Holder side details of a credential, which are not part of the credential itself.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CredentialMetadata {
+    /// Unix time the credential was first recorded in the store, after the holder stored it in
+    /// the wallet. AnonCreds credentials carry no issuance date.
+    pub stored_at: Option<u64>,
+    /// Connection the credential was issued over.
+    pub connection_id: Option<String>,
+    /// Thread of the issuance protocol the credential was received in.
+    pub thread_id: Option<String>,
+    #[serde(default)]
+    pub labels: BTreeSet<String>,
+    /// Last known revocation status, as of [`RevocationStatus::checked_at`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revocation_status: Option<RevocationStatus>,
+}
+
+impl CredentialMetadata {
+    fn to_tags(&self) -> RecordTags {
+        let mut tags = RecordTags::default();
+        if let Some(connection_id) = &self.connection_id {
+            tags.add(RecordTag::new(CONNECTION_ID_TAG, connection_id));
+        }
+        if let Some(thread_id) = &self.thread_id {
+            tags.add(RecordTag::new(THREAD_ID_TAG, thread_id));
+        }
+        if let Some(status) = &self.revocation_status {
+            tags.add(RecordTag::new(REVOKED_TAG, &status.revoked.to_string()));
+        }
+        for label in self.labels.iter() {
+            tags.add(RecordTag::new(&label_tag_name(label), "1"));
+        }
+        tags
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevocationStatus {
+    pub revoked: bool,
+    /// Unix time the status was checked against the ledger at.
+    pub checked_at: u64,
+}
+
+/// Credential stored in the wallet together with its metadata.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoredCredential {
+    pub info: RetrievedCredentialInfo,
+    pub metadata: CredentialMetadata,
+}
+
+/// Criteria for searching stored credentials. All of the criteria set must be met.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CredentialQuery {
+    schema_id: Option<String>,
+    cred_def_id: Option<String>,
+    issuer_did: Option<String>,
+    attributes: Vec<(String, String)>,
+    connection_id: Option<String>,
+    label: Option<String>,
+}
+
+impl CredentialQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn schema_id(mut self, schema_id: &str) -> Self {
+        self.schema_id = Some(schema_id.to_owned());
+        self
+    }
+
+    pub fn cred_def_id(mut self, cred_def_id: &str) -> Self {
+        self.cred_def_id = Some(cred_def_id.to_owned());
+        self
+    }
+
+    pub fn issuer_did(mut self, issuer_did: &str) -> Self {
+        self.issuer_did = Some(issuer_did.to_owned());
+        self
+    }
+
+    /// Matches credentials with the given raw attribute value. Attribute names are compared
+    /// case insensitively and ignoring spaces, as in presentation requests.
+    pub fn attribute(mut self, name: &str, value: &str) -> Self {
+        self.attributes.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    pub fn connection_id(mut self, connection_id: &str) -> Self {
+        self.connection_id = Some(connection_id.to_owned());
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    /// Filter over the tags of the credential records, as understood by
+    /// [`BaseAnonCreds::prover_get_credentials`].
+    fn credential_filter(&self) -> Option<String> {
+        let mut queries = Vec::new();
+        if let Some(schema_id) = &self.schema_id {
+            queries.push(Wql::eq("schema_id", schema_id));
+        }
+        if let Some(cred_def_id) = &self.cred_def_id {
+            queries.push(Wql::eq("cred_def_id", cred_def_id));
+        }
+        if let Some(issuer_did) = &self.issuer_did {
+            queries.push(Wql::eq("issuer_did", issuer_did));
+        }
+        for (name, value) in self.attributes.iter() {
+            let name = name.replace(' ', "").to_lowercase();
+            queries.push(Wql::eq(&format!("attr::{name}::value"), value));
+        }
+        (!queries.is_empty()).then(|| Wql::and(queries).to_string())
+    }
+
+    /// Filter over the tags of the metadata records, if any metadata criteria are set.
+    fn metadata_filter(&self) -> Option<Wql> {
+        let mut queries = Vec::new();
+        if let Some(connection_id) = &self.connection_id {
+            queries.push(Wql::eq(CONNECTION_ID_TAG, connection_id));
+        }
+        if let Some(label) = &self.label {
+            queries.push(Wql::eq(&label_tag_name(label), "1"));
+        }
+        (!queries.is_empty()).then(|| Wql::and(queries))
+    }
+}
+
+/// Holder credential store, keeping track of the metadata of the credentials in the wallet.
+/// Credentials themselves are stored and retrieved through [`BaseAnonCreds`].
+pub struct HolderCredentialStore<'a, W, A> {
+    wallet: &'a W,
+    anoncreds: &'a A,
+}
+
+impl<'a, W, A> HolderCredentialStore<'a, W, A>
+where
+    W: BaseWallet,
+    A: BaseAnonCreds,
+{
+    pub fn new(wallet: &'a W, anoncreds: &'a A) -> Self {
+        Self { wallet, anoncreds }
+    }
+
+    /// Records the metadata of the credential the holder stored, overwriting the origin of any
+    /// previously recorded metadata while keeping its labels and the time it was first recorded.
+    pub async fn record_credential(
+        &self,
+        holder: &Holder,
+        connection_id: Option<&str>,
+    ) -> VcxResult<CredentialMetadata> {
+        let cred_id = holder.get_cred_id()?;
+        let recorded = self.get_metadata(&cred_id).await?;
+        let metadata = CredentialMetadata {
+            stored_at: recorded.stored_at.or_else(|| Some(now())),
+            connection_id: connection_id.map(ToOwned::to_owned),
+            thread_id: Some(holder.get_thread_id()?),
+            ..recorded
+        };
+        self.save_metadata(&cred_id, &metadata).await?;
+        Ok(metadata)
+    }
+
+    pub async fn get(&self, cred_id: &str) -> VcxResult<StoredCredential> {
+        let info = self
+            .anoncreds
+            .prover_get_credential(self.wallet, &cred_id.to_owned())
+            .await?;
+        let metadata = self.get_metadata(cred_id).await?;
+        Ok(StoredCredential { info, metadata })
+    }
+
+    /// Returns the metadata of the credential, which is empty if none was recorded.
+    pub async fn get_metadata(&self, cred_id: &str) -> VcxResult<CredentialMetadata> {
+        match self
+            .wallet
+            .get_record(RecordCategory::CredMetadata, cred_id)
+            .await
+        {
+            Ok(record) => Ok(serde_json::from_str(record.value())?),
+            Err(err) if err.kind() == AriesVcxCoreErrorKind::WalletRecordNotFound => {
+                Ok(CredentialMetadata::default())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Searches the credentials and their metadata, each with a single query. Credentials without
+    /// recorded metadata only match queries without metadata criteria.
+    pub async fn search(&self, query: &CredentialQuery) -> VcxResult<Vec<StoredCredential>> {
+        let metadata_filter = query.metadata_filter();
+        let metadata_required = metadata_filter.is_some();
+        let mut metadata = self
+            .wallet
+            .search_record(
+                RecordCategory::CredMetadata,
+                metadata_filter.map(SearchFilter::from),
+            )
+            .await?
+            .into_iter()
+            .map(|record| -> VcxResult<(String, CredentialMetadata)> {
+                Ok((
+                    record.name().to_owned(),
+                    serde_json::from_str(record.value())?,
+                ))
+            })
+            .collect::<VcxResult<HashMap<_, _>>>()?;
+        if metadata_required && metadata.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let filter = query.credential_filter();
+        let credentials = self
+            .anoncreds
+            .prover_get_credentials(self.wallet, filter.as_deref())
+            .await?;
+        Ok(credentials
+            .into_iter()
+            .filter_map(|info| {
+                let metadata = match metadata.remove(&info.referent) {
+                    Some(metadata) => metadata,
+                    None if metadata_required => return None,
+                    None => CredentialMetadata::default(),
+                };
+                Some(StoredCredential { info, metadata })
+            })
+            .collect())
+    }
+
+    pub async fn add_label(&self, cred_id: &str, label: &str) -> VcxResult<()> {
+        let mut metadata = self.get_metadata(cred_id).await?;
+        if metadata.labels.insert(label.to_owned()) {
+            self.save_metadata(cred_id, &metadata).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn remove_label(&self, cred_id: &str, label: &str) -> VcxResult<()> {
+        let mut metadata = self.get_metadata(cred_id).await?;
+        if metadata.labels.remove(label) {
+            self.save_metadata(cred_id, &metadata).await?;
+        }
+        Ok(())
+    }
+
+    /// Checks the revocation status of the holders' credentials against the ledger and caches it
+    /// in their metadata. Returns the outcome by credential id, so a failed check does not hide
+    /// the others; credentials which are not revocable and holders without a credential are
+    /// skipped.
+    pub async fn refresh_revocation_status(
+        &self,
+        ledger: &impl AnoncredsLedgerRead,
+        holders: &[Holder],
+    ) -> HashMap<String, VcxResult<bool>> {
+        let statuses = join_all(holders.iter().filter_map(|holder| {
+            let cred_id = holder
+                .get_cred_id()
+                .map_err(|err| warn!("refresh_revocation_status >> skipping holder: {err}"))
+                .ok()?;
+            Some(async move {
+                let status = self
+                    .refresh_holder_revocation_status(ledger, holder, &cred_id)
+                    .await;
+                (cred_id, status)
+            })
+        }))
+        .await;
+
+        statuses
+            .into_iter()
+            .filter_map(|(cred_id, status)| status.transpose().map(|status| (cred_id, status)))
+            .collect()
+    }
+
+    async fn refresh_holder_revocation_status(
+        &self,
+        ledger: &impl AnoncredsLedgerRead,
+        holder: &Holder,
+        cred_id: &str,
+    ) -> VcxResult<Option<bool>> {
+        if !holder.is_revokable(ledger).await? {
+            return Ok(None);
+        }
+        let revoked = holder
+            .is_revoked(self.wallet, ledger, self.anoncreds)
+            .await?;
+
+        let mut metadata = self.get_metadata(cred_id).await?;
+        metadata.revocation_status = Some(RevocationStatus {
+            revoked,
+            checked_at: now(),
+        });
+        self.save_metadata(cred_id, &metadata).await?;
+        Ok(Some(revoked))
+    }
+
+    /// Deletes the credential along with its metadata.
+    pub async fn delete(&self, cred_id: &str) -> VcxResult<()> {
+        self.anoncreds
+            .prover_delete_credential(self.wallet, &cred_id.to_owned())
+            .await?;
+        match self
+            .wallet
+            .delete_record(RecordCategory::CredMetadata, cred_id)
+            .await
+        {
+            Err(err) if err.kind() != AriesVcxCoreErrorKind::WalletRecordNotFound => {
+                Err(err.into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn save_metadata(&self, cred_id: &str, metadata: &CredentialMetadata) -> VcxResult<()> {
+        let value = serde_json::to_string(metadata)?;
+        match self
+            .wallet
+            .get_record(RecordCategory::CredMetadata, cred_id)
+            .await
+        {
+            Ok(_) => {
+                self.wallet
+                    .update_record_value(RecordCategory::CredMetadata, cred_id, &value)
+                    .await?;
+                self.wallet
+                    .update_record_tags(RecordCategory::CredMetadata, cred_id, metadata.to_tags())
+                    .await?;
+            }
+            Err(err) if err.kind() == AriesVcxCoreErrorKind::WalletRecordNotFound => {
+                let record = Record::builder()
+                    .category(RecordCategory::CredMetadata)
+                    .name(cred_id.to_owned())
+                    .value(value)
+                    .tags(metadata.to_tags())
+                    .build();
+                self.wallet.add_record(record).await?;
+            }
+            Err(err) => return Err(err.into()),
+        }
+        Ok(())
+    }
+}
+
+fn label_tag_name(label: &str) -> String {
+    format!("label::{label}")
+}
+
+fn now() -> u64 {
+    OffsetDateTime::now_utc().unix_timestamp() as u64
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_credential_query_filter() {
+        assert_eq!(None, CredentialQuery::new().credential_filter());
+
+        let query = CredentialQuery::new()
+            .schema_id("V4SGRU86Z58d6TV7PBUe6f:2:gvt:1.0")
+            .issuer_did("V4SGRU86Z58d6TV7PBUe6f")
+            .attribute("First Name", "Alice");
+        let filter: serde_json::Value =
+            serde_json::from_str(&query.credential_filter().unwrap()).unwrap();
+
+        assert_eq!(
+            json!({"$and": [
+                {"schema_id": "V4SGRU86Z58d6TV7PBUe6f:2:gvt:1.0"},
+                {"issuer_did": "V4SGRU86Z58d6TV7PBUe6f"},
+                {"attr::firstname::value": "Alice"}
+            ]}),
+            filter
+        );
+    }
+
+    #[test]
+    fn test_credential_query_metadata_filter() {
+        assert_eq!(
+            None,
+            CredentialQuery::new()
+                .schema_id("V4SGRU86Z58d6TV7PBUe6f:2:gvt:1.0")
+                .metadata_filter()
+        );
+
+        let filter = CredentialQuery::new()
+            .connection_id("conn")
+            .label("work")
+            .metadata_filter()
+            .unwrap();
+        assert_eq!(
+            json!({"$and": [
+                {"connection_id": "conn"},
+                {"label::work": "1"}
+            ]}),
+            filter.to_value()
+        );
+    }
+
+    #[test]
+    fn test_credential_metadata_tags() {
+        let metadata = CredentialMetadata {
+            thread_id: Some("thread".to_owned()),
+            labels: BTreeSet::from(["work".to_owned()]),
+            revocation_status: Some(RevocationStatus {
+                revoked: true,
+                checked_at: 1,
+            }),
+            ..Default::default()
+        };
+
+        let tags = metadata.to_tags().into_inner();
+        assert_eq!(
+            vec![
+                RecordTag::new(THREAD_ID_TAG, "thread"),
+                RecordTag::new(REVOKED_TAG, "true"),
+                RecordTag::new("label::work", "1"),
+            ],
+            tags
+        );
+    }
+}
@@ -4,3 +4,5 @@ pub mod ledger;
 pub mod primitives;
 pub mod proofs;
 pub mod signing;
+#[cfg(feature = "askar_wallet")]
+pub mod sub_wallet;
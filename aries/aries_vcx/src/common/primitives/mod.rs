@@ -1,3 +1,5 @@
 pub mod credential_definition;
 pub mod credential_schema;
 pub mod revocation_registry;
+pub mod revocation_registry_rotation;
+pub mod tails;
@@ -1,8 +1,10 @@
 use std::path::Path;
 
 use anoncreds_types::data_types::{
-    identifiers::cred_def_id::CredentialDefinitionId,
-    ledger::rev_reg_def::RevocationRegistryDefinition,
+    identifiers::{
+        cred_def_id::CredentialDefinitionId, rev_reg_def_id::RevocationRegistryDefinitionId,
+    },
+    ledger::{rev_reg_def::RevocationRegistryDefinition, rev_status_list::RevocationStatusList},
 };
 use aries_vcx_core::{
     anoncreds::base_anoncreds::BaseAnonCreds,
@@ -11,8 +13,9 @@ use aries_vcx_core::{
     wallet::base_wallet::BaseWallet,
 };
 use did_parser::Did;
+use url::Url;
 
-use super::credential_definition::PublicEntityStateType;
+use super::{credential_definition::PublicEntityStateType, tails::upload_tails_file};
 use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
 
 #[derive(Clone, Deserialize, Debug, Serialize)]
@@ -97,6 +100,14 @@ impl RevocationRegistry {
         self.tails_dir.clone()
     }
 
+    /// Uploads the tails file to a tails server, returning the location to publish the
+    /// definition with.
+    pub async fn upload_tails_file(&self, tails_server_url: &Url) -> VcxResult<Url> {
+        let tails_hash = &self.rev_reg_def.value.tails_hash;
+        let tails_file = Path::new(&self.tails_dir).join(tails_hash);
+        upload_tails_file(tails_server_url, &self.rev_reg_id, &tails_file, tails_hash).await
+    }
+
     pub fn was_rev_reg_def_published(&self) -> bool {
         self.rev_reg_def_state == PublicEntityStateType::Published
     }
@@ -252,6 +263,57 @@ impl RevocationRegistry {
             .map_err(|err| err.into())
     }
 
+    /// Revokes the credentials locally, so that they are published in a single ledger write by
+    /// [Self::publish_local_revocations].
+    pub async fn revoke_credentials_local(
+        &self,
+        wallet: &impl BaseWallet,
+        anoncreds: &impl BaseAnonCreds,
+        ledger: &impl AnoncredsLedgerRead,
+        cred_rev_ids: &[u32],
+    ) -> VcxResult<()> {
+        for cred_rev_id in cred_rev_ids {
+            self.revoke_credential_local(wallet, anoncreds, ledger, *cred_rev_id)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Revokes the credentials on top of the revocation status list currently on the ledger and
+    /// publishes the updated list, which is returned.
+    pub async fn revoke_credentials_and_publish(
+        &self,
+        wallet: &impl BaseWallet,
+        anoncreds: &impl BaseAnonCreds,
+        ledger_read: &impl AnoncredsLedgerRead,
+        ledger_write: &impl AnoncredsLedgerWrite,
+        cred_rev_ids: &[u32],
+    ) -> VcxResult<RevocationStatusList> {
+        let rev_reg_id: RevocationRegistryDefinitionId = self.rev_reg_id.to_owned().try_into()?;
+        let (prev_rev_status_list, _) = ledger_read
+            .get_rev_status_list(&rev_reg_id, None, Some(&self.rev_reg_def))
+            .await?;
+
+        let rev_status_list = anoncreds
+            .issuer_update_revocation_status_list(
+                wallet,
+                &rev_reg_id,
+                prev_rev_status_list.clone(),
+                cred_rev_ids,
+            )
+            .await?;
+        ledger_write
+            .publish_rev_status_list(
+                wallet,
+                &prev_rev_status_list,
+                &rev_status_list,
+                &self.issuer_did,
+            )
+            .await?;
+
+        Ok(rev_status_list)
+    }
+
     pub async fn publish_local_revocations(
         &self,
         wallet: &impl BaseWallet,
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+
+use anoncreds_types::data_types::identifiers::cred_def_id::CredentialDefinitionId;
+use aries_vcx_core::{
+    anoncreds::base_anoncreds::BaseAnonCreds,
+    ledger::base_ledger::{AnoncredsLedgerRead, AnoncredsLedgerWrite},
+    wallet::base_wallet::BaseWallet,
+};
+use did_parser::Did;
+use url::Url;
+
+use super::revocation_registry::RevocationRegistry;
+use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
+
+/// Revocation registry and index a credential was issued with.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CredentialRevocationLocation {
+    pub rev_reg_id: String,
+    pub cred_rev_id: u32,
+}
+
+/// Revocation registries of a credential definition, the last of which is active. A new registry
+/// is created and published once the number of credentials issued in the active one reaches
+/// the rotation threshold, which defaults to the registry size. Issuance counts are read from
+/// the anoncreds backend, which tracks them in the wallet.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RevocationRegistryRotation {
+    issuer_did: Did,
+    cred_def_id: CredentialDefinitionId,
+    tails_dir: String,
+    tails_server_url: Url,
+    max_creds: u32,
+    rotation_threshold: u32,
+    registries: Vec<RevocationRegistry>,
+}
+
+impl RevocationRegistryRotation {
+    pub fn new(
+        issuer_did: Did,
+        cred_def_id: CredentialDefinitionId,
+        tails_dir: &str,
+        tails_server_url: Url,
+        max_creds: u32,
+    ) -> Self {
+        Self {
+            issuer_did,
+            cred_def_id,
+            tails_dir: tails_dir.to_owned(),
+            tails_server_url,
+            max_creds,
+            rotation_threshold: max_creds,
+            registries: Vec::new(),
+        }
+    }
+
+    /// Rotates registries once `threshold` credentials have been issued, capped at the registry
+    /// size.
+    pub fn with_rotation_threshold(mut self, threshold: u32) -> Self {
+        self.rotation_threshold = threshold.min(self.max_creds);
+        self
+    }
+
+    /// Adopts a published registry as the active one.
+    pub fn with_active_registry(mut self, registry: RevocationRegistry) -> Self {
+        self.registries.push(registry);
+        self
+    }
+
+    pub fn active_registry(&self) -> Option<&RevocationRegistry> {
+        self.registries.last()
+    }
+
+    pub fn registries(&self) -> impl Iterator<Item = &RevocationRegistry> {
+        self.registries.iter()
+    }
+
+    pub async fn needs_rotation(
+        &self,
+        wallet: &impl BaseWallet,
+        anoncreds: &impl BaseAnonCreds,
+    ) -> VcxResult<bool> {
+        let Some(active) = self.registries.last() else {
+            return Ok(true);
+        };
+        let issued = anoncreds
+            .issuer_get_rev_reg_issued_count(wallet, &active.rev_reg_id.to_owned().try_into()?)
+            .await?;
+        Ok(issued >= self.rotation_threshold)
+    }
+
+    /// Returns the registry to issue the next credential with, creating, uploading the tails
+    /// file of and publishing a new registry first if needed.
+    pub async fn prepare_issuance(
+        &mut self,
+        wallet: &impl BaseWallet,
+        anoncreds: &impl BaseAnonCreds,
+        ledger_write: &impl AnoncredsLedgerWrite,
+    ) -> VcxResult<&RevocationRegistry> {
+        if self.needs_rotation(wallet, anoncreds).await? {
+            self.rotate(wallet, anoncreds, ledger_write).await?;
+        }
+        self.active_registry().ok_or_else(|| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidState,
+                "No active revocation registry",
+            )
+        })
+    }
+
+    async fn rotate(
+        &mut self,
+        wallet: &impl BaseWallet,
+        anoncreds: &impl BaseAnonCreds,
+        ledger_write: &impl AnoncredsLedgerWrite,
+    ) -> VcxResult<()> {
+        let tag = self.registries.last().map_or(1, |active| active.tag + 1);
+        info!(
+            "RevocationRegistryRotation::rotate >>> creating revocation registry with tag {tag} \
+             for cred_def_id: {}",
+            self.cred_def_id
+        );
+
+        let mut registry = RevocationRegistry::create(
+            wallet,
+            anoncreds,
+            &self.issuer_did,
+            &self.cred_def_id,
+            &self.tails_dir,
+            self.max_creds,
+            tag,
+        )
+        .await?;
+        let tails_location = registry.upload_tails_file(&self.tails_server_url).await?;
+        registry
+            .publish_revocation_primitives(wallet, ledger_write, tails_location.as_str())
+            .await?;
+
+        self.registries.push(registry);
+        Ok(())
+    }
+
+    /// Revokes the credentials with one ledger write per affected registry. The registries are
+    /// revoked and published one after the other, without rollback: if one of them fails, the
+    /// error is returned and the registries before it, in order of their ids, stay revoked and
+    /// published.
+    pub async fn revoke_credentials(
+        &self,
+        wallet: &impl BaseWallet,
+        anoncreds: &impl BaseAnonCreds,
+        ledger_read: &impl AnoncredsLedgerRead,
+        ledger_write: &impl AnoncredsLedgerWrite,
+        locations: &[CredentialRevocationLocation],
+    ) -> VcxResult<()> {
+        for (rev_reg_id, cred_rev_ids) in group_by_registry(locations) {
+            let registry = self
+                .registries()
+                .find(|registry| registry.rev_reg_id == rev_reg_id)
+                .ok_or_else(|| {
+                    AriesVcxError::from_msg(
+                        AriesVcxErrorKind::InvalidRevocationDetails,
+                        format!("Unknown revocation registry {rev_reg_id}"),
+                    )
+                })?;
+            registry
+                .revoke_credentials_local(wallet, anoncreds, ledger_read, &cred_rev_ids)
+                .await?;
+            registry
+                .publish_local_revocations(wallet, anoncreds, ledger_write, &self.issuer_did)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+fn group_by_registry(locations: &[CredentialRevocationLocation]) -> BTreeMap<&str, Vec<u32>> {
+    let mut grouped: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+    for location in locations {
+        grouped
+            .entry(&location.rev_reg_id)
+            .or_default()
+            .push(location.cred_rev_id);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(rev_reg_id: &str, cred_rev_id: u32) -> CredentialRevocationLocation {
+        CredentialRevocationLocation {
+            rev_reg_id: rev_reg_id.to_owned(),
+            cred_rev_id,
+        }
+    }
+
+    #[test]
+    fn test_group_by_registry() {
+        let locations = vec![location("b", 1), location("a", 2), location("b", 3)];
+
+        let grouped = group_by_registry(&locations);
+
+        assert_eq!(vec![2], grouped["a"]);
+        assert_eq!(vec![1, 3], grouped["b"]);
+    }
+
+    #[test]
+    fn test_rotation_without_registry() {
+        let rotation = RevocationRegistryRotation::new(
+            Did::parse("V4SGRU86Z58d6TV7PBUe6f".to_owned()).unwrap(),
+            CredentialDefinitionId::new_unchecked("V4SGRU86Z58d6TV7PBUe6f:3:CL:1:tag"),
+            "/tmp/tails",
+            Url::parse("http://localhost:6543").unwrap(),
+            10,
+        )
+        .with_rotation_threshold(20);
+
+        assert_eq!(10, rotation.rotation_threshold);
+        assert!(rotation.active_registry().is_none());
+    }
+}
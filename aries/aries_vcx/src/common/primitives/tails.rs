@@ -0,0 +1,201 @@
+use std::path::{Path, PathBuf};
+
+use anoncreds_types::data_types::ledger::rev_reg_def::RevocationRegistryDefinition;
+use sha2::{Digest, Sha256};
+use shared::http_client::{get_bytes, put_bytes};
+use url::Url;
+
+use crate::errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult};
+
+fn io_error(err: std::io::Error) -> AriesVcxError {
+    AriesVcxError::from_msg(AriesVcxErrorKind::IOError, err.to_string())
+}
+
+/// Computes the hash of a tails file, as referenced by revocation registry definitions.
+pub fn tails_hash(tails: &[u8]) -> String {
+    bs58::encode(Sha256::digest(tails)).into_string()
+}
+
+pub fn verify_tails_hash(tails: &[u8], expected_hash: &str) -> VcxResult<()> {
+    let hash = tails_hash(tails);
+    if hash != expected_hash {
+        return Err(AriesVcxError::from_msg(
+            AriesVcxErrorKind::InvalidRevocationDetails,
+            format!("Tails file hash {hash} does not match the expected hash {expected_hash}"),
+        ));
+    }
+    Ok(())
+}
+
+/// Location of the tails file of the registry on a tails server.
+pub fn tails_file_url(tails_server_url: &Url, rev_reg_id: &str) -> VcxResult<Url> {
+    let mut url = tails_server_url.clone();
+    url.path_segments_mut()
+        .map_err(|_| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidUrl,
+                format!("Tails server URL {tails_server_url} cannot be a base"),
+            )
+        })?
+        .pop_if_empty()
+        .push(rev_reg_id);
+    Ok(url)
+}
+
+/// Uploads the tails file to the tails server after checking it against `tails_hash`, and
+/// returns the location it can be downloaded from.
+pub async fn upload_tails_file(
+    tails_server_url: &Url,
+    rev_reg_id: &str,
+    tails_file: &Path,
+    tails_hash: &str,
+) -> VcxResult<Url> {
+    trace!(
+        "upload_tails_file >>> tails_server_url: {tails_server_url}, rev_reg_id: {rev_reg_id}, \
+         tails_file: {tails_file:?}"
+    );
+    let tails = tokio::fs::read(tails_file).await.map_err(io_error)?;
+    verify_tails_hash(&tails, tails_hash)?;
+
+    let url = tails_file_url(tails_server_url, rev_reg_id)?;
+    put_bytes(tails, url.clone()).await?;
+    Ok(url)
+}
+
+/// Local cache of the tails files downloaded by a holder, with one directory per revocation
+/// registry.
+#[derive(Debug, Clone)]
+pub struct TailsCache {
+    dir: PathBuf,
+}
+
+impl TailsCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Directory holding the tails file of the registry, to be used as `tails_dir` when creating
+    /// revocation states.
+    pub fn tails_dir(&self, rev_reg_id: &str) -> PathBuf {
+        let dir_name: String = rev_reg_id
+            .chars()
+            .map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' => c,
+                _ => '_',
+            })
+            .collect();
+        self.dir.join(dir_name)
+    }
+
+    pub async fn get_or_download(
+        &self,
+        rev_reg_def: &RevocationRegistryDefinition,
+    ) -> VcxResult<PathBuf> {
+        self.get_or_download_from(
+            &rev_reg_def.id.0,
+            &rev_reg_def.value.tails_location,
+            &rev_reg_def.value.tails_hash,
+        )
+        .await
+    }
+
+    /// Returns the tails directory of the registry, downloading the tails file from
+    /// `tails_location` first unless it is cached already. Downloaded files are only cached
+    /// if they match `tails_hash`.
+    pub async fn get_or_download_from(
+        &self,
+        rev_reg_id: &str,
+        tails_location: &str,
+        tails_hash: &str,
+    ) -> VcxResult<PathBuf> {
+        let tails_dir = self.tails_dir(rev_reg_id);
+        let tails_file = tails_dir.join(tails_hash);
+        if tokio::fs::metadata(&tails_file)
+            .await
+            .is_ok_and(|metadata| metadata.is_file())
+        {
+            return Ok(tails_dir);
+        }
+
+        let url = Url::parse(tails_location).map_err(|err| {
+            AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidUrl,
+                format!("Invalid tails location {tails_location}: {err}"),
+            )
+        })?;
+        debug!("TailsCache::get_or_download_from >> downloading tails file from {url}");
+        let tails = get_bytes(url).await?;
+        verify_tails_hash(&tails, tails_hash)?;
+
+        tokio::fs::create_dir_all(&tails_dir)
+            .await
+            .map_err(io_error)?;
+        let partial_file = tails_dir.join(format!("{tails_hash}.partial"));
+        tokio::fs::write(&partial_file, tails)
+            .await
+            .map_err(io_error)?;
+        tokio::fs::rename(&partial_file, &tails_file)
+            .await
+            .map_err(io_error)?;
+
+        Ok(tails_dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, net::TcpListener};
+
+    use super::*;
+
+    #[test]
+    fn test_tails_file_url_escapes_rev_reg_id() {
+        let url = tails_file_url(
+            &Url::parse("http://localhost:6543/tails/").unwrap(),
+            "did:indy:sovrin:F72i3Y3Q4i466efjYJYCHM/anoncreds/v0/REV_REG_DEF/1/tag",
+        )
+        .unwrap();
+        assert_eq!(
+            "http://localhost:6543/tails/did:indy:sovrin:F72i3Y3Q4i466efjYJYCHM%2Fanoncreds%2Fv0%2FREV_REG_DEF%2F1%2Ftag",
+            url.as_str()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upload_and_download_tails_file() {
+        let root = std::env::temp_dir().join(format!("tails_{}", uuid::Uuid::new_v4()));
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let server_url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        tokio::spawn(tails_server::serve(listener, root.join("server")));
+
+        let rev_reg_id = "V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:1:tag:CL_ACCUM:tag";
+        let tails = b"tails content".to_vec();
+        let hash = tails_hash(&tails);
+        let tails_file = root.join(&hash);
+        fs::write(&tails_file, &tails).unwrap();
+
+        let err = upload_tails_file(&server_url, rev_reg_id, &tails_file, "wrong")
+            .await
+            .unwrap_err();
+        assert_eq!(AriesVcxErrorKind::InvalidRevocationDetails, err.kind());
+
+        let location = upload_tails_file(&server_url, rev_reg_id, &tails_file, &hash)
+            .await
+            .unwrap();
+
+        let cache = TailsCache::new(root.join("cache"));
+        let err = cache
+            .get_or_download_from(rev_reg_id, location.as_str(), "wrong")
+            .await
+            .unwrap_err();
+        assert_eq!(AriesVcxErrorKind::InvalidRevocationDetails, err.kind());
+
+        let tails_dir = cache
+            .get_or_download_from(rev_reg_id, location.as_str(), &hash)
+            .await
+            .unwrap();
+        assert_eq!(tails, fs::read(tails_dir.join(&hash)).unwrap());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
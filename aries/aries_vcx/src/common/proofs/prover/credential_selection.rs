@@ -0,0 +1,672 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+};
+
+use anoncreds_types::data_types::messages::{
+    cred_selection::{RetrievedCredentialForReferent, RetrievedCredentials, SelectedCredentials},
+    pres_request::{PredicateInfo, PredicateTypes, PresentationRequest},
+};
+
+use crate::errors::error::prelude::*;
+
+/// How to pick among several credentials satisfying the same referent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Keeps the order in which the wallet returned the candidates.
+    #[default]
+    First,
+    /// Prefers the credential with the latest issuance time, as registered with
+    /// [CredentialSelector::issuance_times]. Credentials of unknown issuance time come last.
+    Newest,
+}
+
+/// Why a candidate credential was not selected for a referent.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// The credential is known to be revoked.
+    Revoked,
+    /// Non-revocation was requested, but no tails directory is known for the credential's
+    /// revocation registry.
+    MissingTailsDir,
+    /// The credential has no value for the predicate attribute, or it is not an integer.
+    MissingPredicateValue,
+    /// The credential's value does not satisfy the predicate.
+    PredicateNotSatisfied,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RejectedCredential {
+    pub credential_referent: String,
+    pub reason: RejectionReason,
+}
+
+/// A referent of the presentation request no credential could be selected for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatisfiedReferent {
+    pub referent: String,
+    /// Candidates which were returned by the wallet, but rejected.
+    pub rejected: Vec<RejectedCredential>,
+    /// Whether a self attested value would have been accepted, had one been provided.
+    pub self_attest_allowed: bool,
+}
+
+impl fmt::Display for UnsatisfiedReferent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.rejected.is_empty() {
+            write!(f, "{}: no matching credential", self.referent)?;
+        } else {
+            let rejected = self
+                .rejected
+                .iter()
+                .map(|rejected| format!("{} ({:?})", rejected.credential_referent, rejected.reason))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(
+                f,
+                "{}: all candidates rejected: {}",
+                self.referent, rejected
+            )?;
+        }
+
+        if self.self_attest_allowed {
+            write!(
+                f,
+                "; a self attested value is allowed, but was not provided"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Outcome of a credential selection.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CredentialSelection {
+    pub selected_credentials: SelectedCredentials,
+    pub self_attested_attrs: HashMap<String, String>,
+    pub unsatisfied: Vec<UnsatisfiedReferent>,
+}
+
+impl CredentialSelection {
+    pub fn is_complete(&self) -> bool {
+        self.unsatisfied.is_empty()
+    }
+
+    /// Returns the arguments for generating the presentation, or an error listing the referents
+    /// which could not be satisfied.
+    pub fn into_parts(self) -> VcxResult<(SelectedCredentials, HashMap<String, String>)> {
+        if !self.is_complete() {
+            let unsatisfied = self
+                .unsatisfied
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidProofCredentialData,
+                format!("cannot satisfy presentation request referents: {unsatisfied}"),
+            ));
+        }
+
+        Ok((self.selected_credentials, self.self_attested_attrs))
+    }
+}
+
+/// Selects credentials satisfying a presentation request out of the candidates retrieved from
+/// the wallet.
+#[derive(Clone, Debug)]
+pub struct CredentialSelector {
+    strategy: SelectionStrategy,
+    issuance_times: HashMap<String, u64>,
+    revoked: HashSet<String>,
+    tails_dirs: HashMap<String, String>,
+    self_attested_values: HashMap<String, String>,
+    group_attributes: bool,
+}
+
+impl Default for CredentialSelector {
+    fn default() -> Self {
+        Self {
+            strategy: SelectionStrategy::default(),
+            issuance_times: HashMap::new(),
+            revoked: HashSet::new(),
+            tails_dirs: HashMap::new(),
+            self_attested_values: HashMap::new(),
+            group_attributes: true,
+        }
+    }
+}
+
+impl CredentialSelector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn strategy(mut self, strategy: SelectionStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Issuance times of credentials, by credential referent, used by
+    /// [SelectionStrategy::Newest].
+    pub fn issuance_times(mut self, issuance_times: HashMap<String, u64>) -> Self {
+        self.issuance_times = issuance_times;
+        self
+    }
+
+    /// Credentials known to be revoked, e.g. from revocation notifications, which are never
+    /// selected.
+    pub fn revoked_credentials(mut self, revoked: HashSet<String>) -> Self {
+        self.revoked = revoked;
+        self
+    }
+
+    /// Tails directories by revocation registry id. Revocable credentials are only selected for
+    /// referents requesting non-revocation if the tails directory of their registry is known.
+    pub fn tails_dirs(mut self, tails_dirs: HashMap<String, String>) -> Self {
+        self.tails_dirs = tails_dirs;
+        self
+    }
+
+    /// Values to self attest, by referent or by attribute name, for attribute referents without
+    /// restrictions that no credential satisfies.
+    pub fn self_attested_values(mut self, values: HashMap<String, String>) -> Self {
+        self.self_attested_values = values;
+        self
+    }
+
+    /// Whether attribute referents with identical restrictions should be proven with the same
+    /// credential when possible. Enabled by default.
+    pub fn group_attributes(mut self, group_attributes: bool) -> Self {
+        self.group_attributes = group_attributes;
+        self
+    }
+
+    pub fn select(
+        &self,
+        presentation_request: &PresentationRequest,
+        retrieved: &RetrievedCredentials,
+    ) -> CredentialSelection {
+        let request = presentation_request.value();
+        let mut selection = CredentialSelection::default();
+
+        // BTreeMap to make the selection deterministic
+        let mut attr_candidates = BTreeMap::new();
+        for (referent, attr_info) in request.requested_attributes.iter() {
+            let non_revoked = attr_info.non_revoked.is_some() || request.non_revoked.is_some();
+            let (accepted, rejected) =
+                self.filter_candidates(candidates_for(retrieved, referent), non_revoked, None);
+            attr_candidates.insert(referent.clone(), (accepted, rejected));
+        }
+
+        let grouped = if self.group_attributes {
+            self.select_grouped(presentation_request, &attr_candidates)
+        } else {
+            HashMap::new()
+        };
+
+        for (referent, (accepted, rejected)) in attr_candidates {
+            let attr_info = &request.requested_attributes[&referent];
+            let chosen = grouped
+                .get(&referent)
+                .cloned()
+                .or_else(|| self.pick(&accepted));
+
+            if let Some(candidate) = chosen {
+                self.select_candidate(&mut selection, referent, candidate);
+                continue;
+            }
+
+            let self_attest_allowed = attr_info.restrictions.is_none()
+                && attr_info.self_attest_allowed != Some(false)
+                && attr_info.name.is_some();
+            let self_attested_value = attr_info.name.as_ref().and_then(|name| {
+                self.self_attested_values
+                    .get(&referent)
+                    .or_else(|| self.self_attested_values.get(name))
+            });
+
+            match self_attested_value {
+                Some(value) if self_attest_allowed => {
+                    selection
+                        .self_attested_attrs
+                        .insert(referent, value.to_owned());
+                }
+                _ => selection.unsatisfied.push(UnsatisfiedReferent {
+                    referent,
+                    rejected,
+                    self_attest_allowed,
+                }),
+            }
+        }
+
+        let predicates: BTreeMap<_, _> = request.requested_predicates.iter().collect();
+        for (referent, predicate) in predicates {
+            let non_revoked = predicate.non_revoked.is_some() || request.non_revoked.is_some();
+            let (accepted, rejected) = self.filter_candidates(
+                candidates_for(retrieved, referent),
+                non_revoked,
+                Some(predicate),
+            );
+
+            match self.pick(&accepted) {
+                Some(candidate) => {
+                    self.select_candidate(&mut selection, referent.to_owned(), candidate)
+                }
+                None => selection.unsatisfied.push(UnsatisfiedReferent {
+                    referent: referent.to_owned(),
+                    rejected,
+                    self_attest_allowed: false,
+                }),
+            }
+        }
+
+        selection
+    }
+
+    fn filter_candidates<'a>(
+        &self,
+        candidates: &'a [RetrievedCredentialForReferent],
+        non_revoked: bool,
+        predicate: Option<&PredicateInfo>,
+    ) -> (
+        Vec<&'a RetrievedCredentialForReferent>,
+        Vec<RejectedCredential>,
+    ) {
+        let mut accepted = Vec::new();
+        let mut rejected = Vec::new();
+
+        for candidate in candidates {
+            match self.check_candidate(candidate, non_revoked, predicate) {
+                Ok(()) => accepted.push(candidate),
+                Err(reason) => rejected.push(RejectedCredential {
+                    credential_referent: candidate.cred_info.referent.clone(),
+                    reason,
+                }),
+            }
+        }
+
+        (accepted, rejected)
+    }
+
+    fn check_candidate(
+        &self,
+        candidate: &RetrievedCredentialForReferent,
+        non_revoked: bool,
+        predicate: Option<&PredicateInfo>,
+    ) -> Result<(), RejectionReason> {
+        let cred_info = &candidate.cred_info;
+
+        if self.revoked.contains(&cred_info.referent) {
+            return Err(RejectionReason::Revoked);
+        }
+
+        if non_revoked && self.tails_dir_for(candidate).is_none() && cred_info.rev_reg_id.is_some()
+        {
+            return Err(RejectionReason::MissingTailsDir);
+        }
+
+        if let Some(predicate) = predicate {
+            let value = cred_info
+                .attributes
+                .iter()
+                .find(|(name, _)| attr_common_view(name) == attr_common_view(&predicate.name))
+                .and_then(|(_, value)| value.parse::<i64>().ok())
+                .ok_or(RejectionReason::MissingPredicateValue)?;
+
+            if !predicate_satisfied(&predicate.p_type, value, predicate.p_value.into()) {
+                return Err(RejectionReason::PredicateNotSatisfied);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds a common credential for attribute referents sharing the same restrictions.
+    fn select_grouped<'a>(
+        &self,
+        presentation_request: &PresentationRequest,
+        attr_candidates: &BTreeMap<
+            String,
+            (
+                Vec<&'a RetrievedCredentialForReferent>,
+                Vec<RejectedCredential>,
+            ),
+        >,
+    ) -> HashMap<String, &'a RetrievedCredentialForReferent> {
+        let request = presentation_request.value();
+
+        let mut groups: BTreeMap<String, Vec<&String>> = BTreeMap::new();
+        for referent in attr_candidates.keys() {
+            if let Some(restrictions) = &request.requested_attributes[referent].restrictions {
+                let key = serde_json::to_string(restrictions).unwrap_or_default();
+                groups.entry(key).or_default().push(referent);
+            }
+        }
+
+        let mut grouped = HashMap::new();
+        for referents in groups.into_values().filter(|group| group.len() > 1) {
+            let Some((first, rest)) = referents.split_first() else {
+                continue;
+            };
+
+            let common: Vec<_> = attr_candidates[*first]
+                .0
+                .iter()
+                .copied()
+                .filter(|candidate| {
+                    rest.iter().all(|referent| {
+                        attr_candidates[*referent]
+                            .0
+                            .iter()
+                            .any(|other| other.cred_info.referent == candidate.cred_info.referent)
+                    })
+                })
+                .collect();
+
+            if let Some(chosen) = self.pick(&common) {
+                for referent in referents {
+                    let candidate = attr_candidates[referent]
+                        .0
+                        .iter()
+                        .find(|other| other.cred_info.referent == chosen.cred_info.referent);
+                    if let Some(candidate) = candidate {
+                        grouped.insert(referent.to_owned(), *candidate);
+                    }
+                }
+            }
+        }
+
+        grouped
+    }
+
+    fn pick<'a>(
+        &self,
+        candidates: &[&'a RetrievedCredentialForReferent],
+    ) -> Option<&'a RetrievedCredentialForReferent> {
+        match self.strategy {
+            SelectionStrategy::First => candidates.first().copied(),
+            // max_by_key returns the last maximum, so iterate in reverse to keep the wallet
+            // order for credentials issued at the same time
+            SelectionStrategy::Newest => candidates
+                .iter()
+                .rev()
+                .copied()
+                .max_by_key(|candidate| self.issuance_times.get(&candidate.cred_info.referent)),
+        }
+    }
+
+    fn select_candidate(
+        &self,
+        selection: &mut CredentialSelection,
+        referent: String,
+        candidate: &RetrievedCredentialForReferent,
+    ) {
+        selection
+            .selected_credentials
+            .select_credential_for_referent_from_retrieved(
+                referent,
+                candidate.clone(),
+                self.tails_dir_for(candidate),
+            );
+    }
+
+    fn tails_dir_for(&self, candidate: &RetrievedCredentialForReferent) -> Option<String> {
+        candidate
+            .cred_info
+            .rev_reg_id
+            .as_ref()
+            .and_then(|rev_reg_id| self.tails_dirs.get(rev_reg_id))
+            .cloned()
+    }
+}
+
+fn candidates_for<'a>(
+    retrieved: &'a RetrievedCredentials,
+    referent: &str,
+) -> &'a [RetrievedCredentialForReferent] {
+    retrieved
+        .credentials_by_referent
+        .get(referent)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+// attribute names are compared case and whitespace insensitive, as done by anoncreds
+fn attr_common_view(name: &str) -> String {
+    name.replace(' ', "").to_lowercase()
+}
+
+fn predicate_satisfied(p_type: &PredicateTypes, value: i64, p_value: i64) -> bool {
+    match p_type {
+        PredicateTypes::GE => value >= p_value,
+        PredicateTypes::GT => value > p_value,
+        PredicateTypes::LE => value <= p_value,
+        PredicateTypes::LT => value < p_value,
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use anoncreds_types::data_types::{
+        identifiers::{cred_def_id::CredentialDefinitionId, schema_id::SchemaId},
+        messages::cred_selection::RetrievedCredentialInfo,
+    };
+
+    use super::*;
+
+    fn candidate(
+        referent: &str,
+        age: &str,
+        rev_reg_id: Option<&str>,
+    ) -> RetrievedCredentialForReferent {
+        RetrievedCredentialForReferent {
+            cred_info: RetrievedCredentialInfo {
+                referent: referent.to_owned(),
+                attributes: vec![
+                    ("Name".to_owned(), "Alice".to_owned()),
+                    ("age".to_owned(), age.to_owned()),
+                ]
+                .into_iter()
+                .collect(),
+                schema_id: SchemaId::new_unchecked("schema_id"),
+                cred_def_id: CredentialDefinitionId::new_unchecked("cred_def_id"),
+                rev_reg_id: rev_reg_id.map(ToOwned::to_owned),
+                cred_rev_id: rev_reg_id.map(|_| 1),
+            },
+            interval: None,
+        }
+    }
+
+    fn presentation_request() -> PresentationRequest {
+        let presentation_request = json!({
+            "nonce": "123432421212",
+            "name": "proof_req_1",
+            "version": "1.0",
+            "requested_attributes": {
+                "name_1": { "name": "name", "restrictions": { "cred_def_id": "cred_def_id" } },
+                "age_1": { "name": "age", "restrictions": { "cred_def_id": "cred_def_id" } },
+                "nickname_1": { "name": "nickname" }
+            },
+            "requested_predicates": {
+                "age_2": { "name": "age", "p_type": ">=", "p_value": 18 }
+            },
+        })
+        .to_string();
+
+        serde_json::from_str(&presentation_request).unwrap()
+    }
+
+    fn retrieved(
+        name: Vec<RetrievedCredentialForReferent>,
+        age: Vec<RetrievedCredentialForReferent>,
+        predicate: Vec<RetrievedCredentialForReferent>,
+    ) -> RetrievedCredentials {
+        RetrievedCredentials {
+            credentials_by_referent: vec![
+                ("name_1".to_owned(), name),
+                ("age_1".to_owned(), age),
+                ("age_2".to_owned(), predicate),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    fn selected_referent(selection: &CredentialSelection, referent: &str) -> String {
+        selection.selected_credentials.credential_for_referent[referent]
+            .credential
+            .cred_info
+            .referent
+            .clone()
+    }
+
+    #[test]
+    fn test_select_credentials_with_predicates_and_self_attestation() {
+        let retrieved = retrieved(
+            vec![
+                candidate("cred_1", "17", None),
+                candidate("cred_2", "21", None),
+            ],
+            vec![candidate("cred_2", "21", None)],
+            vec![
+                candidate("cred_1", "17", None),
+                candidate("cred_2", "21", None),
+            ],
+        );
+        let selector = CredentialSelector::new().self_attested_values(
+            vec![("nickname".to_owned(), "Al".to_owned())]
+                .into_iter()
+                .collect(),
+        );
+
+        let selection = selector.select(&presentation_request(), &retrieved);
+
+        assert!(selection.is_complete());
+        // grouped with age_1, for which only cred_2 matches
+        assert_eq!("cred_2", selected_referent(&selection, "name_1"));
+        assert_eq!("cred_2", selected_referent(&selection, "age_1"));
+        assert_eq!("cred_2", selected_referent(&selection, "age_2"));
+        assert_eq!("Al", selection.self_attested_attrs["nickname_1"]);
+    }
+
+    #[test]
+    fn test_select_newest_credential() {
+        let retrieved = retrieved(
+            vec![
+                candidate("cred_1", "30", None),
+                candidate("cred_2", "31", None),
+            ],
+            vec![
+                candidate("cred_1", "30", None),
+                candidate("cred_2", "31", None),
+            ],
+            vec![
+                candidate("cred_1", "30", None),
+                candidate("cred_2", "31", None),
+            ],
+        );
+        let selector = CredentialSelector::new()
+            .strategy(SelectionStrategy::Newest)
+            .issuance_times(
+                vec![("cred_1".to_owned(), 200), ("cred_2".to_owned(), 100)]
+                    .into_iter()
+                    .collect(),
+            )
+            .self_attested_values(
+                vec![("nickname_1".to_owned(), "Al".to_owned())]
+                    .into_iter()
+                    .collect(),
+            );
+
+        let selection = selector.select(&presentation_request(), &retrieved);
+
+        assert!(selection.is_complete());
+        assert_eq!("cred_1", selected_referent(&selection, "name_1"));
+        assert_eq!("cred_1", selected_referent(&selection, "age_2"));
+    }
+
+    #[test]
+    fn test_report_unsatisfiable_referents() {
+        let retrieved = retrieved(
+            vec![candidate("cred_1", "17", Some("rev_reg_id"))],
+            vec![candidate("cred_1", "17", Some("rev_reg_id"))],
+            vec![
+                candidate("cred_1", "17", Some("rev_reg_id")),
+                candidate("cred_2", "19", Some("rev_reg_id")),
+            ],
+        );
+        let selector = CredentialSelector::new()
+            .revoked_credentials(vec!["cred_2".to_owned()].into_iter().collect());
+
+        let selection = selector.select(&presentation_request(), &retrieved);
+
+        assert_eq!(
+            vec![
+                UnsatisfiedReferent {
+                    referent: "nickname_1".to_owned(),
+                    rejected: vec![],
+                    self_attest_allowed: true,
+                },
+                UnsatisfiedReferent {
+                    referent: "age_2".to_owned(),
+                    rejected: vec![
+                        RejectedCredential {
+                            credential_referent: "cred_1".to_owned(),
+                            reason: RejectionReason::PredicateNotSatisfied,
+                        },
+                        RejectedCredential {
+                            credential_referent: "cred_2".to_owned(),
+                            reason: RejectionReason::Revoked,
+                        },
+                    ],
+                    self_attest_allowed: false,
+                },
+            ],
+            selection.unsatisfied
+        );
+        assert_eq!(
+            AriesVcxErrorKind::InvalidProofCredentialData,
+            selection.into_parts().unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn test_require_tails_dir_for_non_revocation() {
+        let mut presentation_request = presentation_request();
+        let PresentationRequest::PresentationRequestV1(payload) = &mut presentation_request else {
+            panic!("unexpected presentation request version");
+        };
+        payload.non_revoked = Some(Default::default());
+
+        let retrieved = retrieved(
+            vec![candidate("cred_1", "20", Some("rev_reg_id"))],
+            vec![candidate("cred_1", "20", Some("rev_reg_id"))],
+            vec![candidate("cred_1", "20", Some("rev_reg_id"))],
+        );
+        let selection = CredentialSelector::new().select(&presentation_request, &retrieved);
+        assert_eq!(
+            RejectionReason::MissingTailsDir,
+            selection.unsatisfied[0].rejected[0].reason
+        );
+
+        let selector = CredentialSelector::new()
+            .tails_dirs(
+                vec![("rev_reg_id".to_owned(), "/tmp/tails".to_owned())]
+                    .into_iter()
+                    .collect(),
+            )
+            .self_attested_values(
+                vec![("nickname".to_owned(), "Al".to_owned())]
+                    .into_iter()
+                    .collect(),
+            );
+        let selection = selector.select(&presentation_request, &retrieved);
+        assert!(selection.is_complete());
+        assert_eq!(
+            Some("/tmp/tails".to_owned()),
+            selection.selected_credentials.credential_for_referent["age_2"].tails_dir
+        );
+    }
+}
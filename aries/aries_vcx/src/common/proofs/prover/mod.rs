@@ -1,3 +1,4 @@
+pub mod credential_selection;
 mod prover_internal;
 
 use std::collections::HashMap;
@@ -1,13 +1,23 @@
+pub mod verification_policy;
+pub mod verification_result;
 mod verifier_internal;
 
+use anoncreds_types::data_types::messages::{
+    pres_request::PresentationRequest, presentation::Presentation,
+};
 use aries_vcx_core::{
-    anoncreds::base_anoncreds::BaseAnonCreds, ledger::base_ledger::AnoncredsLedgerRead,
+    anoncreds::base_anoncreds::BaseAnonCreds, errors::error::AriesVcxCoreErrorKind,
+    ledger::base_ledger::AnoncredsLedgerRead,
 };
 
 use crate::{
-    common::proofs::verifier::verifier_internal::{
-        build_cred_defs_json_verifier, build_rev_reg_defs_json, build_rev_reg_json,
-        build_schemas_json_verifier, get_credential_info, validate_proof_revealed_attributes,
+    common::proofs::verifier::{
+        verification_policy::VerificationPolicy,
+        verification_result::{PresentationVerificationResult, VerificationFailure},
+        verifier_internal::{
+            build_cred_defs_json_verifier, build_rev_reg_defs_json, build_rev_reg_json,
+            build_schemas_json_verifier, get_credential_info, validate_proof_revealed_attributes,
+        },
     },
     errors::error::prelude::*,
 };
@@ -49,3 +59,66 @@ pub async fn validate_indy_proof(
         .await
         .map_err(|err| err.into())
 }
+
+/// Verifies the presentation and describes the outcome for each requested referent. The policy
+/// is checked before the proofs are verified. Invalid presentations are reported through the
+/// result, errors are returned only when verification could not be carried out.
+pub async fn verify_indy_proof_detailed(
+    ledger: &impl AnoncredsLedgerRead,
+    anoncreds: &impl BaseAnonCreds,
+    proof_json: &str,
+    proof_req_json: &str,
+    policy: &VerificationPolicy,
+) -> VcxResult<PresentationVerificationResult> {
+    let proof: Presentation = serde_json::from_str(proof_json)?;
+    let proof_req: PresentationRequest = serde_json::from_str(proof_req_json)?;
+
+    let mut result = PresentationVerificationResult::inspect(&proof, proof_req.value(), policy);
+    if !result.is_valid() {
+        debug!(
+            "verify_indy_proof_detailed >> presentation does not comply with the request or \
+             policy: {:?}",
+            result.failures
+        );
+        return Ok(result);
+    }
+
+    let credential_data = get_credential_info(proof_json)?;
+    let credential_defs_json = build_cred_defs_json_verifier(ledger, &credential_data).await?;
+    let schemas_json = build_schemas_json_verifier(ledger, &credential_data).await?;
+    let non_revocation_checked = credential_data
+        .iter()
+        .any(|cred_info| cred_info.timestamp.is_some());
+    let rev_reg_defs_json = build_rev_reg_defs_json(ledger, &credential_data).await;
+    let rev_regs_json = build_rev_reg_json(ledger, &credential_data).await;
+    let (rev_reg_defs_json, rev_regs_json) = match (rev_reg_defs_json, rev_regs_json) {
+        (Ok(rev_reg_defs_json), Ok(rev_regs_json)) => (rev_reg_defs_json, rev_regs_json),
+        _ if non_revocation_checked => {
+            result.fail(VerificationFailure::RevocationDataUnavailable);
+            return Ok(result);
+        }
+        _ => (json!({}).to_string(), json!({}).to_string()),
+    };
+
+    let verified = anoncreds
+        .verifier_verify_proof(
+            proof_req,
+            proof,
+            serde_json::from_str(&schemas_json)?,
+            serde_json::from_str(&credential_defs_json)?,
+            serde_json::from_str(&rev_reg_defs_json)?,
+            serde_json::from_str(&rev_regs_json)?,
+        )
+        .await;
+    match verified {
+        Ok(true) => {}
+        Ok(false) => result.fail_proof_verification(),
+        Err(err) if err.kind() == AriesVcxCoreErrorKind::ProofRejected => {
+            result.fail(VerificationFailure::ProofRejected {
+                message: err.to_string(),
+            })
+        }
+        Err(err) => return Err(err.into()),
+    }
+    Ok(result)
+}
@@ -0,0 +1,365 @@
+use std::collections::{HashMap, HashSet};
+
+use anoncreds_types::{
+    data_types::messages::{pres_request::NonRevokedInterval, presentation::Identifier},
+    utils::query::Query,
+};
+
+use super::verification_result::VerificationFailure;
+
+/// Rules a presentation has to comply with, on top of the presentation request, checked before
+/// its proofs are verified.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VerificationPolicy {
+    timestamp_tolerance: u64,
+    allowed_issuers: Option<HashSet<String>>,
+    require_non_revocation: bool,
+    enforce_restrictions: bool,
+    enforce_interval_start: bool,
+}
+
+impl Default for VerificationPolicy {
+    fn default() -> Self {
+        Self {
+            timestamp_tolerance: 0,
+            allowed_issuers: None,
+            require_non_revocation: false,
+            enforce_restrictions: true,
+            enforce_interval_start: false,
+        }
+    }
+}
+
+impl VerificationPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seconds a non-revocation timestamp may lie past the end, or before the start if enforced,
+    /// of the requested interval, to account for ledger and clock skew.
+    pub fn timestamp_tolerance(mut self, seconds: u64) -> Self {
+        self.timestamp_tolerance = seconds;
+        self
+    }
+
+    /// Issuer DIDs presented credentials must originate from. Both unqualified and `did:sov`
+    /// qualified legacy DIDs are matched. All issuers are accepted unless set.
+    pub fn allowed_issuers(mut self, issuers: HashSet<String>) -> Self {
+        self.allowed_issuers = Some(issuers);
+        self
+    }
+
+    /// Requires non-revocation to be proven for every revocable credential, even where the
+    /// presentation request does not ask for it.
+    pub fn require_non_revocation(mut self, require: bool) -> Self {
+        self.require_non_revocation = require;
+        self
+    }
+
+    /// Whether restrictions of the presentation request are checked against the presented
+    /// credentials, enabled by default.
+    pub fn enforce_restrictions(mut self, enforce: bool) -> Self {
+        self.enforce_restrictions = enforce;
+        self
+    }
+
+    /// Whether non-revocation timestamps have to lie after the start of the requested interval.
+    /// Disabled by default: Aries RFC 0441 lets provers prove non-revocation with the latest
+    /// registry state at or before the end of the interval, regardless of its start.
+    pub fn enforce_interval_start(mut self, enforce: bool) -> Self {
+        self.enforce_interval_start = enforce;
+        self
+    }
+
+    pub(super) fn check_referent(
+        &self,
+        referent: &str,
+        identifier: &Identifier,
+        restrictions: Option<&Query>,
+        revealed_values: &HashMap<String, String>,
+        non_revoked: Option<&NonRevokedInterval>,
+        failures: &mut Vec<VerificationFailure>,
+    ) {
+        if self.enforce_restrictions {
+            let restrictions_met = restrictions
+                .map(|query| evaluate_restriction(query, identifier, revealed_values))
+                .unwrap_or(Some(true));
+            if restrictions_met == Some(false) {
+                failures.push(VerificationFailure::RestrictionMismatch {
+                    referent: referent.to_owned(),
+                });
+            }
+        }
+
+        if let Some(allowed_issuers) = &self.allowed_issuers {
+            let issuer_id = cred_def_issuer_id(&identifier.cred_def_id.0);
+            if !issuer_id.map_or(false, |issuer_id| is_allowed(allowed_issuers, issuer_id)) {
+                failures.push(VerificationFailure::IssuerNotAllowed {
+                    referent: referent.to_owned(),
+                    issuer_id: issuer_id.map(ToOwned::to_owned),
+                });
+            }
+        }
+
+        let non_revocation_required = non_revoked.is_some() || self.require_non_revocation;
+        match identifier.timestamp {
+            None if non_revocation_required && identifier.rev_reg_id.is_some() => {
+                failures.push(VerificationFailure::NonRevocationNotProven {
+                    referent: referent.to_owned(),
+                })
+            }
+            Some(timestamp)
+                if non_revoked
+                    .map_or(false, |interval| self.outside_interval(timestamp, interval)) =>
+            {
+                failures.push(VerificationFailure::TimestampOutsideInterval {
+                    referent: referent.to_owned(),
+                    timestamp,
+                })
+            }
+            _ => {}
+        }
+    }
+
+    fn outside_interval(&self, timestamp: u64, interval: &NonRevokedInterval) -> bool {
+        let before_from = self.enforce_interval_start
+            && interval.from.map_or(false, |from| {
+                timestamp.saturating_add(self.timestamp_tolerance) < from
+            });
+        let after_to = interval.to.map_or(false, |to| {
+            timestamp > to.saturating_add(self.timestamp_tolerance)
+        });
+        before_from || after_to
+    }
+}
+
+fn is_allowed(allowed_issuers: &HashSet<String>, issuer_id: &str) -> bool {
+    allowed_issuers.contains(issuer_id)
+        || allowed_issuers.contains(&format!("did:sov:{issuer_id}"))
+        || issuer_id
+            .strip_prefix("did:sov:")
+            .map_or(false, |did| allowed_issuers.contains(did))
+}
+
+/// Derives the issuer DID from legacy Indy (`<did>:3:CL:...`) and qualified
+/// (`<did>/anoncreds/...`) credential definition ids.
+pub(super) fn cred_def_issuer_id(cred_def_id: &str) -> Option<&str> {
+    if cred_def_id.starts_with("did:") {
+        return cred_def_id.split_once('/').map(|(issuer_id, _)| issuer_id);
+    }
+    let parts: Vec<&str> = cred_def_id.split(':').collect();
+    (parts.len() >= 5 && parts[1] == "3").then_some(parts[0])
+}
+
+/// Evaluates a restriction against the identifiers of the presented credential, returning `None`
+/// where it cannot be decided from the presentation alone.
+fn evaluate_restriction(
+    query: &Query,
+    identifier: &Identifier,
+    revealed_values: &HashMap<String, String>,
+) -> Option<bool> {
+    let tag_value = |tag: &str| restriction_tag_value(tag, identifier, revealed_values);
+    let evaluate_all = |queries: &[Query]| -> Vec<Option<bool>> {
+        queries
+            .iter()
+            .map(|query| evaluate_restriction(query, identifier, revealed_values))
+            .collect()
+    };
+    match query {
+        Query::And(queries) => {
+            let results = evaluate_all(queries);
+            if results.contains(&Some(false)) {
+                Some(false)
+            } else if results.iter().all(|result| *result == Some(true)) {
+                Some(true)
+            } else {
+                None
+            }
+        }
+        Query::Or(queries) => {
+            let results = evaluate_all(queries);
+            if results.contains(&Some(true)) {
+                Some(true)
+            } else if results.iter().all(|result| *result == Some(false)) {
+                Some(false)
+            } else {
+                None
+            }
+        }
+        Query::Not(query) => evaluate_restriction(query, identifier, revealed_values).map(|r| !r),
+        Query::Eq(tag, value) => tag_value(tag).map(|actual| actual == *value),
+        Query::Neq(tag, value) => tag_value(tag).map(|actual| actual != *value),
+        Query::In(tag, values) => tag_value(tag).map(|actual| values.contains(&actual)),
+        _ => None,
+    }
+}
+
+fn restriction_tag_value(
+    tag: &str,
+    identifier: &Identifier,
+    revealed_values: &HashMap<String, String>,
+) -> Option<String> {
+    let schema_parts: Vec<&str> = identifier.schema_id.0.split(':').collect();
+    let legacy_schema = schema_parts.len() == 4 && schema_parts[1] == "2";
+    match tag {
+        "schema_id" => Some(identifier.schema_id.0.clone()),
+        "cred_def_id" => Some(identifier.cred_def_id.0.clone()),
+        "rev_reg_id" => identifier.rev_reg_id.as_ref().map(|id| id.0.clone()),
+        "issuer_did" | "issuer_id" => {
+            cred_def_issuer_id(&identifier.cred_def_id.0).map(ToOwned::to_owned)
+        }
+        "schema_issuer_did" if legacy_schema => Some(schema_parts[0].to_owned()),
+        "schema_name" if legacy_schema => Some(schema_parts[2].to_owned()),
+        "schema_version" if legacy_schema => Some(schema_parts[3].to_owned()),
+        _ => {
+            let attr_name = tag.strip_prefix("attr::")?.strip_suffix("::value")?;
+            revealed_values
+                .iter()
+                .find(|(name, _)| normalize_attr_name(name) == normalize_attr_name(attr_name))
+                .map(|(_, value)| value.clone())
+        }
+    }
+}
+
+fn normalize_attr_name(name: &str) -> String {
+    name.replace(' ', "").to_lowercase()
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use anoncreds_types::data_types::identifiers::{
+        cred_def_id::CredentialDefinitionId, rev_reg_def_id::RevocationRegistryDefinitionId,
+        schema_id::SchemaId,
+    };
+
+    use super::*;
+
+    const ISSUER_DID: &str = "V4SGRU86Z58d6TV7PBUe6f";
+
+    fn identifier(timestamp: Option<u64>) -> Identifier {
+        Identifier {
+            schema_id: SchemaId::new_unchecked(format!("{ISSUER_DID}:2:gvt:1.0")),
+            cred_def_id: CredentialDefinitionId::new_unchecked(format!("{ISSUER_DID}:3:CL:1:tag")),
+            rev_reg_id: Some(RevocationRegistryDefinitionId::new_unchecked(format!(
+                "{ISSUER_DID}:4:{ISSUER_DID}:3:CL:1:tag:CL_ACCUM:1"
+            ))),
+            timestamp,
+        }
+    }
+
+    fn check(
+        policy: &VerificationPolicy,
+        identifier: &Identifier,
+        non_revoked: Option<&NonRevokedInterval>,
+    ) -> Vec<VerificationFailure> {
+        let mut failures = Vec::new();
+        policy.check_referent(
+            "ref",
+            identifier,
+            None,
+            &HashMap::new(),
+            non_revoked,
+            &mut failures,
+        );
+        failures
+    }
+
+    #[test]
+    fn test_cred_def_issuer_id() {
+        assert_eq!(
+            Some(ISSUER_DID),
+            cred_def_issuer_id(&format!("{ISSUER_DID}:3:CL:1:tag"))
+        );
+        assert_eq!(
+            Some("did:indy:sovrin:V4SGRU86Z58d6TV7PBUe6f"),
+            cred_def_issuer_id(
+                "did:indy:sovrin:V4SGRU86Z58d6TV7PBUe6f/anoncreds/v0/CLAIM_DEF/1/tag"
+            )
+        );
+        assert_eq!(None, cred_def_issuer_id("cred_def"));
+    }
+
+    #[test]
+    fn test_timestamp_tolerance() {
+        let interval = NonRevokedInterval::new(None, Some(100));
+        let identifier = identifier(Some(130));
+
+        assert_eq!(
+            vec![VerificationFailure::TimestampOutsideInterval {
+                referent: "ref".to_owned(),
+                timestamp: 130
+            }],
+            check(&VerificationPolicy::new(), &identifier, Some(&interval))
+        );
+        assert!(check(
+            &VerificationPolicy::new().timestamp_tolerance(30),
+            &identifier,
+            Some(&interval)
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_timestamp_before_interval() {
+        let interval = NonRevokedInterval::new(Some(100), Some(200));
+        let early = identifier(Some(70));
+        let enforce_start = VerificationPolicy::new().enforce_interval_start(true);
+
+        assert!(check(&VerificationPolicy::new(), &early, Some(&interval)).is_empty());
+        assert_eq!(
+            vec![VerificationFailure::TimestampOutsideInterval {
+                referent: "ref".to_owned(),
+                timestamp: 70
+            }],
+            check(&enforce_start, &early, Some(&interval))
+        );
+        assert!(check(
+            &enforce_start.clone().timestamp_tolerance(30),
+            &early,
+            Some(&interval)
+        )
+        .is_empty());
+        assert!(check(&enforce_start, &identifier(Some(150)), Some(&interval)).is_empty());
+    }
+
+    #[test]
+    fn test_allowed_issuers() {
+        let identifier = identifier(Some(100));
+        let allowed = |issuer: &str| {
+            VerificationPolicy::new().allowed_issuers(HashSet::from([issuer.to_owned()]))
+        };
+
+        assert!(check(&allowed(ISSUER_DID), &identifier, None).is_empty());
+        assert!(check(
+            &allowed(&format!("did:sov:{ISSUER_DID}")),
+            &identifier,
+            None
+        )
+        .is_empty());
+        assert_eq!(
+            vec![VerificationFailure::IssuerNotAllowed {
+                referent: "ref".to_owned(),
+                issuer_id: Some(ISSUER_DID.to_owned())
+            }],
+            check(&allowed("8wZcEriaNLNKtteJvx7f8i"), &identifier, None)
+        );
+    }
+
+    #[test]
+    fn test_require_non_revocation() {
+        let identifier = identifier(None);
+
+        assert!(check(&VerificationPolicy::new(), &identifier, None).is_empty());
+        assert_eq!(
+            vec![VerificationFailure::NonRevocationNotProven {
+                referent: "ref".to_owned()
+            }],
+            check(
+                &VerificationPolicy::new().require_non_revocation(true),
+                &identifier,
+                None
+            )
+        );
+    }
+}
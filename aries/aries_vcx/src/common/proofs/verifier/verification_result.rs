@@ -0,0 +1,553 @@
+use std::collections::HashMap;
+
+use anoncreds_types::data_types::{
+    identifiers::{
+        cred_def_id::CredentialDefinitionId, rev_reg_def_id::RevocationRegistryDefinitionId,
+        schema_id::SchemaId,
+    },
+    messages::{
+        pres_request::{
+            NonRevokedInterval, PredicateTypes, PredicateValue, PresentationRequestPayload,
+        },
+        presentation::{Identifier, Presentation, RequestedProof},
+    },
+};
+
+use super::verification_policy::{cred_def_issuer_id, VerificationPolicy};
+use crate::{
+    protocols::proof_presentation::verifier::verification_status::PresentationVerificationStatus,
+    utils::openssl::encode,
+};
+
+/// Credential a referent was presented from.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CredentialSource {
+    pub schema_id: SchemaId,
+    pub cred_def_id: CredentialDefinitionId,
+    /// Issuer DID, as far as it can be derived from the credential definition id.
+    pub issuer_id: Option<String>,
+    pub rev_reg_id: Option<RevocationRegistryDefinitionId>,
+    /// Time of the revocation registry state non-revocation was proven against.
+    pub timestamp: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PresentedAttribute {
+    Revealed { raw: String },
+    RevealedGroup { values: HashMap<String, String> },
+    Unrevealed,
+    SelfAttested { value: String },
+    Missing,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AttributeResult {
+    pub value: PresentedAttribute,
+    pub source: Option<CredentialSource>,
+    /// Interval non-revocation was requested for.
+    pub non_revoked: Option<NonRevokedInterval>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PredicateResult {
+    pub name: String,
+    pub p_type: PredicateTypes,
+    pub p_value: PredicateValue,
+    /// Whether the predicate was proven by a valid presentation.
+    pub satisfied: bool,
+    pub source: Option<CredentialSource>,
+    pub non_revoked: Option<NonRevokedInterval>,
+}
+
+/// Reason a presentation failed verification.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum VerificationFailure {
+    /// The requested referent is not part of the presentation.
+    MissingReferent { referent: String },
+    /// The credential presented for the referent does not satisfy its restrictions.
+    RestrictionMismatch { referent: String },
+    /// The revealed raw value does not match its encoded value.
+    EncodingMismatch { referent: String },
+    /// Non-revocation was requested or required by the policy, but not proven for the referent.
+    NonRevocationNotProven { referent: String },
+    /// Non-revocation was proven at a time outside the requested interval, beyond the tolerance
+    /// of the policy.
+    TimestampOutsideInterval { referent: String, timestamp: u64 },
+    /// The credential presented for the referent was not issued by an allowed issuer.
+    IssuerNotAllowed {
+        referent: String,
+        issuer_id: Option<String>,
+    },
+    /// The revocation registries needed to check non-revocation could not be fetched.
+    RevocationDataUnavailable,
+    /// The presentation was rejected by the AnonCreds verifier.
+    ProofRejected { message: String },
+    /// The proofs do not verify while non-revocation was proven for the listed referents. The
+    /// verifier cannot tell a revoked credential from an otherwise invalid proof, so the failure
+    /// applies to every referent.
+    InvalidOrRevoked { referents: Vec<String> },
+    /// The proofs do not verify.
+    CryptographicFailure,
+}
+
+impl VerificationFailure {
+    /// Referent the failure is specific to, if any.
+    pub fn referent(&self) -> Option<&str> {
+        match self {
+            Self::MissingReferent { referent }
+            | Self::RestrictionMismatch { referent }
+            | Self::EncodingMismatch { referent }
+            | Self::NonRevocationNotProven { referent }
+            | Self::TimestampOutsideInterval { referent, .. }
+            | Self::IssuerNotAllowed { referent, .. } => Some(referent),
+            Self::RevocationDataUnavailable
+            | Self::ProofRejected { .. }
+            | Self::InvalidOrRevoked { .. }
+            | Self::CryptographicFailure => None,
+        }
+    }
+}
+
+/// Typed outcome of verifying a presentation, per requested referent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PresentationVerificationResult {
+    pub status: PresentationVerificationStatus,
+    pub attributes: HashMap<String, AttributeResult>,
+    pub predicates: HashMap<String, PredicateResult>,
+    pub failures: Vec<VerificationFailure>,
+}
+
+impl PresentationVerificationResult {
+    /// Inspects the presentation against the request and the verifier policy, without verifying
+    /// its proofs.
+    pub fn inspect(
+        proof: &Presentation,
+        proof_req: &PresentationRequestPayload,
+        policy: &VerificationPolicy,
+    ) -> Self {
+        Self::inspect_parts(
+            &proof.requested_proof,
+            &proof.identifiers,
+            proof_req,
+            policy,
+        )
+    }
+
+    fn inspect_parts(
+        requested_proof: &RequestedProof,
+        identifiers: &[Identifier],
+        proof_req: &PresentationRequestPayload,
+        policy: &VerificationPolicy,
+    ) -> Self {
+        let mut failures = Vec::new();
+
+        let mut attributes = HashMap::new();
+        for (referent, attr_info) in proof_req.requested_attributes.iter() {
+            let non_revoked = attr_info
+                .non_revoked
+                .clone()
+                .or_else(|| proof_req.non_revoked.clone());
+
+            let (value, sub_proof_index, revealed_values) =
+                if let Some(info) = requested_proof.revealed_attrs.get(referent) {
+                    if encode(&info.raw).ok().as_deref() != Some(info.encoded.as_str()) {
+                        failures.push(VerificationFailure::EncodingMismatch {
+                            referent: referent.clone(),
+                        });
+                    }
+                    let name = attr_info.name.clone().unwrap_or_default();
+                    (
+                        PresentedAttribute::Revealed {
+                            raw: info.raw.clone(),
+                        },
+                        Some(info.sub_proof_index),
+                        HashMap::from([(name, info.raw.clone())]),
+                    )
+                } else if let Some(group) = requested_proof.revealed_attr_groups.get(referent) {
+                    for value in group.values.values() {
+                        if encode(&value.raw).ok().as_deref() != Some(value.encoded.as_str()) {
+                            failures.push(VerificationFailure::EncodingMismatch {
+                                referent: referent.clone(),
+                            });
+                        }
+                    }
+                    let values: HashMap<String, String> = group
+                        .values
+                        .iter()
+                        .map(|(name, value)| (name.clone(), value.raw.clone()))
+                        .collect();
+                    (
+                        PresentedAttribute::RevealedGroup {
+                            values: values.clone(),
+                        },
+                        Some(group.sub_proof_index),
+                        values,
+                    )
+                } else if let Some(info) = requested_proof.unrevealed_attrs.get(referent) {
+                    (
+                        PresentedAttribute::Unrevealed,
+                        Some(info.sub_proof_index),
+                        HashMap::new(),
+                    )
+                } else if let Some(value) = requested_proof.self_attested_attrs.get(referent) {
+                    (
+                        PresentedAttribute::SelfAttested {
+                            value: value.clone(),
+                        },
+                        None,
+                        HashMap::new(),
+                    )
+                } else {
+                    failures.push(VerificationFailure::MissingReferent {
+                        referent: referent.clone(),
+                    });
+                    (PresentedAttribute::Missing, None, HashMap::new())
+                };
+
+            let identifier = sub_proof_index.and_then(|idx| identifiers.get(idx as usize));
+            if let Some(identifier) = identifier {
+                policy.check_referent(
+                    referent,
+                    identifier,
+                    attr_info.restrictions.as_ref(),
+                    &revealed_values,
+                    non_revoked.as_ref(),
+                    &mut failures,
+                );
+            }
+
+            attributes.insert(
+                referent.clone(),
+                AttributeResult {
+                    value,
+                    source: identifier.map(CredentialSource::from),
+                    non_revoked,
+                },
+            );
+        }
+
+        let mut predicates = HashMap::new();
+        for (referent, pred_info) in proof_req.requested_predicates.iter() {
+            let non_revoked = pred_info
+                .non_revoked
+                .clone()
+                .or_else(|| proof_req.non_revoked.clone());
+
+            let identifier = requested_proof
+                .predicates
+                .get(referent)
+                .and_then(|info| identifiers.get(info.sub_proof_index as usize));
+            match identifier {
+                Some(identifier) => policy.check_referent(
+                    referent,
+                    identifier,
+                    pred_info.restrictions.as_ref(),
+                    &HashMap::new(),
+                    non_revoked.as_ref(),
+                    &mut failures,
+                ),
+                None => failures.push(VerificationFailure::MissingReferent {
+                    referent: referent.clone(),
+                }),
+            }
+
+            predicates.insert(
+                referent.clone(),
+                PredicateResult {
+                    name: pred_info.name.clone(),
+                    p_type: pred_info.p_type.clone(),
+                    p_value: pred_info.p_value,
+                    satisfied: !failures
+                        .iter()
+                        .any(|failure| failure.referent() == Some(referent.as_str())),
+                    source: identifier.map(CredentialSource::from),
+                    non_revoked,
+                },
+            );
+        }
+
+        let status = if failures.is_empty() {
+            PresentationVerificationStatus::Valid
+        } else {
+            PresentationVerificationStatus::Invalid
+        };
+        Self {
+            status,
+            attributes,
+            predicates,
+            failures,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.status == PresentationVerificationStatus::Valid
+    }
+
+    /// Records a failure, invalidating the result. Failures which are not specific to a referent
+    /// leave no predicate satisfied.
+    pub fn fail(&mut self, failure: VerificationFailure) {
+        for (referent, predicate) in self.predicates.iter_mut() {
+            if failure.referent().map_or(true, |failed| failed == referent) {
+                predicate.satisfied = false;
+            }
+        }
+        self.failures.push(failure);
+        self.status = PresentationVerificationStatus::Invalid;
+    }
+
+    /// Records that the proofs of the presentation do not verify. Since the proofs are verified
+    /// as a whole, no predicate is left satisfied.
+    pub fn fail_proof_verification(&mut self) {
+        let referents = self.non_revocation_proven_referents();
+        if referents.is_empty() {
+            self.fail(VerificationFailure::CryptographicFailure);
+        } else {
+            self.fail(VerificationFailure::InvalidOrRevoked { referents });
+        }
+    }
+
+    /// Referents proven from a credential for which non-revocation was proven, in order.
+    pub fn non_revocation_proven_referents(&self) -> Vec<String> {
+        let mut referents: Vec<String> = self
+            .attributes
+            .iter()
+            .map(|(referent, attr)| (referent, &attr.source))
+            .chain(
+                self.predicates
+                    .iter()
+                    .map(|(referent, pred)| (referent, &pred.source)),
+            )
+            .filter(|(_, source)| {
+                source
+                    .as_ref()
+                    .is_some_and(|source| source.timestamp.is_some())
+            })
+            .map(|(referent, _)| referent.clone())
+            .collect();
+        referents.sort();
+        referents
+    }
+
+    pub fn non_revocation_requested(&self) -> bool {
+        self.attributes
+            .values()
+            .map(|attr| &attr.non_revoked)
+            .chain(self.predicates.values().map(|pred| &pred.non_revoked))
+            .any(Option::is_some)
+    }
+}
+
+impl From<&Identifier> for CredentialSource {
+    fn from(identifier: &Identifier) -> Self {
+        Self {
+            schema_id: identifier.schema_id.clone(),
+            cred_def_id: identifier.cred_def_id.clone(),
+            issuer_id: cred_def_issuer_id(&identifier.cred_def_id.0).map(ToOwned::to_owned),
+            rev_reg_id: identifier.rev_reg_id.clone(),
+            timestamp: identifier.timestamp,
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use anoncreds_types::{
+        data_types::messages::{
+            nonce::Nonce,
+            pres_request::{AttributeInfo, PredicateInfo},
+            presentation::{RevealedAttributeInfo, SubProofReferent},
+        },
+        utils::query::Query,
+    };
+
+    use super::*;
+
+    const SCHEMA_ID: &str = "V4SGRU86Z58d6TV7PBUe6f:2:gvt:1.0";
+    const CRED_DEF_ID: &str = "V4SGRU86Z58d6TV7PBUe6f:3:CL:1:tag";
+
+    fn identifier() -> Identifier {
+        Identifier {
+            schema_id: SchemaId::new_unchecked(SCHEMA_ID),
+            cred_def_id: CredentialDefinitionId::new_unchecked(CRED_DEF_ID),
+            rev_reg_id: None,
+            timestamp: None,
+        }
+    }
+
+    fn requested_proof() -> RequestedProof {
+        let mut requested_proof = RequestedProof::default();
+        requested_proof.revealed_attrs.insert(
+            "name_ref".to_owned(),
+            RevealedAttributeInfo {
+                sub_proof_index: 0,
+                raw: "Alice".to_owned(),
+                encoded: encode("Alice").unwrap(),
+            },
+        );
+        requested_proof.predicates.insert(
+            "age_ref".to_owned(),
+            SubProofReferent { sub_proof_index: 0 },
+        );
+        requested_proof
+    }
+
+    fn proof_request(restrictions: Option<Query>) -> PresentationRequestPayload {
+        PresentationRequestPayload::builder()
+            .nonce(Nonce::new().unwrap())
+            .name("proof".to_owned())
+            .requested_attributes(HashMap::from([(
+                "name_ref".to_owned(),
+                AttributeInfo {
+                    name: Some("name".to_owned()),
+                    restrictions,
+                    ..Default::default()
+                },
+            )]))
+            .requested_predicates(HashMap::from([(
+                "age_ref".to_owned(),
+                PredicateInfo::builder()
+                    .name("age".to_owned())
+                    .p_type(PredicateTypes::GE)
+                    .p_value(18)
+                    .build(),
+            )]))
+            .build()
+    }
+
+    #[test]
+    fn test_inspect_valid_presentation() {
+        let restrictions = Query::And(vec![
+            Query::Eq("issuer_did".to_owned(), "V4SGRU86Z58d6TV7PBUe6f".to_owned()),
+            Query::Eq("schema_name".to_owned(), "gvt".to_owned()),
+            Query::Eq("attr::name::value".to_owned(), "Alice".to_owned()),
+        ]);
+
+        let result = PresentationVerificationResult::inspect_parts(
+            &requested_proof(),
+            &[identifier()],
+            &proof_request(Some(restrictions)),
+            &VerificationPolicy::default(),
+        );
+
+        assert!(result.is_valid(), "{:?}", result.failures);
+        assert_eq!(
+            PresentedAttribute::Revealed {
+                raw: "Alice".to_owned()
+            },
+            result.attributes["name_ref"].value
+        );
+        let predicate = &result.predicates["age_ref"];
+        assert!(predicate.satisfied);
+        assert_eq!(
+            Some("V4SGRU86Z58d6TV7PBUe6f"),
+            predicate.source.as_ref().unwrap().issuer_id.as_deref()
+        );
+    }
+
+    #[test]
+    fn test_inspect_reports_failures() {
+        let restrictions = Query::Eq("cred_def_id".to_owned(), "other".to_owned());
+        let mut requested_proof = requested_proof();
+        requested_proof.predicates.clear();
+
+        let mut result = PresentationVerificationResult::inspect_parts(
+            &requested_proof,
+            &[identifier()],
+            &proof_request(Some(restrictions)),
+            &VerificationPolicy::default(),
+        );
+
+        assert_eq!(PresentationVerificationStatus::Invalid, result.status);
+        assert!(result
+            .failures
+            .contains(&VerificationFailure::RestrictionMismatch {
+                referent: "name_ref".to_owned()
+            }));
+        assert!(result
+            .failures
+            .contains(&VerificationFailure::MissingReferent {
+                referent: "age_ref".to_owned()
+            }));
+
+        result.fail(VerificationFailure::CryptographicFailure);
+        assert_eq!(3, result.failures.len());
+    }
+
+    #[test]
+    fn test_predicate_satisfied_per_referent() {
+        let mut requested_proof = requested_proof();
+        requested_proof
+            .revealed_attrs
+            .get_mut("name_ref")
+            .unwrap()
+            .encoded = "1".to_owned();
+
+        let mut result = PresentationVerificationResult::inspect_parts(
+            &requested_proof,
+            &[identifier()],
+            &proof_request(None),
+            &VerificationPolicy::default(),
+        );
+
+        // the predicate is proven even though another referent failed
+        assert!(!result.is_valid());
+        assert!(result.predicates["age_ref"].satisfied);
+
+        result.fail(VerificationFailure::NonRevocationNotProven {
+            referent: "name_ref".to_owned(),
+        });
+        assert!(result.predicates["age_ref"].satisfied);
+
+        result.fail(VerificationFailure::NonRevocationNotProven {
+            referent: "age_ref".to_owned(),
+        });
+        assert!(!result.predicates["age_ref"].satisfied);
+    }
+
+    #[test]
+    fn test_failed_proof_fails_every_predicate() {
+        let mut requested_proof = requested_proof();
+        requested_proof.predicates.insert(
+            "height_ref".to_owned(),
+            SubProofReferent { sub_proof_index: 1 },
+        );
+        let revocable = Identifier {
+            rev_reg_id: Some(RevocationRegistryDefinitionId::new_unchecked(
+                "V4SGRU86Z58d6TV7PBUe6f:4:V4SGRU86Z58d6TV7PBUe6f:3:CL:1:tag:CL_ACCUM:tag",
+            )),
+            timestamp: Some(1_700_000_000),
+            ..identifier()
+        };
+        let mut request = proof_request(None);
+        request.requested_predicates.insert(
+            "height_ref".to_owned(),
+            PredicateInfo::builder()
+                .name("height".to_owned())
+                .p_type(PredicateTypes::GE)
+                .p_value(150)
+                .build(),
+        );
+
+        let mut result = PresentationVerificationResult::inspect_parts(
+            &requested_proof,
+            &[identifier(), revocable],
+            &request,
+            &VerificationPolicy::default(),
+        );
+        assert!(result.is_valid(), "{:?}", result.failures);
+
+        // the predicate of the non-revocable credential is not blamed on revocation, nor left
+        // satisfied
+        result.fail_proof_verification();
+        assert_eq!(
+            vec![VerificationFailure::InvalidOrRevoked {
+                referents: vec!["height_ref".to_owned()]
+            }],
+            result.failures
+        );
+        assert!(!result.predicates["age_ref"].satisfied);
+        assert!(!result.predicates["height_ref"].satisfied);
+    }
+}
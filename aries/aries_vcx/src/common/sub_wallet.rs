@@ -0,0 +1,207 @@
+use aries_vcx_core::{
+    errors::error::AriesVcxCoreErrorKind,
+    wallet::{
+        askar::AskarWallet,
+        base_wallet::{record::Record, record_category::RecordCategory, wql::Wql, RecordWallet},
+        record_tags::{RecordTag, RecordTags},
+    },
+};
+use public_key::Key;
+
+use crate::errors::error::prelude::*;
+
+const TENANT_TAG: &str = "tenant";
+
+/// Hosts many tenants in a single Askar store, one profile per tenant. Routing keys are mapped
+/// to tenants by records kept in the profile of the root wallet, so that inbound messages can be
+/// dispatched to the right sub-wallet.
+#[derive(Debug)]
+pub struct SubWalletManager {
+    root: AskarWallet,
+}
+
+impl SubWalletManager {
+    pub fn new(root: AskarWallet) -> Self {
+        Self { root }
+    }
+
+    pub fn root_wallet(&self) -> &AskarWallet {
+        &self.root
+    }
+
+    pub async fn create_sub_wallet(&self, tenant_id: &str) -> VcxResult<AskarWallet> {
+        trace!("SubWalletManager::create_sub_wallet >> tenant_id: {tenant_id}");
+        Ok(self.root.create_profile(tenant_id).await?)
+    }
+
+    pub async fn get_sub_wallet(&self, tenant_id: &str) -> VcxResult<AskarWallet> {
+        self.check_tenant_id(tenant_id)?;
+        Ok(self.root.for_profile(tenant_id).await?)
+    }
+
+    /// Lists the tenants, excluding the root wallet.
+    pub async fn list_sub_wallets(&self) -> VcxResult<Vec<String>> {
+        Ok(self
+            .root
+            .list_profiles()
+            .await?
+            .into_iter()
+            .filter(|profile| profile != self.root.profile())
+            .collect())
+    }
+
+    /// Removes the sub-wallet with all of its data, and any routes pointing to it.
+    pub async fn remove_sub_wallet(&self, tenant_id: &str) -> VcxResult<()> {
+        trace!("SubWalletManager::remove_sub_wallet >> tenant_id: {tenant_id}");
+        self.check_tenant_id(tenant_id)?;
+
+        for route in self.tenant_routes(tenant_id).await? {
+            self.root
+                .delete_record(RecordCategory::SubWalletRoute, route.name())
+                .await?;
+        }
+
+        Ok(self.root.remove_profile(tenant_id).await?)
+    }
+
+    /// Routes messages for `routing_key` to the sub-wallet of `tenant_id`.
+    pub async fn add_route(&self, tenant_id: &str, routing_key: &Key) -> VcxResult<()> {
+        self.check_tenant_id(tenant_id)?;
+        self.root.for_profile(tenant_id).await?;
+
+        let record = Record::builder()
+            .category(RecordCategory::SubWalletRoute)
+            .name(routing_key.base58())
+            .value(tenant_id.into())
+            .tags(RecordTags::new(vec![RecordTag::new(TENANT_TAG, tenant_id)]))
+            .build();
+
+        Ok(self.root.add_record(record).await?)
+    }
+
+    pub async fn remove_route(&self, routing_key: &Key) -> VcxResult<()> {
+        Ok(self
+            .root
+            .delete_record(RecordCategory::SubWalletRoute, &routing_key.base58())
+            .await?)
+    }
+
+    /// Returns the routing keys mapped to the tenant.
+    pub async fn list_routes(&self, tenant_id: &str) -> VcxResult<Vec<String>> {
+        Ok(self
+            .tenant_routes(tenant_id)
+            .await?
+            .into_iter()
+            .map(|record| record.name().to_owned())
+            .collect())
+    }
+
+    /// Finds the tenant the routing key belongs to, if any.
+    pub async fn resolve_tenant(&self, routing_key: &Key) -> VcxResult<Option<String>> {
+        match self
+            .root
+            .get_record(RecordCategory::SubWalletRoute, &routing_key.base58())
+            .await
+        {
+            Ok(record) => Ok(Some(record.value().to_owned())),
+            Err(err) if err.kind() == AriesVcxCoreErrorKind::WalletRecordNotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Finds the sub-wallet the routing key belongs to, if any.
+    pub async fn resolve_sub_wallet(&self, routing_key: &Key) -> VcxResult<Option<AskarWallet>> {
+        match self.resolve_tenant(routing_key).await? {
+            Some(tenant_id) => Ok(Some(self.root.for_profile(&tenant_id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn tenant_routes(&self, tenant_id: &str) -> VcxResult<Vec<Record>> {
+        Ok(self
+            .root
+            .search_record(
+                RecordCategory::SubWalletRoute,
+                Some(Wql::eq(TENANT_TAG, tenant_id).into()),
+            )
+            .await?)
+    }
+
+    fn check_tenant_id(&self, tenant_id: &str) -> VcxResult<()> {
+        if tenant_id == self.root.profile() {
+            return Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::InvalidInput,
+                "the root profile is not a sub-wallet",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aries_askar::StoreKeyMethod;
+    use aries_vcx_core::wallet::{askar::AskarWallet, base_wallet::DidWallet};
+    use public_key::{Key, KeyType};
+    use uuid::Uuid;
+
+    use super::SubWalletManager;
+    use crate::errors::error::AriesVcxErrorKind;
+
+    async fn setup_manager() -> SubWalletManager {
+        let root = AskarWallet::create(
+            "sqlite://:memory:",
+            StoreKeyMethod::Unprotected,
+            None.into(),
+            true,
+            &Uuid::new_v4().to_string(),
+        )
+        .await
+        .unwrap();
+
+        SubWalletManager::new(root)
+    }
+
+    #[tokio::test]
+    async fn test_sub_wallet_manager_should_route_to_tenant() {
+        let manager = setup_manager().await;
+        let alice = manager.create_sub_wallet("alice").await.unwrap();
+        manager.create_sub_wallet("bob").await.unwrap();
+
+        let did_data = alice.create_and_store_my_did(None, None).await.unwrap();
+        manager.add_route("alice", did_data.verkey()).await.unwrap();
+
+        let res = manager.resolve_tenant(did_data.verkey()).await.unwrap();
+        assert_eq!(Some("alice".to_owned()), res);
+
+        let sub_wallet = manager
+            .resolve_sub_wallet(did_data.verkey())
+            .await
+            .unwrap()
+            .unwrap();
+        let res = sub_wallet.key_for_did(did_data.did()).await.unwrap();
+        assert_eq!(did_data.verkey(), &res);
+
+        let unknown = Key::new(vec![1; 32], KeyType::Ed25519).unwrap();
+        assert!(manager.resolve_tenant(&unknown).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sub_wallet_manager_should_remove_sub_wallet_with_routes() {
+        let manager = setup_manager().await;
+        manager.create_sub_wallet("alice").await.unwrap();
+
+        let key = Key::new(vec![1; 32], KeyType::Ed25519).unwrap();
+        manager.add_route("alice", &key).await.unwrap();
+        assert_eq!(vec!["alice"], manager.list_sub_wallets().await.unwrap());
+
+        manager.remove_sub_wallet("alice").await.unwrap();
+
+        assert!(manager.list_sub_wallets().await.unwrap().is_empty());
+        assert!(manager.resolve_tenant(&key).await.unwrap().is_none());
+
+        let res = manager.add_route("alice", &key).await;
+        assert_eq!(AriesVcxErrorKind::WalletNotFound, res.unwrap_err().kind());
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use aries_vcx_core::{
     anoncreds::base_anoncreds::BaseAnonCreds, ledger::base_ledger::AnoncredsLedgerRead,
     wallet::base_wallet::BaseWallet,
@@ -27,7 +29,7 @@ use messages::{
 use uuid::Uuid;
 
 use crate::{
-    common::credentials::get_cred_rev_id,
+    common::{credentials::get_cred_rev_id, primitives::tails::TailsCache},
     errors::error::prelude::*,
     handlers::{
         mediated_connection::MediatedConnection,
@@ -196,6 +198,18 @@ impl Holder {
         self.holder_sm.get_rev_reg_id()
     }
 
+    /// Fetches the tails file of the credential's revocation registry into the cache, returning
+    /// the tails directory to create revocation states with.
+    pub async fn download_tails_file(&self, tails_cache: &TailsCache) -> VcxResult<PathBuf> {
+        tails_cache
+            .get_or_download_from(
+                &self.get_rev_reg_id()?,
+                &self.get_tails_location()?,
+                &self.get_tails_hash()?,
+            )
+            .await
+    }
+
     pub fn get_cred_id(&self) -> VcxResult<String> {
         self.holder_sm.get_cred_id()
     }
@@ -1,5 +1,6 @@
 use aries_vcx_core::{
-    anoncreds::base_anoncreds::BaseAnonCreds, ledger::base_ledger::AnoncredsLedgerRead,
+    anoncreds::base_anoncreds::BaseAnonCreds,
+    ledger::base_ledger::{AnoncredsLedgerRead, AnoncredsLedgerWrite},
     wallet::base_wallet::BaseWallet,
 };
 use messages::{
@@ -21,6 +22,9 @@ use messages::{
 };
 
 use crate::{
+    common::primitives::revocation_registry_rotation::{
+        CredentialRevocationLocation, RevocationRegistryRotation,
+    },
     errors::error::prelude::*,
     handlers::util::OfferInfo,
     protocols::issuance::issuer::state_machine::{IssuerSM, IssuerState, RevocationInfoV1},
@@ -194,6 +198,26 @@ impl Issuer {
         Ok(())
     }
 
+    /// Builds the credential in the active registry of `registries`, rotating it first if it is
+    /// full. The registry and index the credential was issued with are available through
+    /// `get_revocation_location`.
+    pub async fn build_credential_with_rotation(
+        &mut self,
+        wallet: &impl BaseWallet,
+        anoncreds: &impl BaseAnonCreds,
+        ledger_write: &impl AnoncredsLedgerWrite,
+        registries: &mut RevocationRegistryRotation,
+    ) -> VcxResult<()> {
+        let registry = registries
+            .prepare_issuance(wallet, anoncreds, ledger_write)
+            .await?;
+        self.issuer_sm = self
+            .issuer_sm
+            .clone()
+            .set_revocation_registry(registry.get_rev_reg_id(), registry.get_tails_dir())?;
+        self.build_credential(wallet, anoncreds).await
+    }
+
     pub fn get_msg_issue_credential(&mut self) -> VcxResult<IssueCredentialV1> {
         self.issuer_sm.clone().get_msg_issue_credential()
     }
@@ -225,6 +249,13 @@ impl Issuer {
             .and_then(|s| s.parse().map_err(Into::into))
     }
 
+    pub fn get_revocation_location(&self) -> VcxResult<CredentialRevocationLocation> {
+        Ok(CredentialRevocationLocation {
+            rev_reg_id: self.get_rev_reg_id()?,
+            cred_rev_id: self.get_revocation_id()?,
+        })
+    }
+
     pub async fn revoke_credential_local(
         &self,
         wallet: &impl BaseWallet,
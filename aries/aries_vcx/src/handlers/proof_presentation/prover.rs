@@ -31,6 +31,7 @@ use messages::{
 use uuid::Uuid;
 
 use crate::{
+    common::proofs::prover::credential_selection::{CredentialSelection, CredentialSelector},
     errors::error::prelude::*,
     handlers::util::{get_attach_as_string, PresentationProposalData},
     protocols::{
@@ -94,6 +95,21 @@ impl Prover {
         Ok(json_retrieved_credentials)
     }
 
+    /// Retrieves the candidate credentials and selects among them as configured by `selector`.
+    /// Use [CredentialSelection::into_parts] to obtain the arguments of
+    /// [Prover::generate_presentation].
+    pub async fn select_credentials(
+        &self,
+        wallet: &impl BaseWallet,
+        anoncreds: &impl BaseAnonCreds,
+        selector: &CredentialSelector,
+    ) -> VcxResult<CredentialSelection> {
+        trace!("Prover::select_credentials >>>");
+        let retrieved_credentials = self.retrieve_credentials(wallet, anoncreds).await?;
+        let presentation_request = serde_json::from_str(&self.presentation_request_data()?)?;
+        Ok(selector.select(&presentation_request, &retrieved_credentials))
+    }
+
     pub async fn generate_presentation(
         &mut self,
         wallet: &impl BaseWallet,
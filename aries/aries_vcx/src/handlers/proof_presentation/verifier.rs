@@ -18,6 +18,10 @@ use messages::{
 };
 
 use crate::{
+    common::proofs::verifier::{
+        verification_policy::VerificationPolicy,
+        verification_result::PresentationVerificationResult,
+    },
     errors::error::prelude::*,
     handlers::util::get_attach_as_string,
     protocols::{
@@ -145,6 +149,21 @@ impl Verifier {
         self.verifier_sm.get_verification_status()
     }
 
+    /// Sets the policy presentations are checked against, e.g. allowed issuers or required
+    /// non-revocation, before their proofs are verified.
+    pub fn set_verification_policy(&mut self, verification_policy: VerificationPolicy) {
+        self.verifier_sm = self
+            .verifier_sm
+            .clone()
+            .set_verification_policy(verification_policy);
+    }
+
+    /// Outcome of the verification for each requested referent, including the reasons an
+    /// invalid presentation was rejected for.
+    pub fn get_verification_result(&self) -> VcxResult<PresentationVerificationResult> {
+        self.verifier_sm.get_verification_result()
+    }
+
     pub fn get_presentation_attachment(&self) -> VcxResult<String> {
         let presentation = &self.verifier_sm.get_presentation_msg()?;
         Ok(get_attach_as_string!(
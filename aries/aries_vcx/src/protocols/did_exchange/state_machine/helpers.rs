@@ -2,18 +2,10 @@ use std::collections::HashMap;
 
 use aries_vcx_core::wallet::base_wallet::BaseWallet;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
-use did_doc::schema::{
-    types::uri::Uri,
-    verification_method::{VerificationMethod, VerificationMethodKind, VerificationMethodType},
-};
-use did_doc_sov::{
-    extra_fields::{didcommv1::ExtraFieldsDidCommV1, KeyKind},
-    service::{didcommv1::ServiceDidCommV1, ServiceSov},
-    DidDocumentSov,
-};
+use did_doc_sov::DidDocumentSov;
 use did_key::DidKey;
-use did_parser::{Did, DidUrl};
-use did_peer::peer_did::{numalgos::numalgo2::Numalgo2, PeerDid};
+use did_peer::registrar::{options::PeerDidCreateOptions, PeerDidRegistrar};
+use did_registrar::registration_options::DidRegistrationOptions;
 use messages::decorators::attachment::{Attachment, AttachmentData, AttachmentType};
 use public_key::{Key, KeyType};
 use serde_json::Value;
@@ -35,79 +27,20 @@ pub async fn generate_keypair(
     Ok(Key::from_base58(&pairwise_info.pw_vk, key_type)?)
 }
 
-pub fn construct_service(
-    routing_keys: Vec<KeyKind>,
-    recipient_keys: Vec<KeyKind>,
-    service_endpoint: Url,
-) -> Result<ServiceSov, AriesVcxError> {
-    let extra = ExtraFieldsDidCommV1::builder()
-        .set_routing_keys(routing_keys)
-        .set_recipient_keys(recipient_keys)
-        .build();
-    let service = ServiceSov::DIDCommV1(ServiceDidCommV1::new(
-        Uri::new("#0")?,
-        service_endpoint.into(),
-        extra,
-    )?);
-    Ok(service)
-}
-
 pub async fn create_our_did_document(
     wallet: &impl BaseWallet,
     service_endpoint: Url,
     routing_keys: Vec<String>,
 ) -> Result<(DidDocumentSov, Key), AriesVcxError> {
-    let key_ver = generate_keypair(wallet, KeyType::Ed25519).await?;
-    let key_enc = generate_keypair(wallet, KeyType::X25519).await?;
-    let service = construct_service(
-        routing_keys.into_iter().map(KeyKind::Value).collect(),
-        vec![KeyKind::DidKey(key_enc.clone().try_into()?)],
-        service_endpoint,
-    )?;
-
-    // TODO: Make it easier to generate peer did from keys and service, and generate DDO from it
-    let did_document_temp = did_doc_from_keys(
-        Default::default(),
-        key_ver.clone(),
-        key_enc.clone(),
-        service.clone(),
-    )?;
-    let peer_did = PeerDid::<Numalgo2>::from_did_doc(did_document_temp.into())?;
-
-    Ok((
-        did_doc_from_keys(peer_did.into(), key_ver, key_enc.clone(), service)?,
-        key_enc,
-    ))
-}
-
-fn did_doc_from_keys(
-    did: Did,
-    key_ver: Key,
-    key_enc: Key,
-    service: ServiceSov,
-) -> Result<DidDocumentSov, AriesVcxError> {
-    let vm_ver_id = DidUrl::from_fragment(key_ver.short_prefixless_fingerprint())?;
-    let vm_ka_id = DidUrl::from_fragment(key_enc.short_prefixless_fingerprint())?;
-    let vm_ver = VerificationMethod::builder(
-        vm_ver_id,
-        did.clone(),
-        VerificationMethodType::Ed25519VerificationKey2020,
-    )
-    .add_public_key_base58(key_ver.base58())
-    .build();
-    let vm_ka = VerificationMethod::builder(
-        vm_ka_id,
-        did.clone(),
-        VerificationMethodType::X25519KeyAgreementKey2020,
-    )
-    .add_public_key_base58(key_enc.base58())
-    .build();
-    Ok(DidDocumentSov::builder(did)
-        .add_service(service)
-        .add_verification_method(vm_ver)
-        // TODO: Include just reference
-        .add_key_agreement(VerificationMethodKind::Resolved(vm_ka))
-        .build())
+    let options = DidRegistrationOptions::new(
+        PeerDidCreateOptions::new()
+            .set_service_endpoint(service_endpoint.into())
+            .set_routing_keys(routing_keys),
+    );
+    let (did_document, key_enc) = PeerDidRegistrar::new()
+        .create_did_document(wallet, &options)
+        .await?;
+    Ok((did_document.into(), key_enc))
 }
 
 pub fn ddo_sov_to_attach(ddo: DidDocumentSov) -> Result<Attachment, AriesVcxError> {
@@ -423,6 +423,35 @@ impl IssuerSM {
         Ok(Self { state, ..self })
     }
 
+    /// Sets the revocation registry the credential is issued in, until the credential is built.
+    pub fn set_revocation_registry(
+        self,
+        rev_reg_id: String,
+        tails_file: String,
+    ) -> VcxResult<Self> {
+        let state = match self.state {
+            IssuerFullState::OfferSet(state_data) => IssuerFullState::OfferSet(OfferSetState {
+                rev_reg_id: Some(rev_reg_id),
+                tails_file: Some(tails_file),
+                ..state_data
+            }),
+            IssuerFullState::RequestReceived(state_data) => {
+                IssuerFullState::RequestReceived(RequestReceivedState {
+                    rev_reg_id: Some(rev_reg_id),
+                    tails_file: Some(tails_file),
+                    ..state_data
+                })
+            }
+            _ => {
+                return Err(AriesVcxError::from_msg(
+                    AriesVcxErrorKind::NotReady,
+                    "Revocation registry can only be set before the credential is built",
+                ));
+            }
+        };
+        Ok(Self { state, ..self })
+    }
+
     pub async fn build_credential(
         self,
         wallet: &impl BaseWallet,
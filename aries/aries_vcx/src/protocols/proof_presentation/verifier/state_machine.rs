@@ -26,6 +26,10 @@ use messages::{
 use uuid::Uuid;
 
 use crate::{
+    common::proofs::verifier::{
+        verification_policy::VerificationPolicy,
+        verification_result::PresentationVerificationResult,
+    },
     errors::error::prelude::*,
     handlers::util::{make_attach_from_str, verify_thread_id, AttachmentId, Status},
     protocols::{
@@ -47,6 +51,8 @@ pub struct VerifierSM {
     source_id: String,
     thread_id: String,
     state: VerifierFullState,
+    #[serde(default)]
+    verification_policy: VerificationPolicy,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -140,6 +146,7 @@ impl VerifierSM {
             thread_id: String::new(),
             source_id: source_id.to_string(),
             state: VerifierFullState::Initial(InitialVerifierState {}),
+            verification_policy: VerificationPolicy::default(),
         }
     }
 
@@ -153,6 +160,7 @@ impl VerifierSM {
             source_id: source_id.to_string(),
             thread_id: Uuid::new_v4().to_string(),
             state: VerifierFullState::Initial(InitialVerifierState {}),
+            verification_policy: VerificationPolicy::default(),
         };
         sm.set_presentation_request(presentation_request_data, None)
     }
@@ -164,6 +172,7 @@ impl VerifierSM {
             state: VerifierFullState::PresentationProposalReceived(
                 PresentationProposalReceivedState::new(presentation_proposal.clone()),
             ),
+            verification_policy: VerificationPolicy::default(),
         }
     }
 
@@ -260,12 +269,18 @@ impl VerifierSM {
         let state = match self.state {
             VerifierFullState::PresentationRequestSent(state) => {
                 let verification_result = state
-                    .verify_presentation(ledger, anoncreds, &presentation, &self.thread_id)
+                    .verify_presentation(
+                        ledger,
+                        anoncreds,
+                        &presentation,
+                        &self.thread_id,
+                        &self.verification_policy,
+                    )
                     .await;
 
                 match verification_result {
-                    Ok(()) => VerifierFullState::Finished(
-                        (state, presentation, PresentationVerificationStatus::Valid).into(),
+                    Ok(verification_result) => VerifierFullState::Finished(
+                        (state, presentation, verification_result).into(),
                     ),
                     Err(err) => {
                         let problem_report =
@@ -329,6 +344,7 @@ impl VerifierSM {
             source_id,
             thread_id,
             state,
+            verification_policy,
         } = self;
         let state = match state {
             VerifierFullState::Initial(_)
@@ -351,6 +367,7 @@ impl VerifierSM {
             source_id,
             state,
             thread_id,
+            verification_policy,
         })
     }
 
@@ -359,6 +376,7 @@ impl VerifierSM {
             state,
             source_id,
             thread_id,
+            verification_policy,
         } = self;
         let state = match state {
             VerifierFullState::PresentationRequestSet(state) => {
@@ -378,6 +396,7 @@ impl VerifierSM {
             source_id,
             thread_id,
             state,
+            verification_policy,
         })
     }
 
@@ -414,6 +433,14 @@ impl VerifierSM {
         }
     }
 
+    /// Sets the policy presentations are checked against before their proofs are verified.
+    pub fn set_verification_policy(self, verification_policy: VerificationPolicy) -> Self {
+        Self {
+            verification_policy,
+            ..self
+        }
+    }
+
     pub fn get_verification_status(&self) -> PresentationVerificationStatus {
         match self.state {
             VerifierFullState::Finished(ref state) => state.verification_status.clone(),
@@ -421,6 +448,24 @@ impl VerifierSM {
         }
     }
 
+    pub fn get_verification_result(&self) -> VcxResult<PresentationVerificationResult> {
+        match self.state {
+            VerifierFullState::Finished(ref state) => {
+                state
+                    .verification_result
+                    .clone()
+                    .ok_or(AriesVcxError::from_msg(
+                        AriesVcxErrorKind::InvalidState,
+                        "State machine is final state, but presentation was not verified",
+                    ))
+            }
+            _ => Err(AriesVcxError::from_msg(
+                AriesVcxErrorKind::NotReady,
+                "Presentation not verified yet",
+            )),
+        }
+    }
+
     pub fn presentation_request_msg(&self) -> VcxResult<RequestPresentationV1> {
         match self.state {
             VerifierFullState::Initial(_) => Err(AriesVcxError::from_msg(
@@ -5,6 +5,7 @@ use messages::msg_fields::protocols::{
 use serde::Deserialize;
 
 use crate::{
+    common::proofs::verifier::verification_result::PresentationVerificationResult,
     handlers::util::Status,
     protocols::proof_presentation::verifier::verification_status::PresentationVerificationStatus,
 };
@@ -15,6 +16,8 @@ pub struct FinishedState {
     pub presentation: Option<PresentationV1>,
     pub status: Status,
     pub verification_status: PresentationVerificationStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_result: Option<PresentationVerificationResult>,
 }
 
 impl FinishedState {
@@ -25,6 +28,7 @@ impl FinishedState {
             presentation: None,
             status: Status::Declined(problem_report),
             verification_status: PresentationVerificationStatus::Unavailable,
+            verification_result: None,
         }
     }
 }
@@ -40,6 +44,7 @@ pub mod unit_tests {
             presentation: None,
             status: Status::Success,
             verification_status: PresentationVerificationStatus::Valid,
+            verification_result: None,
         };
         let serialized = serde_json::to_string(&state).unwrap();
         let expected = r#"{"presentation_request":null,"presentation":null,"status":"Success","verification_status":"Valid"}"#;
@@ -55,6 +60,7 @@ pub mod unit_tests {
             presentation: None,
             status: Status::Success,
             verification_status: PresentationVerificationStatus::Unavailable,
+            verification_result: None,
         };
         let serialized = serde_json::to_string(&state).unwrap();
         let expected = r#"{"presentation_request":null,"presentation":null,"status":"Success","verification_status":"Unavailable"}"#;
@@ -70,6 +76,7 @@ pub mod unit_tests {
             presentation: None,
             status: Status::Success,
             verification_status: PresentationVerificationStatus::Invalid,
+            verification_result: None,
         };
         let serialized = serde_json::to_string(&state).unwrap();
         let expected = r#"{"presentation_request":null,"presentation":null,"status":"Success","verification_status":"Invalid"}"#;
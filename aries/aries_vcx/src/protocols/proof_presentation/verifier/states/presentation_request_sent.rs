@@ -7,7 +7,10 @@ use messages::msg_fields::protocols::{
 };
 
 use crate::{
-    common::proofs::verifier::validate_indy_proof,
+    common::proofs::verifier::{
+        verification_policy::VerificationPolicy,
+        verification_result::PresentationVerificationResult, verify_indy_proof_detailed,
+    },
     errors::error::{AriesVcxError, AriesVcxErrorKind, VcxResult},
     handlers::util::{get_attach_as_string, matches_thread_id, Status},
     protocols::proof_presentation::verifier::{
@@ -27,7 +30,8 @@ impl PresentationRequestSentState {
         anoncreds: &impl BaseAnonCreds,
         presentation: &PresentationV1,
         thread_id: &str,
-    ) -> VcxResult<()> {
+        policy: &VerificationPolicy,
+    ) -> VcxResult<PresentationVerificationResult> {
         if !matches_thread_id!(presentation, thread_id) {
             return Err(AriesVcxError::from_msg(
                 AriesVcxErrorKind::InvalidJson,
@@ -46,16 +50,7 @@ impl PresentationRequestSentState {
                 .request_presentations_attach
         );
 
-        let valid = validate_indy_proof(ledger, anoncreds, &proof_json, &proof_req_json).await?;
-
-        if !valid {
-            return Err(AriesVcxError::from_msg(
-                AriesVcxErrorKind::InvalidProof,
-                "Presentation verification failed",
-            ));
-        }
-
-        Ok(())
+        verify_indy_proof_detailed(ledger, anoncreds, &proof_json, &proof_req_json, policy).await
     }
 }
 
@@ -79,6 +74,32 @@ impl
             presentation: Some(presentation),
             status: Status::Success,
             verification_status,
+            verification_result: None,
+        }
+    }
+}
+
+impl
+    From<(
+        PresentationRequestSentState,
+        PresentationV1,
+        PresentationVerificationResult,
+    )> for FinishedState
+{
+    fn from(
+        (state, presentation, verification_result): (
+            PresentationRequestSentState,
+            PresentationV1,
+            PresentationVerificationResult,
+        ),
+    ) -> Self {
+        trace!("transit state from PresentationRequestSentState to FinishedState");
+        FinishedState {
+            presentation_request: Some(state.presentation_request),
+            presentation: Some(presentation),
+            status: Status::Success,
+            verification_status: verification_result.status.clone(),
+            verification_result: Some(verification_result),
         }
     }
 }
@@ -95,6 +116,7 @@ impl From<(PresentationRequestSentState, ProblemReport)> for FinishedState {
             presentation: None,
             status: Status::Failed(problem_report),
             verification_status: PresentationVerificationStatus::Unavailable,
+            verification_result: None,
         }
     }
 }
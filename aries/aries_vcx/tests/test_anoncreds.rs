@@ -166,3 +166,150 @@ async fn test_pool_revoke_credential() -> Result<(), Box<dyn Error>> {
     assert_ne!(first_rev_reg_delta, second_rev_reg_delta);
     Ok(())
 }
+
+#[cfg(feature = "anoncreds")]
+#[tokio::test]
+async fn test_w3c_issue_present_verify() -> Result<(), Box<dyn Error>> {
+    use std::collections::HashMap;
+
+    use anoncreds_types::data_types::messages::{
+        pres_request::PresentationRequest,
+        presentation::{RequestedAttribute, RequestedCredentials},
+    };
+    use aries_vcx::common::credentials::encoding::encode_attributes;
+    use aries_vcx_core::{
+        anoncreds::base_anoncreds::{CredentialDefinitionsMap, SchemasMap},
+        anoncreds_registry::local::LocalAnoncredsRegistry,
+        global::settings::DEFAULT_LINK_SECRET_ALIAS,
+    };
+    use did_parser::Did;
+    use test_utils::{
+        constants::TRUSTEE_SEED,
+        devsetup::{dev_build_featured_anoncreds, dev_build_featured_wallet},
+    };
+
+    let (institution_did, wallet) = dev_build_featured_wallet(TRUSTEE_SEED).await;
+    let institution_did = Did::parse(institution_did)?;
+    let anoncreds = dev_build_featured_anoncreds().await;
+    anoncreds
+        .prover_create_link_secret(&wallet, &DEFAULT_LINK_SECRET_ALIAS.to_string())
+        .await?;
+    let registry = LocalAnoncredsRegistry::in_memory();
+    let schema = create_and_write_test_schema(
+        &wallet,
+        &anoncreds,
+        &registry,
+        &institution_did,
+        DEFAULT_SCHEMA_ATTRS,
+    )
+    .await;
+    let cred_def = create_and_write_test_cred_def(
+        &wallet,
+        &anoncreds,
+        &registry,
+        &registry,
+        &institution_did,
+        &schema.schema_id,
+        false,
+    )
+    .await;
+    let cred_def_json = cred_def.get_cred_def_json();
+
+    let offer = anoncreds
+        .issuer_create_credential_offer(&wallet, cred_def.get_cred_def_id())
+        .await?;
+    let (req, req_meta) = anoncreds
+        .prover_create_credential_req(
+            &wallet,
+            &institution_did,
+            serde_json::from_value(serde_json::to_value(&offer)?)?,
+            cred_def_json.try_clone()?,
+            &DEFAULT_LINK_SECRET_ALIAS.to_string(),
+        )
+        .await?;
+    let credential_data = r#"{"address1": ["123 Main St"], "address2": ["Suite 3"], "city": ["Draper"], "state": ["UT"], "zip": ["84000"]}"#;
+    let (w3c_credential, cred_rev_id) = anoncreds
+        .issuer_create_w3c_credential(
+            &wallet,
+            offer,
+            req,
+            serde_json::from_str(&encode_attributes(credential_data)?)?,
+            None,
+            None,
+        )
+        .await?;
+    assert!(cred_rev_id.is_none());
+
+    let cred_id = anoncreds
+        .prover_store_w3c_credential(
+            &wallet,
+            req_meta,
+            w3c_credential,
+            cred_def_json.try_clone()?,
+            None,
+        )
+        .await?;
+
+    let proof_req: PresentationRequest = serde_json::from_value(json!({
+        "nonce": "123432421212",
+        "name": "proof_req_1",
+        "version": "1.0",
+        "requested_attributes": {
+            "address1_1": {
+                "name": "address1",
+                "restrictions": [{ "issuer_did": institution_did }]
+            }
+        },
+        "requested_predicates": {}
+    }))?;
+    let requested_credentials = RequestedCredentials {
+        self_attested_attributes: HashMap::new(),
+        requested_attributes: HashMap::from([(
+            "address1_1".to_owned(),
+            RequestedAttribute {
+                cred_id,
+                timestamp: None,
+                revealed: true,
+            },
+        )]),
+        requested_predicates: HashMap::new(),
+    };
+    let schema_id = cred_def.get_schema_id().clone();
+    let schemas: SchemasMap = HashMap::from([(
+        schema_id.clone(),
+        registry.get_schema(&schema_id, None).await?,
+    )]);
+    let cred_defs = || -> Result<CredentialDefinitionsMap, Box<dyn Error>> {
+        Ok(HashMap::from([(
+            cred_def.get_cred_def_id().clone(),
+            cred_def_json.try_clone()?,
+        )]))
+    };
+
+    let presentation = anoncreds
+        .prover_create_w3c_presentation(
+            &wallet,
+            proof_req.clone(),
+            requested_credentials,
+            &DEFAULT_LINK_SECRET_ALIAS.to_string(),
+            schemas.clone(),
+            cred_defs()?,
+            None,
+        )
+        .await?;
+
+    assert!(
+        anoncreds
+            .verifier_verify_w3c_presentation(
+                proof_req,
+                presentation,
+                schemas,
+                cred_defs()?,
+                None,
+                None
+            )
+            .await?
+    );
+
+    Ok(())
+}
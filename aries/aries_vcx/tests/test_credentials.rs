@@ -1,14 +1,25 @@
-use std::error::Error;
+use std::{error::Error, time::Duration};
 
-use aries_vcx::common::credentials::{get_cred_rev_id, is_cred_revoked};
+use aries_vcx::common::credentials::{
+    get_cred_rev_id, is_cred_revoked,
+    store::{CredentialMetadata, CredentialQuery, HolderCredentialStore},
+};
 use aries_vcx_core::{
     anoncreds::base_anoncreds::BaseAnonCreds, ledger::base_ledger::AnoncredsLedgerRead,
 };
-use test_utils::{constants::DEFAULT_SCHEMA_ATTRS, devsetup::build_setup_profile};
+use test_utils::{
+    constants::DEFAULT_SCHEMA_ATTRS,
+    devsetup::{build_setup_profile, SetupPoolDirectory},
+};
 
 use crate::utils::{
     create_and_publish_test_rev_reg, create_and_write_credential, create_and_write_test_cred_def,
     create_and_write_test_schema,
+    scenarios::{
+        create_address_schema_creddef_revreg, create_credential_offer, create_credential_request,
+        credential_data_address_1, revoke_credential_and_publish_accumulator, send_credential,
+    },
+    test_agent::{create_test_agent, create_test_agent_trustee},
 };
 
 pub mod utils;
@@ -139,3 +150,90 @@ async fn test_pool_is_cred_revoked() -> Result<(), Box<dyn Error>> {
     assert!(is_cred_revoked(&setup.ledger_read, &rev_reg.rev_reg_id, cred_rev_id).await?);
     Ok(())
 }
+
+#[tokio::test]
+#[ignore]
+async fn test_pool_holder_credential_store() -> Result<(), Box<dyn Error>> {
+    let setup = SetupPoolDirectory::init().await;
+    let mut institution = create_test_agent_trustee(setup.genesis_file_path.clone()).await;
+    let mut consumer = create_test_agent(setup.genesis_file_path).await;
+
+    let (schema, cred_def, rev_reg) = create_address_schema_creddef_revreg(
+        &institution.wallet,
+        &institution.ledger_read,
+        &institution.ledger_write,
+        &institution.anoncreds,
+        &institution.institution_did,
+    )
+    .await;
+    let mut issuer = create_credential_offer(
+        &mut institution,
+        &cred_def,
+        &rev_reg,
+        &credential_data_address_1().to_string(),
+        None,
+    )
+    .await;
+    let mut holder = create_credential_request(&mut consumer, issuer.get_credential_offer()?).await;
+    let cred_request = holder.get_msg_credential_request()?;
+    send_credential(
+        &mut consumer,
+        &mut institution,
+        &mut issuer,
+        &mut holder,
+        cred_request,
+        true,
+    )
+    .await;
+    let cred_id = holder.get_cred_id()?;
+
+    let store = HolderCredentialStore::new(&consumer.wallet, &consumer.anoncreds);
+    let metadata = store.record_credential(&holder, Some("connection")).await?;
+    assert_eq!(Some("connection"), metadata.connection_id.as_deref());
+    assert_eq!(Some(holder.get_thread_id()?), metadata.thread_id);
+    store.add_label(&cred_id, "work").await?;
+    let recorded = store.record_credential(&holder, Some("connection")).await?;
+    assert_eq!(metadata.stored_at, recorded.stored_at);
+    assert!(recorded.labels.contains("work"));
+
+    let found = store
+        .search(
+            &CredentialQuery::new()
+                .schema_id(&schema.schema_id.to_string())
+                .connection_id("connection")
+                .label("work"),
+        )
+        .await?;
+    assert_eq!(1, found.len());
+    assert_eq!(cred_id, found[0].info.referent);
+    assert_eq!(recorded, found[0].metadata);
+    assert!(store
+        .search(&CredentialQuery::new().label("personal"))
+        .await?
+        .is_empty());
+
+    let statuses = store
+        .refresh_revocation_status(&consumer.ledger_read, std::slice::from_ref(&holder))
+        .await;
+    assert!(matches!(statuses[&cred_id], Ok(false)));
+
+    revoke_credential_and_publish_accumulator(&mut institution, &issuer, &rev_reg).await;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let statuses = store
+        .refresh_revocation_status(&consumer.ledger_read, std::slice::from_ref(&holder))
+        .await;
+    assert!(matches!(statuses[&cred_id], Ok(true)));
+    let revocation_status = store.get_metadata(&cred_id).await?.revocation_status;
+    assert!(revocation_status.unwrap().revoked);
+
+    store.delete(&cred_id).await?;
+    assert!(store
+        .search(&CredentialQuery::new().connection_id("connection"))
+        .await?
+        .is_empty());
+    assert_eq!(
+        CredentialMetadata::default(),
+        store.get_metadata(&cred_id).await?
+    );
+    Ok(())
+}
@@ -0,0 +1,313 @@
+use std::{collections::HashMap, error::Error, net::TcpListener};
+
+use anoncreds_types::data_types::messages::pres_request::PresentationRequest;
+use aries_vcx::common::{
+    credentials::{get_cred_rev_id, is_cred_revoked},
+    primitives::{
+        credential_definition::CredentialDef, revocation_registry::RevocationRegistry,
+        revocation_registry_rotation::RevocationRegistryRotation,
+    },
+    proofs::{
+        prover::{credential_selection::CredentialSelector, generate_indy_proof},
+        verifier::validate_indy_proof,
+    },
+};
+use aries_vcx_core::{
+    anoncreds::base_anoncreds::BaseAnonCreds, anoncreds_registry::local::LocalAnoncredsRegistry,
+    global::settings::DEFAULT_LINK_SECRET_ALIAS, ledger::base_ledger::AnoncredsLedgerRead,
+    wallet::base_wallet::BaseWallet,
+};
+use did_parser::Did;
+use serde_json::json;
+use test_utils::{
+    constants::TRUSTEE_SEED,
+    devsetup::{dev_build_featured_anoncreds, dev_build_featured_wallet},
+};
+use url::Url;
+
+use crate::utils::{
+    create_and_publish_test_rev_reg, create_and_write_credential, create_and_write_test_cred_def,
+    create_and_write_test_schema,
+};
+
+pub mod utils;
+
+const ADDRESS_ATTRS: &str = r#"["address1", "address2", "city", "state", "zip"]"#;
+
+async fn prove_and_verify_address(
+    wallet: &impl BaseWallet,
+    anoncreds: &impl BaseAnonCreds,
+    registry: &LocalAnoncredsRegistry,
+    issuer_did: &Did,
+    rev_reg: &RevocationRegistry,
+) -> Result<bool, Box<dyn Error>> {
+    let to = time::OffsetDateTime::now_utc().unix_timestamp() as u64;
+    let proof_req: PresentationRequest = serde_json::from_value(json!({
+        "nonce": "123432421212",
+        "name": "proof_req_1",
+        "version": "1.0",
+        "requested_attributes": {
+            "address1_1": {
+                "name": "address1",
+                "restrictions": [{ "issuer_did": issuer_did }]
+            }
+        },
+        "requested_predicates": {},
+        "non_revoked": { "to": to }
+    }))?;
+
+    let retrieved = anoncreds
+        .prover_get_credentials_for_proof_req(wallet, proof_req.clone())
+        .await?;
+    let (selected_credentials, self_attested_attrs) = CredentialSelector::new()
+        .tails_dirs(HashMap::from([(
+            rev_reg.rev_reg_id.clone(),
+            rev_reg.tails_dir.clone(),
+        )]))
+        .select(&proof_req, &retrieved)
+        .into_parts()?;
+
+    let proof = generate_indy_proof(
+        wallet,
+        registry,
+        anoncreds,
+        &selected_credentials,
+        self_attested_attrs,
+        proof_req.clone(),
+    )
+    .await?;
+
+    Ok(validate_indy_proof(
+        registry,
+        anoncreds,
+        &serde_json::to_string(&proof)?,
+        &serde_json::to_string(&proof_req)?,
+    )
+    .await?)
+}
+
+struct IssuerSetup<W, A> {
+    institution_did: Did,
+    wallet: W,
+    anoncreds: A,
+    registry: LocalAnoncredsRegistry,
+    cred_def: CredentialDef,
+}
+
+async fn setup_cred_def(
+    revocable: bool,
+) -> Result<IssuerSetup<impl BaseWallet, impl BaseAnonCreds>, Box<dyn Error>> {
+    let (institution_did, wallet) = dev_build_featured_wallet(TRUSTEE_SEED).await;
+    let institution_did = Did::parse(institution_did)?;
+    let anoncreds = dev_build_featured_anoncreds().await;
+    anoncreds
+        .prover_create_link_secret(&wallet, &DEFAULT_LINK_SECRET_ALIAS.to_string())
+        .await?;
+    let registry = LocalAnoncredsRegistry::in_memory();
+
+    let schema = create_and_write_test_schema(
+        &wallet,
+        &anoncreds,
+        &registry,
+        &institution_did,
+        ADDRESS_ATTRS,
+    )
+    .await;
+    let cred_def = create_and_write_test_cred_def(
+        &wallet,
+        &anoncreds,
+        &registry,
+        &registry,
+        &institution_did,
+        &schema.schema_id,
+        revocable,
+    )
+    .await;
+
+    Ok(IssuerSetup {
+        institution_did,
+        wallet,
+        anoncreds,
+        registry,
+        cred_def,
+    })
+}
+
+struct IssuedCredential<W, A> {
+    institution_did: Did,
+    wallet: W,
+    anoncreds: A,
+    registry: LocalAnoncredsRegistry,
+    rev_reg: RevocationRegistry,
+    cred_rev_id: u32,
+}
+
+async fn issue_revocable_credential(
+) -> Result<IssuedCredential<impl BaseWallet, impl BaseAnonCreds>, Box<dyn Error>> {
+    let IssuerSetup {
+        institution_did,
+        wallet,
+        anoncreds,
+        registry,
+        cred_def,
+    } = setup_cred_def(true).await?;
+
+    let rev_reg = create_and_publish_test_rev_reg(
+        &wallet,
+        &anoncreds,
+        &registry,
+        &institution_did,
+        cred_def.get_cred_def_id(),
+    )
+    .await;
+    let cred_id = create_and_write_credential(
+        &wallet,
+        &wallet,
+        &anoncreds,
+        &anoncreds,
+        &institution_did,
+        &cred_def,
+        Some(&rev_reg),
+    )
+    .await;
+    let cred_rev_id = get_cred_rev_id(&wallet, &anoncreds, &cred_id).await?;
+
+    Ok(IssuedCredential {
+        institution_did,
+        wallet,
+        anoncreds,
+        registry,
+        rev_reg,
+        cred_rev_id,
+    })
+}
+
+#[tokio::test]
+async fn test_local_registry_issue_revoke_verify() -> Result<(), Box<dyn Error>> {
+    let IssuedCredential {
+        institution_did,
+        wallet,
+        anoncreds,
+        registry,
+        rev_reg,
+        cred_rev_id,
+    } = issue_revocable_credential().await?;
+
+    assert!(!is_cred_revoked(&registry, &rev_reg.rev_reg_id, cred_rev_id).await?);
+    assert!(
+        prove_and_verify_address(&wallet, &anoncreds, &registry, &institution_did, &rev_reg)
+            .await?
+    );
+
+    rev_reg
+        .revoke_credential_local(&wallet, &anoncreds, &registry, cred_rev_id)
+        .await?;
+    rev_reg
+        .publish_local_revocations(&wallet, &anoncreds, &registry, &institution_did)
+        .await?;
+
+    assert!(is_cred_revoked(&registry, &rev_reg.rev_reg_id, cred_rev_id).await?);
+    // the prover either fails to build a non-revocation proof, or the proof does not verify
+    let verified =
+        prove_and_verify_address(&wallet, &anoncreds, &registry, &institution_did, &rev_reg).await;
+    assert!(!matches!(verified, Ok(true)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_local_registry_revoke_credentials_and_publish() -> Result<(), Box<dyn Error>> {
+    let IssuedCredential {
+        institution_did,
+        wallet,
+        anoncreds,
+        registry,
+        rev_reg,
+        cred_rev_id,
+    } = issue_revocable_credential().await?;
+
+    let rev_status_list = rev_reg
+        .revoke_credentials_and_publish(&wallet, &anoncreds, &registry, &registry, &[cred_rev_id])
+        .await?;
+    assert!(rev_status_list.state()[cred_rev_id as usize]);
+    assert!(is_cred_revoked(&registry, &rev_reg.rev_reg_id, cred_rev_id).await?);
+
+    // the published list matches what the ledger now reports
+    let (ledger_rev_status_list, _) = registry
+        .get_rev_status_list(
+            &rev_reg.rev_reg_id.to_owned().try_into()?,
+            None,
+            Some(&rev_reg.get_rev_reg_def()),
+        )
+        .await?;
+    assert_eq!(rev_status_list.state(), ledger_rev_status_list.state());
+
+    let verified =
+        prove_and_verify_address(&wallet, &anoncreds, &registry, &institution_did, &rev_reg).await;
+    assert!(!matches!(verified, Ok(true)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_local_registry_rotates_full_registry() -> Result<(), Box<dyn Error>> {
+    let IssuerSetup {
+        institution_did,
+        wallet,
+        anoncreds,
+        registry,
+        cred_def,
+    } = setup_cred_def(true).await?;
+
+    let tails_root = std::env::temp_dir().join(format!("tails_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&tails_root)?;
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let tails_server_url = Url::parse(&format!("http://{}", listener.local_addr()?))?;
+    tokio::spawn(tails_server::serve(listener, tails_root.join("server")));
+
+    let new_rotation = || {
+        RevocationRegistryRotation::new(
+            institution_did.clone(),
+            cred_def.get_cred_def_id().clone(),
+            tails_root.to_str().unwrap(),
+            tails_server_url.clone(),
+            2,
+        )
+    };
+
+    let mut rotation = new_rotation();
+    let mut issued_with = Vec::new();
+    for _ in 0..4 {
+        let rev_reg = rotation
+            .prepare_issuance(&wallet, &anoncreds, &registry)
+            .await?
+            .clone();
+        create_and_write_credential(
+            &wallet,
+            &wallet,
+            &anoncreds,
+            &anoncreds,
+            &institution_did,
+            &cred_def,
+            Some(&rev_reg),
+        )
+        .await;
+        issued_with.push(rev_reg.rev_reg_id);
+    }
+
+    assert_eq!(2, rotation.registries().count());
+    assert_eq!(issued_with[0], issued_with[1]);
+    assert_ne!(issued_with[1], issued_with[2]);
+    assert_eq!(issued_with[2], issued_with[3]);
+
+    // a restored rotation reads the issuance count of its active registry from the wallet
+    let active = rotation.active_registry().unwrap().clone();
+    let mut restored = new_rotation().with_active_registry(active.clone());
+    let next = restored
+        .prepare_issuance(&wallet, &anoncreds, &registry)
+        .await?;
+    assert_ne!(active.rev_reg_id, next.rev_reg_id);
+
+    std::fs::remove_dir_all(tails_root)?;
+    Ok(())
+}
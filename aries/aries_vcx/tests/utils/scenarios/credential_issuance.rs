@@ -377,7 +377,7 @@ pub async fn exchange_credential_with_proposal(
     (holder, issuer)
 }
 
-async fn create_credential_offer(
+pub async fn create_credential_offer(
     faber: &mut TestAgent<
         impl IndyLedgerRead + AnoncredsLedgerRead,
         impl IndyLedgerWrite + AnoncredsLedgerWrite,
@@ -408,7 +408,7 @@ async fn create_credential_offer(
     issuer
 }
 
-async fn create_credential_request(
+pub async fn create_credential_request(
     alice: &mut TestAgent<
         impl IndyLedgerRead + AnoncredsLedgerRead,
         impl IndyLedgerWrite + AnoncredsLedgerWrite,
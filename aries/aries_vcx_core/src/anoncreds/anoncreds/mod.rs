@@ -18,6 +18,10 @@ use anoncreds::{
             RevocationRegistryDefinitionId as AnoncredsRevocationRegistryDefinitionId, CL_ACCUM,
         },
         schema::{Schema as AnoncredsSchema, SchemaId as AnoncredsSchemaId},
+        w3c::{
+            credential::W3CCredential as AnoncredsW3CCredential,
+            presentation::W3CPresentation as AnoncredsW3CPresentation,
+        },
     },
     issuer::{create_revocation_registry_def, create_revocation_status_list},
     tails::TailsFileWriter,
@@ -33,6 +37,7 @@ use anoncreds::{
         RevocationRegistryDefinition as AnoncredsRevocationRegistryDefinition,
         RevocationStatusList,
     },
+    w3c::credential_conversion::{credential_from_w3c, credential_to_w3c},
 };
 use anoncreds_types::data_types::{
     identifiers::{
@@ -44,6 +49,7 @@ use anoncreds_types::data_types::{
         rev_reg::RevocationRegistry,
         rev_reg_def::RevocationRegistryDefinition,
         rev_reg_delta::{RevocationRegistryDelta, RevocationRegistryDeltaValue},
+        rev_status_list::RevocationStatusList as OurRevocationStatusList,
         schema::{AttributeNames, Schema},
     },
     messages::{
@@ -56,8 +62,11 @@ use anoncreds_types::data_types::{
         credential::{Credential, CredentialValues},
         nonce::Nonce,
         pres_request::PresentationRequest,
-        presentation::{Presentation, RequestedCredentials},
+        presentation::{
+            Presentation, RequestedAttribute, RequestedCredentials, RequestedPredicate,
+        },
         revocation_state::CredentialRevocationState,
+        w3c::{W3CCredential, W3CPresentation},
     },
 };
 use async_trait::async_trait;
@@ -177,6 +186,19 @@ pub struct RevocationRegistryInfo {
     pub used_ids: HashSet<u32>,
 }
 
+/// Credentials to present by id, with their timestamp, revocation state and the referents of the
+/// requested attributes (with their revealed status) and predicates they are presented for.
+type ProofDetails = HashMap<
+    String,
+    (
+        AnoncredsCredential,
+        Option<u64>,
+        Option<AnoncredsCredentialRevocationState>,
+        Vec<(String, bool)>,
+        Vec<String>,
+    ),
+>;
+
 impl Anoncreds {
     async fn get_wallet_record_value<T>(
         &self,
@@ -203,6 +225,77 @@ impl Anoncreds {
         Ok(ms_decimal.value().try_into().unwrap())
     }
 
+    async fn get_proof_details(
+        &self,
+        wallet: &impl BaseWallet,
+        requested_attributes: HashMap<String, RequestedAttribute>,
+        requested_predicates: HashMap<String, RequestedPredicate>,
+        revoc_states_json: Option<&RevocationStatesMap>,
+    ) -> VcxCoreResult<ProofDetails> {
+        let mut proof_details_by_cred_id: ProofDetails = HashMap::new();
+
+        // add cred data and referent details for each requested attribute
+        for (reft, detail) in requested_attributes {
+            let cred_id = &detail.cred_id;
+            let revealed = detail.revealed;
+
+            if let Some((_, _, _, req_attr_refts_revealed, _)) =
+                proof_details_by_cred_id.get_mut(cred_id)
+            {
+                // mapping made for this credential already, add reft and its revealed status
+                req_attr_refts_revealed.push((reft.to_string(), revealed));
+            } else {
+                let credential = self
+                    .get_wallet_record_value(wallet, RecordCategory::Cred, cred_id)
+                    .await?;
+
+                let (timestamp, rev_state) =
+                    get_rev_state(cred_id, &credential, detail.timestamp, revoc_states_json)?;
+
+                proof_details_by_cred_id.insert(
+                    cred_id.to_string(),
+                    (
+                        credential.convert(())?,
+                        timestamp,
+                        rev_state.map(|v| v.convert(())).transpose()?,
+                        vec![(reft.to_string(), revealed)],
+                        vec![],
+                    ),
+                );
+            }
+        }
+
+        // add cred data and referent details for each requested predicate
+        for (reft, detail) in requested_predicates {
+            let cred_id = &detail.cred_id;
+
+            if let Some((_, _, _, _, req_preds_refts)) = proof_details_by_cred_id.get_mut(cred_id) {
+                // mapping made for this credential already, add reft
+                req_preds_refts.push(reft.to_string());
+            } else {
+                let credential = self
+                    .get_wallet_record_value(wallet, RecordCategory::Cred, cred_id)
+                    .await?;
+
+                let (timestamp, rev_state) =
+                    get_rev_state(cred_id, &credential, detail.timestamp, revoc_states_json)?;
+
+                proof_details_by_cred_id.insert(
+                    cred_id.to_string(),
+                    (
+                        credential.convert(())?,
+                        timestamp,
+                        rev_state.map(|v| v.convert(())).transpose()?,
+                        vec![],
+                        vec![reft.to_string()],
+                    ),
+                );
+            }
+        }
+
+        Ok(proof_details_by_cred_id)
+    }
+
     async fn _get_credentials(
         wallet: &impl BaseWallet,
         wql: &str,
@@ -684,6 +777,58 @@ impl BaseAnonCreds for Anoncreds {
         Ok((cred.convert(())?, cred_rev_id))
     }
 
+    async fn issuer_create_w3c_credential(
+        &self,
+        wallet: &impl BaseWallet,
+        cred_offer_json: CredentialOffer,
+        cred_req_json: CredentialRequest,
+        cred_values_json: CredentialValues,
+        rev_reg_id: Option<&RevocationRegistryDefinitionId>,
+        tails_dir: Option<&Path>,
+    ) -> VcxCoreResult<(W3CCredential, Option<u32>)> {
+        let cred_def: AnoncredsCredentialDefinition = self
+            .get_wallet_record_value(
+                wallet,
+                RecordCategory::CredDef,
+                &cred_offer_json.cred_def_id.0,
+            )
+            .await?;
+        let (credential, cred_rev_id) = self
+            .issuer_create_credential(
+                wallet,
+                cred_offer_json,
+                cred_req_json,
+                cred_values_json,
+                rev_reg_id,
+                tails_dir,
+            )
+            .await?;
+        let credential: AnoncredsCredential = credential.convert(())?;
+
+        let w3c_credential = credential_to_w3c(&credential, &cred_def.issuer_id, None)?;
+
+        Ok((w3c_credential.convert(())?, cred_rev_id))
+    }
+
+    async fn credential_to_w3c(
+        &self,
+        credential: Credential,
+        cred_def_json: CredentialDefinition,
+    ) -> VcxCoreResult<W3CCredential> {
+        let credential: AnoncredsCredential = credential.convert(())?;
+        let cred_def: AnoncredsCredentialDefinition = cred_def_json.convert(())?;
+
+        let w3c_credential = credential_to_w3c(&credential, &cred_def.issuer_id, None)?;
+
+        Ok(w3c_credential.convert(())?)
+    }
+
+    async fn credential_from_w3c(&self, credential: W3CCredential) -> VcxCoreResult<Credential> {
+        let w3c_credential: AnoncredsW3CCredential = credential.convert(())?;
+
+        Ok(credential_from_w3c(&w3c_credential)?.convert(())?)
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn prover_create_proof(
         &self,
@@ -697,95 +842,24 @@ impl BaseAnonCreds for Anoncreds {
     ) -> VcxCoreResult<Presentation> {
         let pres_req: AnoncredsPresentationRequest = proof_req_json.convert(())?;
 
-        let requested_attributes = requested_credentials_json.requested_attributes;
-        let requested_predicates = requested_credentials_json.requested_predicates;
         let self_attested_attributes = requested_credentials_json.self_attested_attributes;
 
         let schemas: HashMap<AnoncredsSchemaId, AnoncredsSchema> = schemas_json.convert(())?;
         let cred_defs: HashMap<AnoncredsCredentialDefinitionId, AnoncredsCredentialDefinition> =
             credential_defs_json.convert(())?;
 
+        let proof_details_by_cred_id = self
+            .get_proof_details(
+                wallet,
+                requested_credentials_json.requested_attributes,
+                requested_credentials_json.requested_predicates,
+                revoc_states_json.as_ref(),
+            )
+            .await?;
+
         let mut present_credentials: PresentCredentials<AnoncredsCredential> =
             PresentCredentials::default();
 
-        let mut proof_details_by_cred_id: HashMap<
-            String,
-            (
-                AnoncredsCredential,
-                Option<u64>,
-                Option<AnoncredsCredentialRevocationState>,
-                Vec<(String, bool)>,
-                Vec<String>,
-            ),
-        > = HashMap::new();
-
-        // add cred data and referent details for each requested attribute
-        for (reft, detail) in requested_attributes {
-            let cred_id = &detail.cred_id;
-            let revealed = detail.revealed;
-
-            if let Some((_, _, _, req_attr_refts_revealed, _)) =
-                proof_details_by_cred_id.get_mut(cred_id)
-            {
-                // mapping made for this credential already, add reft and its revealed status
-                req_attr_refts_revealed.push((reft.to_string(), revealed));
-            } else {
-                let credential = self
-                    .get_wallet_record_value(wallet, RecordCategory::Cred, cred_id)
-                    .await?;
-
-                let (timestamp, rev_state) = get_rev_state(
-                    cred_id,
-                    &credential,
-                    detail.timestamp,
-                    revoc_states_json.as_ref(),
-                )?;
-
-                proof_details_by_cred_id.insert(
-                    cred_id.to_string(),
-                    (
-                        credential.convert(())?,
-                        timestamp,
-                        rev_state.map(|v| v.convert(())).transpose()?,
-                        vec![(reft.to_string(), revealed)],
-                        vec![],
-                    ),
-                );
-            }
-        }
-
-        // add cred data and referent details for each requested predicate
-        for (reft, detail) in requested_predicates {
-            let cred_id = &detail.cred_id;
-
-            if let Some((_, _, _, _, req_preds_refts)) = proof_details_by_cred_id.get_mut(cred_id) {
-                // mapping made for this credential already, add reft
-                req_preds_refts.push(reft.to_string());
-            } else {
-                let credential = self
-                    .get_wallet_record_value(wallet, RecordCategory::Cred, cred_id)
-                    .await?;
-
-                let (timestamp, rev_state) = get_rev_state(
-                    cred_id,
-                    &credential,
-                    detail.timestamp,
-                    revoc_states_json.as_ref(),
-                )?;
-
-                proof_details_by_cred_id.insert(
-                    cred_id.to_string(),
-                    (
-                        credential.convert(())?,
-                        timestamp,
-                        rev_state.map(|v| v.convert(())).transpose()?,
-                        vec![],
-                        vec![reft.to_string()],
-                    ),
-                );
-            }
-        }
-
         // add all accumulated requested attributes and requested predicates to credx
         // [PresentCredential] object
         for (
@@ -819,6 +893,127 @@ impl BaseAnonCreds for Anoncreds {
         Ok(presentation.convert(())?)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    async fn prover_create_w3c_presentation(
+        &self,
+        wallet: &impl BaseWallet,
+        proof_req_json: PresentationRequest,
+        requested_credentials_json: RequestedCredentials,
+        link_secret_id: &LinkSecretId,
+        schemas_json: SchemasMap,
+        credential_defs_json: CredentialDefinitionsMap,
+        revoc_states_json: Option<RevocationStatesMap>,
+    ) -> VcxCoreResult<W3CPresentation> {
+        if !requested_credentials_json
+            .self_attested_attributes
+            .is_empty()
+        {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::ActionNotSupported,
+                "Self attested attributes cannot be presented in W3C presentations",
+            ));
+        }
+
+        let pres_req: AnoncredsPresentationRequest = proof_req_json.convert(())?;
+
+        let schemas: HashMap<AnoncredsSchemaId, AnoncredsSchema> = schemas_json.convert(())?;
+        let cred_defs: HashMap<AnoncredsCredentialDefinitionId, AnoncredsCredentialDefinition> =
+            credential_defs_json.convert(())?;
+
+        let proof_details_by_cred_id = self
+            .get_proof_details(
+                wallet,
+                requested_credentials_json.requested_attributes,
+                requested_credentials_json.requested_predicates,
+                revoc_states_json.as_ref(),
+            )
+            .await?;
+
+        let w3c_credentials = proof_details_by_cred_id
+            .iter()
+            .map(|(cred_id, (credential, ..))| {
+                let cred_def = cred_defs.get(&credential.cred_def_id).ok_or_else(|| {
+                    AriesVcxCoreError::from_msg(
+                        AriesVcxCoreErrorKind::InvalidInput,
+                        format!(
+                            "No credential definition provided for credential '{cred_id}' with \
+                             cred_def_id '{}'",
+                            credential.cred_def_id
+                        ),
+                    )
+                })?;
+                let w3c_credential = credential_to_w3c(credential, &cred_def.issuer_id, None)?;
+                Ok((cred_id.as_str(), w3c_credential))
+            })
+            .collect::<VcxCoreResult<HashMap<&str, AnoncredsW3CCredential>>>()?;
+
+        let mut present_credentials: PresentCredentials<AnoncredsW3CCredential> =
+            PresentCredentials::default();
+
+        for (cred_id, (_, timestamp, rev_state, req_attr_refts_revealed, req_preds_refts)) in
+            proof_details_by_cred_id.iter()
+        {
+            let mut add_cred = present_credentials.add_credential(
+                &w3c_credentials[cred_id.as_str()],
+                *timestamp,
+                rev_state.as_ref(),
+            );
+
+            for (referent, revealed) in req_attr_refts_revealed {
+                add_cred.add_requested_attribute(referent, *revealed);
+            }
+
+            for referent in req_preds_refts {
+                add_cred.add_requested_predicate(referent);
+            }
+        }
+
+        let link_secret = self.get_link_secret(wallet, link_secret_id).await?;
+
+        let presentation = anoncreds::prover::create_w3c_presentation(
+            &pres_req,
+            present_credentials,
+            &link_secret,
+            &schemas,
+            &cred_defs,
+            None,
+        )?;
+
+        Ok(presentation.convert(())?)
+    }
+
+    async fn verifier_verify_w3c_presentation(
+        &self,
+        proof_request_json: PresentationRequest,
+        presentation_json: W3CPresentation,
+        schemas_json: SchemasMap,
+        credential_defs_json: CredentialDefinitionsMap,
+        rev_reg_defs_json: Option<RevocationRegistryDefinitionsMap>,
+        rev_regs_json: Option<RevocationRegistriesMap>,
+    ) -> VcxCoreResult<bool> {
+        let presentation: AnoncredsW3CPresentation = presentation_json.convert(())?;
+        let pres_req: AnoncredsPresentationRequest = proof_request_json.convert(())?;
+
+        let schemas: HashMap<AnoncredsSchemaId, AnoncredsSchema> = schemas_json.convert(())?;
+
+        let cred_defs: HashMap<AnoncredsCredentialDefinitionId, AnoncredsCredentialDefinition> =
+            credential_defs_json.convert(())?;
+
+        let rev_reg_defs: Option<
+            HashMap<AnoncredsRevocationRegistryDefinitionId, AnoncredsRevocationRegistryDefinition>,
+        > = rev_reg_defs_json.map(|v| v.convert(())).transpose()?;
+
+        Ok(anoncreds::verifier::verify_w3c_presentation(
+            &presentation,
+            &pres_req,
+            &schemas,
+            &cred_defs,
+            rev_reg_defs.as_ref(),
+            rev_regs_json.map(|r| r.convert(())).transpose()?,
+            None,
+        )?)
+    }
+
     async fn prover_get_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -970,18 +1165,7 @@ impl BaseAnonCreds for Anoncreds {
             ))?;
 
         let revoc_reg_def: AnoncredsRevocationRegistryDefinition = rev_reg_def_json.convert(())?;
-        let tails_file_hash = revoc_reg_def.value.tails_hash.as_str();
-
-        let mut tails_file_path = std::path::PathBuf::new();
-        tails_file_path.push(tails_dir);
-        tails_file_path.push(tails_file_hash);
-
-        let tails_path = tails_file_path.to_str().ok_or_else(|| {
-            AriesVcxCoreError::from_msg(
-                AriesVcxCoreErrorKind::InvalidOption,
-                "tails file is not an unicode string",
-            )
-        })?;
+        let tails_path = tails_file_path(tails_dir, &revoc_reg_def.value.tails_hash)?;
 
         let RevocationRegistryDeltaValue { accum, revoked, .. } = rev_reg_delta_json.value;
 
@@ -1014,7 +1198,7 @@ impl BaseAnonCreds for Anoncreds {
         )?;
 
         let rev_state = anoncreds::prover::create_or_update_revocation_state(
-            tails_path,
+            &tails_path,
             &revoc_reg_def,
             &rev_status_list,
             cred_rev_id,
@@ -1025,6 +1209,28 @@ impl BaseAnonCreds for Anoncreds {
         Ok(rev_state.convert(())?)
     }
 
+    async fn create_revocation_state_from_status_list(
+        &self,
+        tails_dir: &Path,
+        rev_reg_def_json: RevocationRegistryDefinition,
+        rev_status_list: OurRevocationStatusList,
+        cred_rev_id: u32,
+    ) -> VcxCoreResult<CredentialRevocationState> {
+        let revoc_reg_def: AnoncredsRevocationRegistryDefinition = rev_reg_def_json.convert(())?;
+        let tails_path = tails_file_path(tails_dir, &revoc_reg_def.value.tails_hash)?;
+
+        let rev_state = anoncreds::prover::create_or_update_revocation_state(
+            &tails_path,
+            &revoc_reg_def,
+            &rev_status_list.convert(())?,
+            cred_rev_id,
+            None,
+            None,
+        )?;
+
+        Ok(rev_state.convert(())?)
+    }
+
     async fn prover_store_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -1110,6 +1316,25 @@ impl BaseAnonCreds for Anoncreds {
         Ok(credential_id)
     }
 
+    async fn prover_store_w3c_credential(
+        &self,
+        wallet: &impl BaseWallet,
+        cred_req_metadata_json: CredentialRequestMetadata,
+        cred_json: W3CCredential,
+        cred_def_json: CredentialDefinition,
+        rev_reg_def_json: Option<RevocationRegistryDefinition>,
+    ) -> VcxCoreResult<CredentialId> {
+        let credential = self.credential_from_w3c(cred_json).await?;
+        self.prover_store_credential(
+            wallet,
+            cred_req_metadata_json,
+            credential,
+            cred_def_json,
+            rev_reg_def_json,
+        )
+        .await
+    }
+
     async fn prover_delete_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -1253,6 +1478,54 @@ impl BaseAnonCreds for Anoncreds {
         Ok(())
     }
 
+    async fn issuer_update_revocation_status_list(
+        &self,
+        wallet: &impl BaseWallet,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        rev_status_list: OurRevocationStatusList,
+        revoked: &[u32],
+    ) -> VcxCoreResult<OurRevocationStatusList> {
+        let rev_reg_def: AnoncredsRevocationRegistryDefinition = self
+            .get_wallet_record_value(wallet, RecordCategory::RevRegDef, &rev_reg_id.0)
+            .await?;
+
+        let cred_def: AnoncredsCredentialDefinition = self
+            .get_wallet_record_value(
+                wallet,
+                RecordCategory::CredDef,
+                &rev_reg_def.cred_def_id.to_string(),
+            )
+            .await?;
+
+        let rev_reg_def_priv = self
+            .get_wallet_record_value(wallet, RecordCategory::RevRegDefPriv, &rev_reg_id.0)
+            .await?;
+
+        let current_time = OffsetDateTime::now_utc().unix_timestamp() as u64;
+        let updated_rev_status_list = anoncreds::issuer::update_revocation_status_list(
+            &cred_def,
+            &rev_reg_def,
+            &rev_reg_def_priv,
+            &rev_status_list.convert(())?,
+            None,
+            Some(revoked.iter().copied().collect()),
+            Some(current_time),
+        )?;
+
+        // keep the stored accumulator in sync, so that subsequent issuance and revocation build
+        // on top of the updated registry
+        let rev_reg: Option<CryptoRevocationRegistry> = (&updated_rev_status_list).try_into()?;
+        if let Some(rev_reg) = rev_reg {
+            let str_rev_reg =
+                serde_json::to_string(&AnoncredsRevocationRegistry { value: rev_reg })?;
+            wallet
+                .update_record_value(RecordCategory::RevReg, &rev_reg_id.0, &str_rev_reg)
+                .await?;
+        }
+
+        Ok(updated_rev_status_list.convert(())?)
+    }
+
     async fn get_rev_reg_delta(
         &self,
         wallet: &impl BaseWallet,
@@ -1291,11 +1564,36 @@ impl BaseAnonCreds for Anoncreds {
         Ok(())
     }
 
+    async fn issuer_get_rev_reg_issued_count(
+        &self,
+        wallet: &impl BaseWallet,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<u32> {
+        let rev_reg_info: RevocationRegistryInfo = self
+            .get_wallet_record_value(wallet, RecordCategory::RevRegInfo, &rev_reg_id.to_string())
+            .await?;
+
+        Ok(rev_reg_info.curr_id)
+    }
+
     async fn generate_nonce(&self) -> VcxCoreResult<Nonce> {
         Ok(anoncreds::verifier::generate_nonce()?.convert(())?)
     }
 }
 
+fn tails_file_path(tails_dir: &Path, tails_hash: &str) -> VcxCoreResult<String> {
+    tails_dir
+        .join(tails_hash)
+        .to_str()
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidOption,
+                "tails file is not an unicode string",
+            )
+        })
+}
+
 fn get_rev_state(
     cred_id: &str,
     credential: &Credential,
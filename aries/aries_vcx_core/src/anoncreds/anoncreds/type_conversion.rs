@@ -15,6 +15,10 @@ use anoncreds::{
             RevocationRegistryDefinitionValue as AnoncredsRevocationRegistryDefinitionValue,
         },
         schema::{Schema as AnoncredsSchema, SchemaId as AnoncredsSchemaId},
+        w3c::{
+            credential::W3CCredential as AnoncredsW3CCredential,
+            presentation::W3CPresentation as AnoncredsW3CPresentation,
+        },
     },
     types::{
         AttributeNames as AnoncredsAttributeNames, Credential as AnoncredsCredential,
@@ -49,6 +53,7 @@ use anoncreds_types::data_types::{
             RevocationRegistryDefinition as OurRevocationRegistryDefinition,
             RevocationRegistryDefinitionValue as OurRevocationRegistryDefinitionValue,
         },
+        rev_status_list::RevocationStatusList as OurRevocationStatusList,
         schema::{AttributeNames as OurAttributeNames, Schema as OurSchema},
     },
     messages::{
@@ -63,6 +68,7 @@ use anoncreds_types::data_types::{
         pres_request::PresentationRequest as OurPresentationRequest,
         presentation::Presentation as OurPresentation,
         revocation_state::CredentialRevocationState as OurCredentialRevocationState,
+        w3c::{W3CCredential as OurW3CCredential, W3CPresentation as OurW3CPresentation},
     },
 };
 
@@ -518,6 +524,26 @@ impl Convert for HashMap<OurRevocationRegistryDefinitionId, HashMap<u64, OurRevo
     }
 }
 
+impl Convert for OurRevocationStatusList {
+    type Args = ();
+    type Target = AnoncredsRevocationStatusList;
+    type Error = Box<dyn std::error::Error>;
+
+    fn convert(self, _args: Self::Args) -> Result<Self::Target, Self::Error> {
+        serde_convert(self)
+    }
+}
+
+impl Convert for AnoncredsRevocationStatusList {
+    type Args = ();
+    type Target = OurRevocationStatusList;
+    type Error = Box<dyn std::error::Error>;
+
+    fn convert(self, _args: Self::Args) -> Result<Self::Target, Self::Error> {
+        serde_convert(self)
+    }
+}
+
 impl Convert for OurPresentationRequest {
     type Args = ();
     type Target = AnoncredsPresentationRequest;
@@ -577,3 +603,43 @@ impl Convert for OurCredentialRevocationState {
         serde_convert(self)
     }
 }
+
+impl Convert for OurW3CCredential {
+    type Args = ();
+    type Target = AnoncredsW3CCredential;
+    type Error = Box<dyn std::error::Error>;
+
+    fn convert(self, _args: Self::Args) -> Result<Self::Target, Self::Error> {
+        serde_convert(self)
+    }
+}
+
+impl Convert for AnoncredsW3CCredential {
+    type Args = ();
+    type Target = OurW3CCredential;
+    type Error = Box<dyn std::error::Error>;
+
+    fn convert(self, _args: Self::Args) -> Result<Self::Target, Self::Error> {
+        serde_convert(self)
+    }
+}
+
+impl Convert for OurW3CPresentation {
+    type Args = ();
+    type Target = AnoncredsW3CPresentation;
+    type Error = Box<dyn std::error::Error>;
+
+    fn convert(self, _args: Self::Args) -> Result<Self::Target, Self::Error> {
+        serde_convert(self)
+    }
+}
+
+impl Convert for AnoncredsW3CPresentation {
+    type Args = ();
+    type Target = OurW3CPresentation;
+    type Error = Box<dyn std::error::Error>;
+
+    fn convert(self, _args: Self::Args) -> Result<Self::Target, Self::Error> {
+        serde_convert(self)
+    }
+}
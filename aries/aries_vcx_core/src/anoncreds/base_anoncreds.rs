@@ -10,6 +10,7 @@ use anoncreds_types::data_types::{
         rev_reg::RevocationRegistry,
         rev_reg_def::RevocationRegistryDefinition,
         rev_reg_delta::RevocationRegistryDelta,
+        rev_status_list::RevocationStatusList,
         schema::{AttributeNames, Schema},
     },
     messages::{
@@ -22,6 +23,7 @@ use anoncreds_types::data_types::{
         pres_request::PresentationRequest,
         presentation::{Presentation, RequestedCredentials},
         revocation_state::CredentialRevocationState,
+        w3c::{W3CCredential, W3CPresentation},
     },
 };
 use async_trait::async_trait;
@@ -94,6 +96,27 @@ pub trait BaseAnonCreds: std::fmt::Debug + Send + Sync {
         tails_dir: Option<&Path>,
     ) -> VcxCoreResult<(Credential, Option<u32>)>;
 
+    /// Issues the credential encoded as a W3C verifiable credential.
+    async fn issuer_create_w3c_credential(
+        &self,
+        wallet: &impl BaseWallet,
+        cred_offer_json: CredentialOffer,
+        cred_req_json: CredentialRequest,
+        cred_values_json: CredentialValues,
+        rev_reg_id: Option<&RevocationRegistryDefinitionId>,
+        tails_dir: Option<&Path>,
+    ) -> VcxCoreResult<(W3CCredential, Option<u32>)>;
+
+    /// Encodes a legacy AnonCreds credential as a W3C verifiable credential.
+    async fn credential_to_w3c(
+        &self,
+        credential: Credential,
+        cred_def_json: CredentialDefinition,
+    ) -> VcxCoreResult<W3CCredential>;
+
+    /// Decodes an AnonCreds W3C verifiable credential into the legacy representation.
+    async fn credential_from_w3c(&self, credential: W3CCredential) -> VcxCoreResult<Credential>;
+
     #[allow(clippy::too_many_arguments)]
     async fn prover_create_proof(
         &self,
@@ -106,6 +129,30 @@ pub trait BaseAnonCreds: std::fmt::Debug + Send + Sync {
         revoc_states_json: Option<RevocationStatesMap>,
     ) -> VcxCoreResult<Presentation>;
 
+    /// Creates the presentation encoded as a W3C verifiable presentation. Self attested
+    /// attributes cannot be presented in this form.
+    #[allow(clippy::too_many_arguments)]
+    async fn prover_create_w3c_presentation(
+        &self,
+        wallet: &impl BaseWallet,
+        proof_req_json: PresentationRequest,
+        requested_credentials_json: RequestedCredentials,
+        link_secret_id: &LinkSecretId,
+        schemas_json: SchemasMap,
+        credential_defs_json: CredentialDefinitionsMap,
+        revoc_states_json: Option<RevocationStatesMap>,
+    ) -> VcxCoreResult<W3CPresentation>;
+
+    async fn verifier_verify_w3c_presentation(
+        &self,
+        proof_request_json: PresentationRequest,
+        presentation_json: W3CPresentation,
+        schemas_json: SchemasMap,
+        credential_defs_json: CredentialDefinitionsMap,
+        rev_reg_defs_json: Option<RevocationRegistryDefinitionsMap>,
+        rev_regs_json: Option<RevocationRegistriesMap>,
+    ) -> VcxCoreResult<bool>;
+
     async fn prover_get_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -142,6 +189,15 @@ pub trait BaseAnonCreds: std::fmt::Debug + Send + Sync {
         cred_rev_id: u32,
     ) -> VcxCoreResult<CredentialRevocationState>;
 
+    /// Creates the state proving non-revocation of the credential against the status list.
+    async fn create_revocation_state_from_status_list(
+        &self,
+        tails_dir: &Path,
+        rev_reg_def_json: RevocationRegistryDefinition,
+        rev_status_list: RevocationStatusList,
+        cred_rev_id: u32,
+    ) -> VcxCoreResult<CredentialRevocationState>;
+
     async fn prover_store_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -151,6 +207,16 @@ pub trait BaseAnonCreds: std::fmt::Debug + Send + Sync {
         rev_reg_def_json: Option<RevocationRegistryDefinition>,
     ) -> VcxCoreResult<CredentialId>;
 
+    /// Stores the W3C verifiable credential, which can then be presented in either form.
+    async fn prover_store_w3c_credential(
+        &self,
+        wallet: &impl BaseWallet,
+        cred_req_metadata_json: CredentialRequestMetadata,
+        cred_json: W3CCredential,
+        cred_def_json: CredentialDefinition,
+        rev_reg_def_json: Option<RevocationRegistryDefinition>,
+    ) -> VcxCoreResult<CredentialId>;
+
     async fn prover_delete_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -182,6 +248,16 @@ pub trait BaseAnonCreds: std::fmt::Debug + Send + Sync {
         rev_reg_delta_json: RevocationRegistryDelta,
     ) -> VcxCoreResult<()>;
 
+    /// Revokes the credentials on top of `rev_status_list`, the list currently published, and
+    /// returns the updated list with a new accumulator, ready to be published.
+    async fn issuer_update_revocation_status_list(
+        &self,
+        wallet: &impl BaseWallet,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        rev_status_list: RevocationStatusList,
+        revoked: &[u32],
+    ) -> VcxCoreResult<RevocationStatusList>;
+
     async fn get_rev_reg_delta(
         &self,
         wallet: &impl BaseWallet,
@@ -194,5 +270,12 @@ pub trait BaseAnonCreds: std::fmt::Debug + Send + Sync {
         rev_reg_id: &RevocationRegistryDefinitionId,
     ) -> VcxCoreResult<()>;
 
+    /// Number of credentials issued so far with the revocation registry created by the issuer.
+    async fn issuer_get_rev_reg_issued_count(
+        &self,
+        wallet: &impl BaseWallet,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<u32>;
+
     async fn generate_nonce(&self) -> VcxCoreResult<Nonce>;
 }
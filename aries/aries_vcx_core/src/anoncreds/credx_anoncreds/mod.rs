@@ -17,6 +17,7 @@ use anoncreds_types::data_types::{
         rev_reg::RevocationRegistry,
         rev_reg_def::RevocationRegistryDefinition,
         rev_reg_delta::RevocationRegistryDelta,
+        rev_status_list::RevocationStatusList,
         schema::{AttributeNames, Schema},
     },
     messages::{
@@ -29,6 +30,7 @@ use anoncreds_types::data_types::{
         pres_request::PresentationRequest,
         presentation::{Presentation, RequestedCredentials},
         revocation_state::CredentialRevocationState,
+        w3c::{W3CCredential, W3CPresentation},
     },
 };
 use async_trait::async_trait;
@@ -54,6 +56,7 @@ use did_parser::Did;
 use indy_credx as credx;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{json, Value};
+use time::OffsetDateTime;
 use type_conversion::Convert;
 use uuid::Uuid;
 
@@ -66,8 +69,8 @@ use crate::{
     utils::{constants::ATTRS, json::AsTypeOrDeserializationError},
     wallet::{
         base_wallet::{
-            record::Record, record_category::RecordCategory, search_filter::SearchFilter,
-            BaseWallet, RecordWallet,
+            record::Record, record_category::RecordCategory, record_iterator::RecordIterator,
+            search_filter::SearchFilter, search_options::SearchOptions, BaseWallet, RecordWallet,
         },
         record_tags::{RecordTag, RecordTags},
     },
@@ -124,6 +127,25 @@ impl RecordWallet for WalletAdapter {
     ) -> VcxCoreResult<Vec<Record>> {
         self.0.search_record(category, search_filter).await
     }
+
+    async fn search_record_iter(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+        options: SearchOptions,
+    ) -> VcxCoreResult<RecordIterator> {
+        self.0
+            .search_record_iter(category, search_filter, options)
+            .await
+    }
+
+    async fn count_records(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+    ) -> VcxCoreResult<usize> {
+        self.0.count_records(category, search_filter).await
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -524,6 +546,30 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
         Ok(offer.convert(())?)
     }
 
+    async fn issuer_create_w3c_credential(
+        &self,
+        _wallet: &impl BaseWallet,
+        _cred_offer_json: CredentialOffer,
+        _cred_req_json: CredentialRequest,
+        _cred_values_json: CredentialValues,
+        _rev_reg_id: Option<&RevocationRegistryDefinitionId>,
+        _tails_dir: Option<&Path>,
+    ) -> VcxCoreResult<(W3CCredential, Option<u32>)> {
+        w3c_not_supported()
+    }
+
+    async fn credential_to_w3c(
+        &self,
+        _credential: Credential,
+        _cred_def_json: CredentialDefinition,
+    ) -> VcxCoreResult<W3CCredential> {
+        w3c_not_supported()
+    }
+
+    async fn credential_from_w3c(&self, _credential: W3CCredential) -> VcxCoreResult<Credential> {
+        w3c_not_supported()
+    }
+
     async fn issuer_create_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -772,6 +818,32 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
         Ok(presentation.convert(())?)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    async fn prover_create_w3c_presentation(
+        &self,
+        _wallet: &impl BaseWallet,
+        _proof_req_json: PresentationRequest,
+        _requested_credentials_json: RequestedCredentials,
+        _link_secret_id: &LinkSecretId,
+        _schemas_json: SchemasMap,
+        _credential_defs_json: CredentialDefinitionsMap,
+        _revoc_states_json: Option<RevocationStatesMap>,
+    ) -> VcxCoreResult<W3CPresentation> {
+        w3c_not_supported()
+    }
+
+    async fn verifier_verify_w3c_presentation(
+        &self,
+        _proof_request_json: PresentationRequest,
+        _presentation_json: W3CPresentation,
+        _schemas_json: SchemasMap,
+        _credential_defs_json: CredentialDefinitionsMap,
+        _rev_reg_defs_json: Option<RevocationRegistryDefinitionsMap>,
+        _rev_regs_json: Option<RevocationRegistriesMap>,
+    ) -> VcxCoreResult<bool> {
+        w3c_not_supported()
+    }
+
     async fn prover_get_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -961,6 +1033,31 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
         Ok(rev_state.convert(())?)
     }
 
+    async fn create_revocation_state_from_status_list(
+        &self,
+        tails_dir: &Path,
+        rev_reg_def_json: RevocationRegistryDefinition,
+        rev_status_list: RevocationStatusList,
+        cred_rev_id: u32,
+    ) -> VcxCoreResult<CredentialRevocationState> {
+        let timestamp = rev_status_list.timestamp().ok_or_else(|| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidInput,
+                "revocation status list has no timestamp",
+            )
+        })?;
+        let rev_reg_delta = rev_status_list.to_delta(None)?;
+
+        self.create_revocation_state(
+            tails_dir,
+            rev_reg_def_json,
+            rev_reg_delta,
+            timestamp,
+            cred_rev_id,
+        )
+        .await
+    }
+
     async fn prover_store_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -1097,6 +1194,17 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
         Ok(())
     }
 
+    async fn prover_store_w3c_credential(
+        &self,
+        _wallet: &impl BaseWallet,
+        _cred_req_metadata_json: CredentialRequestMetadata,
+        _cred_json: W3CCredential,
+        _cred_def_json: CredentialDefinition,
+        _rev_reg_def_json: Option<RevocationRegistryDefinition>,
+    ) -> VcxCoreResult<CredentialId> {
+        w3c_not_supported()
+    }
+
     async fn prover_delete_credential(
         &self,
         wallet: &impl BaseWallet,
@@ -1240,6 +1348,77 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
         Ok(())
     }
 
+    async fn issuer_update_revocation_status_list(
+        &self,
+        wallet: &impl BaseWallet,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        rev_status_list: RevocationStatusList,
+        revoked: &[u32],
+    ) -> VcxCoreResult<RevocationStatusList> {
+        let rev_reg_id_str = &rev_reg_id.to_string();
+
+        let mut rev_reg: CredxRevocationRegistry =
+            Self::get_wallet_record_value(wallet, RecordCategory::RevReg, rev_reg_id_str).await?;
+
+        let rev_reg_def =
+            Self::get_wallet_record_value(wallet, RecordCategory::RevRegDef, rev_reg_id_str)
+                .await?;
+
+        let rev_reg_priv =
+            Self::get_wallet_record_value(wallet, RecordCategory::RevRegDefPriv, rev_reg_id_str)
+                .await?;
+
+        let cred_def_id = match &rev_reg_def {
+            CredxRevocationRegistryDefinition::RevocationRegistryDefinitionV1(r) => {
+                r.cred_def_id.0.as_str()
+            }
+        };
+
+        let cred_def =
+            Self::get_wallet_record_value(wallet, RecordCategory::CredDef, cred_def_id).await?;
+
+        let mut revocation_list = rev_status_list.state().clone();
+        for cred_rev_id in revoked {
+            let mut is_revoked =
+                revocation_list
+                    .get_mut(*cred_rev_id as usize)
+                    .ok_or_else(|| {
+                        AriesVcxCoreError::from_msg(
+                            AriesVcxCoreErrorKind::InvalidInput,
+                            format!("Revocation id: {cred_rev_id} not found in RevocationRegistry"),
+                        )
+                    })?;
+            if *is_revoked {
+                continue;
+            }
+            *is_revoked = true;
+
+            (rev_reg, _) = credx::issuer::revoke_credential(
+                &cred_def,
+                &rev_reg_def,
+                &rev_reg_priv,
+                &rev_reg,
+                *cred_rev_id,
+            )?;
+        }
+
+        let str_rev_reg = serde_json::to_string(&rev_reg)?;
+        wallet
+            .update_record_value(RecordCategory::RevReg, rev_reg_id_str, &str_rev_reg)
+            .await?;
+
+        let rev_reg: RevocationRegistry = rev_reg.convert(())?;
+        let current_time = OffsetDateTime::now_utc().unix_timestamp() as u64;
+
+        Ok(RevocationStatusList::new(
+            Some(rev_reg_id_str),
+            rev_status_list.issuer_id().clone(),
+            revocation_list,
+            Some(rev_reg.value),
+            Some(current_time),
+        )?)
+    }
+
     async fn get_rev_reg_delta(
         &self,
         wallet: &impl BaseWallet,
@@ -1277,11 +1456,33 @@ impl BaseAnonCreds for IndyCredxAnonCreds {
         Ok(())
     }
 
+    async fn issuer_get_rev_reg_issued_count(
+        &self,
+        wallet: &impl BaseWallet,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<u32> {
+        let rev_reg_info: RevocationRegistryInfo = Self::get_wallet_record_value(
+            wallet,
+            RecordCategory::RevRegInfo,
+            &rev_reg_id.to_string(),
+        )
+        .await?;
+
+        Ok(rev_reg_info.curr_id)
+    }
+
     async fn generate_nonce(&self) -> VcxCoreResult<Nonce> {
         Ok(Nonce::from_dec(credx::verifier::generate_nonce()?.as_ref()).unwrap())
     }
 }
 
+fn w3c_not_supported<T>() -> VcxCoreResult<T> {
+    Err(AriesVcxCoreError::from_msg(
+        AriesVcxCoreErrorKind::ActionNotSupported,
+        "W3C credentials are not supported by indy-credx",
+    ))
+}
+
 fn get_rev_state(
     cred_id: &str,
     credential: &CredxCredential,
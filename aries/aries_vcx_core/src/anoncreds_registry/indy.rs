@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use anoncreds_types::data_types::{
+    identifiers::{
+        cred_def_id::CredentialDefinitionId, issuer_id::IssuerId,
+        rev_reg_def_id::RevocationRegistryDefinitionId, schema_id::SchemaId,
+    },
+    ledger::{
+        cred_def::CredentialDefinition, rev_reg::RevocationRegistry,
+        rev_reg_def::RevocationRegistryDefinition, rev_reg_delta::RevocationRegistryDelta,
+        schema::Schema,
+    },
+};
+use async_trait::async_trait;
+use did_parser::Did;
+
+use super::{AnoncredsRegistry, LEGACY_INDY_METHOD};
+use crate::{
+    errors::error::VcxCoreResult,
+    ledger::base_ledger::{AnoncredsLedgerRead, AnoncredsLedgerWrite},
+    wallet::base_wallet::BaseWallet,
+};
+
+/// [AnoncredsRegistry] backed by an Indy ledger.
+#[derive(Debug)]
+pub struct IndyAnoncredsRegistry<R, W> {
+    ledger_read: Arc<R>,
+    ledger_write: Arc<W>,
+    methods: Vec<String>,
+}
+
+impl<R, W> IndyAnoncredsRegistry<R, W>
+where
+    R: AnoncredsLedgerRead,
+    W: AnoncredsLedgerWrite,
+{
+    /// Creates a registry serving legacy Indy identifiers.
+    pub fn new(ledger_read: Arc<R>, ledger_write: Arc<W>) -> Self {
+        Self {
+            ledger_read,
+            ledger_write,
+            methods: vec![LEGACY_INDY_METHOD.to_owned()],
+        }
+    }
+
+    /// Sets the DID methods served by the ledger, e.g. `indy` for `did:indy` identifiers.
+    pub fn with_methods(mut self, methods: Vec<String>) -> Self {
+        self.methods = methods;
+        self
+    }
+}
+
+fn submitter_did(issuer_id: &IssuerId) -> VcxCoreResult<Did> {
+    Ok(Did::parse(issuer_id.0.clone())?)
+}
+
+#[async_trait]
+impl<R, W> AnoncredsRegistry for IndyAnoncredsRegistry<R, W>
+where
+    R: AnoncredsLedgerRead,
+    W: AnoncredsLedgerWrite,
+{
+    fn supports_method(&self, method: &str) -> bool {
+        self.methods.iter().any(|supported| supported == method)
+    }
+
+    async fn get_schema(&self, schema_id: &SchemaId) -> VcxCoreResult<Schema> {
+        self.ledger_read.get_schema(schema_id, None).await
+    }
+
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &CredentialDefinitionId,
+    ) -> VcxCoreResult<CredentialDefinition> {
+        self.ledger_read.get_cred_def(cred_def_id, None).await
+    }
+
+    async fn get_rev_reg_def(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<RevocationRegistryDefinition> {
+        self.ledger_read.get_rev_reg_def_json(rev_reg_def_id).await
+    }
+
+    async fn get_rev_reg(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        timestamp: u64,
+    ) -> VcxCoreResult<(RevocationRegistry, u64)> {
+        self.ledger_read
+            .get_rev_reg(rev_reg_def_id, timestamp)
+            .await
+    }
+
+    async fn get_rev_reg_delta(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+        self.ledger_read
+            .get_rev_reg_delta_json(rev_reg_def_id, from, to)
+            .await
+    }
+
+    async fn register_schema(&self, wallet: &dyn BaseWallet, schema: Schema) -> VcxCoreResult<()> {
+        let submitter_did = submitter_did(&schema.issuer_id)?;
+        self.ledger_write
+            .publish_schema(wallet, schema, &submitter_did, None)
+            .await
+    }
+
+    async fn register_cred_def(
+        &self,
+        wallet: &dyn BaseWallet,
+        cred_def: CredentialDefinition,
+    ) -> VcxCoreResult<()> {
+        let submitter_did = submitter_did(&cred_def.issuer_id)?;
+        self.ledger_write
+            .publish_cred_def(wallet, cred_def, &submitter_did)
+            .await
+    }
+
+    async fn register_rev_reg_def(
+        &self,
+        wallet: &dyn BaseWallet,
+        issuer_id: &IssuerId,
+        rev_reg_def: RevocationRegistryDefinition,
+    ) -> VcxCoreResult<()> {
+        self.ledger_write
+            .publish_rev_reg_def(wallet, rev_reg_def, &submitter_did(issuer_id)?)
+            .await
+    }
+
+    async fn register_rev_reg_entry(
+        &self,
+        wallet: &dyn BaseWallet,
+        issuer_id: &IssuerId,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        rev_reg_delta: RevocationRegistryDelta,
+    ) -> VcxCoreResult<()> {
+        self.ledger_write
+            .publish_rev_reg_delta(
+                wallet,
+                rev_reg_def_id,
+                rev_reg_delta,
+                &submitter_did(issuer_id)?,
+            )
+            .await
+    }
+}
@@ -0,0 +1,640 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use anoncreds_types::data_types::{
+    identifiers::{
+        cred_def_id::CredentialDefinitionId, issuer_id::IssuerId,
+        rev_reg_def_id::RevocationRegistryDefinitionId, schema_id::SchemaId,
+    },
+    ledger::{
+        cred_def::CredentialDefinition,
+        rev_reg::RevocationRegistry,
+        rev_reg_def::RevocationRegistryDefinition,
+        rev_reg_delta::{RevocationRegistryDelta, RevocationRegistryDeltaValue},
+        schema::Schema,
+    },
+};
+use async_trait::async_trait;
+use did_parser::Did;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use super::AnoncredsRegistry;
+use crate::{
+    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
+    ledger::base_ledger::{AnoncredsLedgerRead, AnoncredsLedgerWrite},
+    wallet::base_wallet::BaseWallet,
+};
+
+const SCHEMAS: &str = "schema";
+const CRED_DEFS: &str = "cred_def";
+const REV_REG_DEFS: &str = "rev_reg_def";
+const REV_REG_ENTRIES: &str = "rev_reg_entries";
+
+/// Revocation registry entry as published, along with the time of publication.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RevRegEntry {
+    timestamp: u64,
+    delta: RevocationRegistryDelta,
+}
+
+#[derive(Debug)]
+enum Storage {
+    Memory(Mutex<HashMap<String, String>>),
+    Directory(PathBuf),
+}
+
+impl Storage {
+    fn path(dir: &Path, kind: &str, id: &str) -> PathBuf {
+        dir.join(kind)
+            .join(format!("{}.json", encode_file_name(id)))
+    }
+
+    async fn load<T: DeserializeOwned>(&self, kind: &str, id: &str) -> VcxCoreResult<Option<T>> {
+        let json = match self {
+            Self::Memory(objects) => objects.lock()?.get(&format!("{kind}/{id}")).cloned(),
+            Self::Directory(dir) => {
+                match tokio::fs::read_to_string(Self::path(dir, kind, id)).await {
+                    Ok(json) => Some(json),
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        };
+
+        json.map(|json| serde_json::from_str(&json).map_err(From::from))
+            .transpose()
+    }
+
+    async fn store<T: Serialize + Sync>(
+        &self,
+        kind: &str,
+        id: &str,
+        object: &T,
+    ) -> VcxCoreResult<()> {
+        let json = serde_json::to_string(object)?;
+        match self {
+            Self::Memory(objects) => {
+                objects.lock()?.insert(format!("{kind}/{id}"), json);
+            }
+            Self::Directory(dir) => {
+                tokio::fs::create_dir_all(dir.join(kind)).await?;
+                tokio::fs::write(Self::path(dir, kind, id), json).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn count(&self, kind: &str) -> VcxCoreResult<usize> {
+        match self {
+            Self::Memory(objects) => {
+                let prefix = format!("{kind}/");
+                Ok(objects
+                    .lock()?
+                    .keys()
+                    .filter(|key| key.starts_with(&prefix))
+                    .count())
+            }
+            Self::Directory(dir) => {
+                let mut entries = match tokio::fs::read_dir(dir.join(kind)).await {
+                    Ok(entries) => entries,
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+                    Err(err) => return Err(err.into()),
+                };
+                let mut count = 0;
+                while entries.next_entry().await?.is_some() {
+                    count += 1;
+                }
+                Ok(count)
+            }
+        }
+    }
+}
+
+/// Escapes the characters of an identifier which may not be safe in a file name.
+fn encode_file_name(id: &str) -> String {
+    id.bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' => (byte as char).to_string(),
+            _ => format!("_{byte:02X}"),
+        })
+        .collect()
+}
+
+fn not_found(kind: &str, id: &str) -> AriesVcxCoreError {
+    AriesVcxCoreError::from_msg(
+        AriesVcxCoreErrorKind::LedgerItemNotFound,
+        format!("{kind} {id} not found in local registry"),
+    )
+}
+
+fn now() -> u64 {
+    OffsetDateTime::now_utc().unix_timestamp() as u64
+}
+
+/// Appends a published delta to the entries of a registry. Timestamps are in seconds, as on the
+/// ledger, so a delta published within the second of the latest entry is merged into it. Each
+/// timestamp then identifies a single registry state, which the `(from, to]` interval queries
+/// rely on.
+fn push_entry(entries: &mut Vec<RevRegEntry>, timestamp: u64, delta: RevocationRegistryDelta) {
+    let last = match entries.last_mut() {
+        Some(last) if last.timestamp >= timestamp => last,
+        _ => {
+            entries.push(RevRegEntry { timestamp, delta });
+            return;
+        }
+    };
+
+    let value = &mut last.delta.value;
+    let mut issued: BTreeSet<_> = value.issued.drain(..).collect();
+    let mut revoked: BTreeSet<_> = value.revoked.drain(..).collect();
+    for idx in delta.value.issued {
+        revoked.remove(&idx);
+        issued.insert(idx);
+    }
+    for idx in delta.value.revoked {
+        issued.remove(&idx);
+        revoked.insert(idx);
+    }
+    value.issued = issued.into_iter().collect();
+    value.revoked = revoked.into_iter().collect();
+    value.accum = delta.value.accum;
+}
+
+/// AnonCreds ledger kept in memory or in a local directory, one JSON file per object. It needs no
+/// network and no signatures, which makes it suitable for running issuance, revocation and
+/// verification flows in tests. Revocation registry entries are timestamped on publication.
+#[derive(Debug)]
+pub struct LocalAnoncredsRegistry {
+    storage: Storage,
+    // serializes read-modify-write operations on the storage
+    lock: tokio::sync::Mutex<()>,
+    methods: Option<Vec<String>>,
+}
+
+impl LocalAnoncredsRegistry {
+    pub fn in_memory() -> Self {
+        Self::with_storage(Storage::Memory(Mutex::new(HashMap::new())))
+    }
+
+    /// Keeps the objects as files under `dir`, which is created if needed. Objects written by a
+    /// previous registry using the same directory are available.
+    pub fn in_directory(dir: impl Into<PathBuf>) -> VcxCoreResult<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self::with_storage(Storage::Directory(dir)))
+    }
+
+    fn with_storage(storage: Storage) -> Self {
+        Self {
+            storage,
+            lock: tokio::sync::Mutex::new(()),
+            methods: None,
+        }
+    }
+
+    /// Restricts the DID methods served when used as an [AnoncredsRegistry]. All methods are
+    /// served by default.
+    pub fn with_methods(mut self, methods: Vec<String>) -> Self {
+        self.methods = Some(methods);
+        self
+    }
+
+    async fn load<T: DeserializeOwned>(&self, kind: &str, id: &str) -> VcxCoreResult<T> {
+        self.storage
+            .load(kind, id)
+            .await?
+            .ok_or_else(|| not_found(kind, id))
+    }
+
+    async fn store_new<T: Serialize + Sync>(
+        &self,
+        kind: &str,
+        id: &str,
+        object: &T,
+        duplicate_err: AriesVcxCoreErrorKind,
+    ) -> VcxCoreResult<()> {
+        let _guard = self.lock.lock().await;
+        if self
+            .storage
+            .load::<serde_json::Value>(kind, id)
+            .await?
+            .is_some()
+        {
+            return Err(AriesVcxCoreError::from_msg(
+                duplicate_err,
+                format!("{kind} {id} already exists in local registry"),
+            ));
+        }
+        self.storage.store(kind, id, object).await
+    }
+
+    async fn rev_reg_entries(
+        &self,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<Vec<RevRegEntry>> {
+        self.load::<RevocationRegistryDefinition>(REV_REG_DEFS, &rev_reg_id.0)
+            .await?;
+        Ok(self
+            .storage
+            .load(REV_REG_ENTRIES, &rev_reg_id.0)
+            .await?
+            .unwrap_or_default())
+    }
+
+    async fn add_schema(&self, mut schema: Schema) -> VcxCoreResult<()> {
+        let _guard = self.lock.lock().await;
+        if self
+            .storage
+            .load::<Schema>(SCHEMAS, &schema.id.0)
+            .await?
+            .is_some()
+        {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::DuplicationSchema,
+                format!("schema {} already exists in local registry", schema.id),
+            ));
+        }
+        schema.seq_no = Some(self.storage.count(SCHEMAS).await? as u32 + 1);
+        self.storage.store(SCHEMAS, &schema.id.0, &schema).await
+    }
+
+    async fn add_rev_reg_entry(
+        &self,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        delta: RevocationRegistryDelta,
+    ) -> VcxCoreResult<()> {
+        let _guard = self.lock.lock().await;
+        let mut entries = self.rev_reg_entries(rev_reg_id).await?;
+        push_entry(&mut entries, now(), delta);
+        self.storage
+            .store(REV_REG_ENTRIES, &rev_reg_id.0, &entries)
+            .await
+    }
+}
+
+/// Merges the entries published in `(from, to]` into a single delta, returned along with the
+/// timestamp of the latest of them.
+fn merge_entries(
+    rev_reg_id: &RevocationRegistryDefinitionId,
+    entries: &[RevRegEntry],
+    from: Option<u64>,
+    to: u64,
+) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+    let latest = entries
+        .iter()
+        .rposition(|entry| entry.timestamp <= to)
+        .ok_or_else(|| not_found(REV_REG_ENTRIES, &rev_reg_id.0))?;
+    let first = match from {
+        Some(from) => entries[..=latest]
+            .iter()
+            .position(|entry| entry.timestamp > from)
+            .unwrap_or(latest + 1),
+        None => 0,
+    };
+
+    let mut issued = BTreeSet::new();
+    let mut revoked = BTreeSet::new();
+    for entry in &entries[first..=latest] {
+        for idx in &entry.delta.value.issued {
+            revoked.remove(idx);
+            issued.insert(*idx);
+        }
+        for idx in &entry.delta.value.revoked {
+            issued.remove(idx);
+            revoked.insert(*idx);
+        }
+    }
+
+    let prev_accum = match first {
+        0 => None,
+        first => Some(entries[first - 1].delta.value.accum),
+    };
+    let delta = RevocationRegistryDelta {
+        value: RevocationRegistryDeltaValue {
+            prev_accum,
+            accum: entries[latest].delta.value.accum,
+            issued: issued.into_iter().collect(),
+            revoked: revoked.into_iter().collect(),
+        },
+    };
+
+    Ok((delta, entries[latest].timestamp))
+}
+
+#[async_trait]
+impl AnoncredsLedgerRead for LocalAnoncredsRegistry {
+    async fn get_schema(
+        &self,
+        schema_id: &SchemaId,
+        _submitter_did: Option<&Did>,
+    ) -> VcxCoreResult<Schema> {
+        self.load(SCHEMAS, &schema_id.0).await
+    }
+
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &CredentialDefinitionId,
+        _submitter_did: Option<&Did>,
+    ) -> VcxCoreResult<CredentialDefinition> {
+        self.load(CRED_DEFS, &cred_def_id.0).await
+    }
+
+    async fn get_rev_reg_def_json(
+        &self,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<RevocationRegistryDefinition> {
+        self.load(REV_REG_DEFS, &rev_reg_id.0).await
+    }
+
+    async fn get_rev_reg_delta_json(
+        &self,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+        let entries = self.rev_reg_entries(rev_reg_id).await?;
+        merge_entries(rev_reg_id, &entries, from, to.unwrap_or_else(now))
+    }
+
+    async fn get_rev_reg(
+        &self,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        timestamp: u64,
+    ) -> VcxCoreResult<(RevocationRegistry, u64)> {
+        let entries = self.rev_reg_entries(rev_reg_id).await?;
+        let entry = entries
+            .iter()
+            .rev()
+            .find(|entry| entry.timestamp <= timestamp)
+            .ok_or_else(|| not_found(REV_REG_ENTRIES, &rev_reg_id.0))?;
+
+        let rev_reg = RevocationRegistry {
+            value: entry.delta.value.accum.into(),
+        };
+        Ok((rev_reg, entry.timestamp))
+    }
+}
+
+#[async_trait]
+impl AnoncredsLedgerWrite for LocalAnoncredsRegistry {
+    async fn publish_schema(
+        &self,
+        _wallet: &(impl BaseWallet + ?Sized),
+        schema_json: Schema,
+        _submitter_did: &Did,
+        _endorser_did: Option<&Did>,
+    ) -> VcxCoreResult<()> {
+        self.add_schema(schema_json).await
+    }
+
+    async fn publish_cred_def(
+        &self,
+        _wallet: &(impl BaseWallet + ?Sized),
+        cred_def_json: CredentialDefinition,
+        _submitter_did: &Did,
+    ) -> VcxCoreResult<()> {
+        self.store_new(
+            CRED_DEFS,
+            &cred_def_json.id.0,
+            &cred_def_json,
+            AriesVcxCoreErrorKind::CredDefAlreadyCreated,
+        )
+        .await
+    }
+
+    async fn publish_rev_reg_def(
+        &self,
+        _wallet: &(impl BaseWallet + ?Sized),
+        rev_reg_def: RevocationRegistryDefinition,
+        _submitter_did: &Did,
+    ) -> VcxCoreResult<()> {
+        self.store_new(
+            REV_REG_DEFS,
+            &rev_reg_def.id.0,
+            &rev_reg_def,
+            AriesVcxCoreErrorKind::InvalidInput,
+        )
+        .await
+    }
+
+    async fn publish_rev_reg_delta(
+        &self,
+        _wallet: &(impl BaseWallet + ?Sized),
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        rev_reg_entry_json: RevocationRegistryDelta,
+        _submitter_did: &Did,
+    ) -> VcxCoreResult<()> {
+        self.add_rev_reg_entry(rev_reg_id, rev_reg_entry_json).await
+    }
+}
+
+#[async_trait]
+impl AnoncredsRegistry for LocalAnoncredsRegistry {
+    fn supports_method(&self, method: &str) -> bool {
+        match &self.methods {
+            Some(methods) => methods.iter().any(|supported| supported == method),
+            None => true,
+        }
+    }
+
+    async fn get_schema(&self, schema_id: &SchemaId) -> VcxCoreResult<Schema> {
+        self.load(SCHEMAS, &schema_id.0).await
+    }
+
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &CredentialDefinitionId,
+    ) -> VcxCoreResult<CredentialDefinition> {
+        self.load(CRED_DEFS, &cred_def_id.0).await
+    }
+
+    async fn get_rev_reg_def(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<RevocationRegistryDefinition> {
+        self.load(REV_REG_DEFS, &rev_reg_def_id.0).await
+    }
+
+    async fn get_rev_reg(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        timestamp: u64,
+    ) -> VcxCoreResult<(RevocationRegistry, u64)> {
+        AnoncredsLedgerRead::get_rev_reg(self, rev_reg_def_id, timestamp).await
+    }
+
+    async fn get_rev_reg_delta(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+        self.get_rev_reg_delta_json(rev_reg_def_id, from, to).await
+    }
+
+    async fn register_schema(&self, _wallet: &dyn BaseWallet, schema: Schema) -> VcxCoreResult<()> {
+        self.add_schema(schema).await
+    }
+
+    async fn register_cred_def(
+        &self,
+        _wallet: &dyn BaseWallet,
+        cred_def: CredentialDefinition,
+    ) -> VcxCoreResult<()> {
+        self.store_new(
+            CRED_DEFS,
+            &cred_def.id.0,
+            &cred_def,
+            AriesVcxCoreErrorKind::CredDefAlreadyCreated,
+        )
+        .await
+    }
+
+    async fn register_rev_reg_def(
+        &self,
+        _wallet: &dyn BaseWallet,
+        _issuer_id: &IssuerId,
+        rev_reg_def: RevocationRegistryDefinition,
+    ) -> VcxCoreResult<()> {
+        self.store_new(
+            REV_REG_DEFS,
+            &rev_reg_def.id.0,
+            &rev_reg_def,
+            AriesVcxCoreErrorKind::InvalidInput,
+        )
+        .await
+    }
+
+    async fn register_rev_reg_entry(
+        &self,
+        _wallet: &dyn BaseWallet,
+        _issuer_id: &IssuerId,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        rev_reg_delta: RevocationRegistryDelta,
+    ) -> VcxCoreResult<()> {
+        self.add_rev_reg_entry(rev_reg_def_id, rev_reg_delta).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anoncreds_types::data_types::ledger::schema::AttributeNames;
+    use serde_json::json;
+
+    use super::*;
+
+    const ACCUM: &str = "1 1379509F4D411630D308A5ABB4F422FCE6737B330B1C5FD286AA5C26F2061E60 1 \
+                         235535CC45D4816C7686C5A402A230B35A62DDE82B4A652E384FD31912C4E4BB 1 \
+                         0C94B61595FCAEFC892BB98A27D524C97ED0B7ED1CC49AD6F178A59D4199C9A4 1 \
+                         172482285606DEE8500FC8A13E6A35EC071F8B84F0EB4CD3DD091C0B4CD30E5E 2 \
+                         095E45DDF417D05FB10933FFC63D474548B7FFFF7888802F07FFFFFF7D07A8A8 1 \
+                         0000000000000000000000000000000000000000000000000000000000000000";
+
+    fn schema() -> Schema {
+        Schema {
+            id: SchemaId::new_unchecked("V4SGRU86Z58d6TV7PBUe6f:2:gvt:1.0"),
+            name: "gvt".to_owned(),
+            version: "1.0".to_owned(),
+            attr_names: AttributeNames(vec!["name".to_owned()]),
+            issuer_id: IssuerId::new_unchecked("V4SGRU86Z58d6TV7PBUe6f"),
+            ..Default::default()
+        }
+    }
+
+    fn delta(issued: &[u32], revoked: &[u32]) -> RevocationRegistryDelta {
+        serde_json::from_value(json!({
+            "value": { "accum": ACCUM, "issued": issued, "revoked": revoked }
+        }))
+        .unwrap()
+    }
+
+    fn entry(timestamp: u64, issued: &[u32], revoked: &[u32]) -> RevRegEntry {
+        RevRegEntry {
+            timestamp,
+            delta: delta(issued, revoked),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_local_registry_stores_schema() {
+        let registry = LocalAnoncredsRegistry::in_memory();
+        let schema_id = schema().id;
+
+        let err = AnoncredsRegistry::get_schema(&registry, &schema_id)
+            .await
+            .unwrap_err();
+        assert_eq!(AriesVcxCoreErrorKind::LedgerItemNotFound, err.kind());
+
+        registry.add_schema(schema()).await.unwrap();
+        let res = AnoncredsRegistry::get_schema(&registry, &schema_id)
+            .await
+            .unwrap();
+        assert_eq!(Some(1), res.seq_no);
+        assert_eq!(schema().attr_names, res.attr_names);
+
+        let err = registry.add_schema(schema()).await.unwrap_err();
+        assert_eq!(AriesVcxCoreErrorKind::DuplicationSchema, err.kind());
+    }
+
+    #[tokio::test]
+    async fn test_local_registry_persists_to_directory() {
+        let dir = std::env::temp_dir().join(format!("anoncreds_{}", uuid::Uuid::new_v4()));
+        LocalAnoncredsRegistry::in_directory(&dir)
+            .unwrap()
+            .add_schema(schema())
+            .await
+            .unwrap();
+
+        let registry = LocalAnoncredsRegistry::in_directory(&dir).unwrap();
+        let res = AnoncredsRegistry::get_schema(&registry, &schema().id)
+            .await
+            .unwrap();
+        assert_eq!(schema().name, res.name);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_push_entry_merges_same_second() {
+        let mut entries = vec![entry(10, &[1, 2], &[])];
+
+        push_entry(&mut entries, 10, delta(&[3], &[1]));
+        assert_eq!(1, entries.len());
+        assert_eq!(vec![2, 3], entries[0].delta.value.issued);
+        assert_eq!(vec![1], entries[0].delta.value.revoked);
+
+        push_entry(&mut entries, 11, delta(&[1], &[]));
+        assert_eq!(2, entries.len());
+        assert_eq!(11, entries[1].timestamp);
+    }
+
+    #[test]
+    fn test_merge_rev_reg_entries() {
+        let rev_reg_id = RevocationRegistryDefinitionId::new_unchecked("rev_reg");
+        let entries = vec![
+            entry(10, &[1, 2, 3], &[]),
+            entry(20, &[], &[1, 2]),
+            entry(30, &[2], &[3]),
+        ];
+
+        let (delta, timestamp) = merge_entries(&rev_reg_id, &entries, None, 25).unwrap();
+        assert_eq!(20, timestamp);
+        assert!(delta.value.prev_accum.is_none());
+        assert_eq!(vec![3], delta.value.issued);
+        assert_eq!(vec![1, 2], delta.value.revoked);
+
+        let (delta, timestamp) = merge_entries(&rev_reg_id, &entries, Some(10), 30).unwrap();
+        assert_eq!(30, timestamp);
+        assert!(delta.value.prev_accum.is_some());
+        assert_eq!(vec![2], delta.value.issued);
+        assert_eq!(vec![1, 3], delta.value.revoked);
+
+        let err = merge_entries(&rev_reg_id, &entries, None, 5).unwrap_err();
+        assert_eq!(AriesVcxCoreErrorKind::LedgerItemNotFound, err.kind());
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::Debug;
+
+use anoncreds_types::data_types::{
+    identifiers::{
+        cred_def_id::CredentialDefinitionId, issuer_id::IssuerId,
+        rev_reg_def_id::RevocationRegistryDefinitionId, schema_id::SchemaId,
+    },
+    ledger::{
+        cred_def::CredentialDefinition, rev_reg::RevocationRegistry,
+        rev_reg_def::RevocationRegistryDefinition, rev_reg_delta::RevocationRegistryDelta,
+        schema::Schema,
+    },
+};
+use async_trait::async_trait;
+
+use crate::{
+    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
+    wallet::base_wallet::BaseWallet,
+};
+
+pub mod indy;
+pub mod local;
+pub mod router;
+
+/// DID method assumed for legacy, unqualified Indy identifiers.
+pub const LEGACY_INDY_METHOD: &str = "sov";
+
+/// Method agnostic storage of AnonCreds objects. Each registry serves the objects of issuers
+/// using some set of DID methods, see [registry_method].
+#[async_trait]
+pub trait AnoncredsRegistry: Debug + Send + Sync {
+    fn supports_method(&self, method: &str) -> bool;
+
+    async fn get_schema(&self, schema_id: &SchemaId) -> VcxCoreResult<Schema>;
+
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &CredentialDefinitionId,
+    ) -> VcxCoreResult<CredentialDefinition>;
+
+    async fn get_rev_reg_def(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<RevocationRegistryDefinition>;
+
+    /// Returns the state of the revocation registry at `timestamp`, along with the time the
+    /// returned state was registered at.
+    async fn get_rev_reg(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        timestamp: u64,
+    ) -> VcxCoreResult<(RevocationRegistry, u64)>;
+
+    /// Returns the changes of the revocation registry between `from` and `to`, along with the
+    /// time of the latest change included.
+    async fn get_rev_reg_delta(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)>;
+
+    async fn register_schema(&self, wallet: &dyn BaseWallet, schema: Schema) -> VcxCoreResult<()>;
+
+    async fn register_cred_def(
+        &self,
+        wallet: &dyn BaseWallet,
+        cred_def: CredentialDefinition,
+    ) -> VcxCoreResult<()>;
+
+    async fn register_rev_reg_def(
+        &self,
+        wallet: &dyn BaseWallet,
+        issuer_id: &IssuerId,
+        rev_reg_def: RevocationRegistryDefinition,
+    ) -> VcxCoreResult<()>;
+
+    async fn register_rev_reg_entry(
+        &self,
+        wallet: &dyn BaseWallet,
+        issuer_id: &IssuerId,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        rev_reg_delta: RevocationRegistryDelta,
+    ) -> VcxCoreResult<()>;
+}
+
+/// Returns the method of the registry responsible for an AnonCreds identifier: the DID method
+/// for DID based identifiers, the scheme for other URIs, and [LEGACY_INDY_METHOD] for legacy
+/// Indy identifiers.
+pub fn registry_method(identifier: &str) -> VcxCoreResult<&str> {
+    let invalid = || {
+        AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidInput,
+            format!("cannot determine the registry of identifier: {identifier}"),
+        )
+    };
+
+    if let Some(rest) = identifier.strip_prefix("did:") {
+        return rest
+            .split(':')
+            .next()
+            .filter(|method| !method.is_empty())
+            .ok_or_else(invalid);
+    }
+
+    match identifier.split_once("://") {
+        Some((scheme, _)) if !scheme.is_empty() => Ok(scheme),
+        Some(_) => Err(invalid()),
+        None if identifier.is_empty() => Err(invalid()),
+        None => Ok(LEGACY_INDY_METHOD),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_method() {
+        assert_eq!(
+            "indy",
+            registry_method("did:indy:sovrin:F72i3Y3Q4i466efjYJYCHM/anoncreds/v0/SCHEMA/a/1.0")
+                .unwrap()
+        );
+        assert_eq!(
+            "sov",
+            registry_method("V4SGRU86Z58d6TV7PBUe6f:2:gvt:1.0").unwrap()
+        );
+        assert_eq!(
+            "https",
+            registry_method("https://example.org/schemas/1").unwrap()
+        );
+        assert_eq!(
+            AriesVcxCoreErrorKind::InvalidInput,
+            registry_method("did::foo").unwrap_err().kind()
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use std::sync::Arc;
+
+use anoncreds_types::data_types::{
+    identifiers::{
+        cred_def_id::CredentialDefinitionId, issuer_id::IssuerId,
+        rev_reg_def_id::RevocationRegistryDefinitionId, schema_id::SchemaId,
+    },
+    ledger::{
+        cred_def::CredentialDefinition, rev_reg::RevocationRegistry,
+        rev_reg_def::RevocationRegistryDefinition, rev_reg_delta::RevocationRegistryDelta,
+        schema::Schema,
+    },
+};
+use async_trait::async_trait;
+use did_parser::Did;
+
+use super::{registry_method, AnoncredsRegistry};
+use crate::{
+    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
+    ledger::base_ledger::AnoncredsLedgerRead,
+    wallet::base_wallet::BaseWallet,
+};
+
+/// Dispatches to the first registered [AnoncredsRegistry] supporting the method of the
+/// identifier of the requested object.
+#[derive(Debug, Default, Clone)]
+pub struct AnoncredsRegistryRouter {
+    registries: Vec<Arc<dyn AnoncredsRegistry>>,
+}
+
+impl AnoncredsRegistryRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(mut self, registry: Arc<dyn AnoncredsRegistry>) -> Self {
+        self.registries.push(registry);
+        self
+    }
+
+    pub fn registry_for(&self, identifier: &str) -> VcxCoreResult<&dyn AnoncredsRegistry> {
+        let method = registry_method(identifier)?;
+        self.registries
+            .iter()
+            .find(|registry| registry.supports_method(method))
+            .map(|registry| registry.as_ref())
+            .ok_or_else(|| {
+                AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::ActionNotSupported,
+                    format!("no anoncreds registry supports method '{method}' of {identifier}"),
+                )
+            })
+    }
+}
+
+#[async_trait]
+impl AnoncredsRegistry for AnoncredsRegistryRouter {
+    fn supports_method(&self, method: &str) -> bool {
+        self.registries
+            .iter()
+            .any(|registry| registry.supports_method(method))
+    }
+
+    async fn get_schema(&self, schema_id: &SchemaId) -> VcxCoreResult<Schema> {
+        self.registry_for(&schema_id.0)?.get_schema(schema_id).await
+    }
+
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &CredentialDefinitionId,
+    ) -> VcxCoreResult<CredentialDefinition> {
+        self.registry_for(&cred_def_id.0)?
+            .get_cred_def(cred_def_id)
+            .await
+    }
+
+    async fn get_rev_reg_def(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<RevocationRegistryDefinition> {
+        self.registry_for(&rev_reg_def_id.0)?
+            .get_rev_reg_def(rev_reg_def_id)
+            .await
+    }
+
+    async fn get_rev_reg(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        timestamp: u64,
+    ) -> VcxCoreResult<(RevocationRegistry, u64)> {
+        self.registry_for(&rev_reg_def_id.0)?
+            .get_rev_reg(rev_reg_def_id, timestamp)
+            .await
+    }
+
+    async fn get_rev_reg_delta(
+        &self,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+        self.registry_for(&rev_reg_def_id.0)?
+            .get_rev_reg_delta(rev_reg_def_id, from, to)
+            .await
+    }
+
+    async fn register_schema(&self, wallet: &dyn BaseWallet, schema: Schema) -> VcxCoreResult<()> {
+        self.registry_for(&schema.issuer_id.0)?
+            .register_schema(wallet, schema)
+            .await
+    }
+
+    async fn register_cred_def(
+        &self,
+        wallet: &dyn BaseWallet,
+        cred_def: CredentialDefinition,
+    ) -> VcxCoreResult<()> {
+        self.registry_for(&cred_def.issuer_id.0)?
+            .register_cred_def(wallet, cred_def)
+            .await
+    }
+
+    async fn register_rev_reg_def(
+        &self,
+        wallet: &dyn BaseWallet,
+        issuer_id: &IssuerId,
+        rev_reg_def: RevocationRegistryDefinition,
+    ) -> VcxCoreResult<()> {
+        self.registry_for(&issuer_id.0)?
+            .register_rev_reg_def(wallet, issuer_id, rev_reg_def)
+            .await
+    }
+
+    async fn register_rev_reg_entry(
+        &self,
+        wallet: &dyn BaseWallet,
+        issuer_id: &IssuerId,
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        rev_reg_delta: RevocationRegistryDelta,
+    ) -> VcxCoreResult<()> {
+        self.registry_for(&issuer_id.0)?
+            .register_rev_reg_entry(wallet, issuer_id, rev_reg_def_id, rev_reg_delta)
+            .await
+    }
+}
+
+/// Lets the router be used wherever ledger reads are expected, e.g. when verifying
+/// presentations of credentials issued on any of the registered registries.
+#[async_trait]
+impl AnoncredsLedgerRead for AnoncredsRegistryRouter {
+    async fn get_schema(
+        &self,
+        schema_id: &SchemaId,
+        _submitter_did: Option<&Did>,
+    ) -> VcxCoreResult<Schema> {
+        AnoncredsRegistry::get_schema(self, schema_id).await
+    }
+
+    async fn get_cred_def(
+        &self,
+        cred_def_id: &CredentialDefinitionId,
+        _submitter_did: Option<&Did>,
+    ) -> VcxCoreResult<CredentialDefinition> {
+        AnoncredsRegistry::get_cred_def(self, cred_def_id).await
+    }
+
+    async fn get_rev_reg_def_json(
+        &self,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<RevocationRegistryDefinition> {
+        self.get_rev_reg_def(rev_reg_id).await
+    }
+
+    async fn get_rev_reg_delta_json(
+        &self,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        from: Option<u64>,
+        to: Option<u64>,
+    ) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+        self.get_rev_reg_delta(rev_reg_id, from, to).await
+    }
+
+    async fn get_rev_reg(
+        &self,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        timestamp: u64,
+    ) -> VcxCoreResult<(RevocationRegistry, u64)> {
+        AnoncredsRegistry::get_rev_reg(self, rev_reg_id, timestamp).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct NamedRegistry {
+        method: &'static str,
+    }
+
+    fn not_supported() -> AriesVcxCoreError {
+        AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::ActionNotSupported,
+            "not supported by the test registry",
+        )
+    }
+
+    #[async_trait]
+    #[allow(unused_variables)]
+    impl AnoncredsRegistry for NamedRegistry {
+        fn supports_method(&self, method: &str) -> bool {
+            self.method == method
+        }
+
+        async fn get_schema(&self, schema_id: &SchemaId) -> VcxCoreResult<Schema> {
+            Ok(Schema {
+                id: schema_id.clone(),
+                name: self.method.to_owned(),
+                ..Default::default()
+            })
+        }
+
+        async fn get_cred_def(
+            &self,
+            cred_def_id: &CredentialDefinitionId,
+        ) -> VcxCoreResult<CredentialDefinition> {
+            Err(not_supported())
+        }
+
+        async fn get_rev_reg_def(
+            &self,
+            rev_reg_def_id: &RevocationRegistryDefinitionId,
+        ) -> VcxCoreResult<RevocationRegistryDefinition> {
+            Err(not_supported())
+        }
+
+        async fn get_rev_reg(
+            &self,
+            rev_reg_def_id: &RevocationRegistryDefinitionId,
+            timestamp: u64,
+        ) -> VcxCoreResult<(RevocationRegistry, u64)> {
+            Err(not_supported())
+        }
+
+        async fn get_rev_reg_delta(
+            &self,
+            rev_reg_def_id: &RevocationRegistryDefinitionId,
+            from: Option<u64>,
+            to: Option<u64>,
+        ) -> VcxCoreResult<(RevocationRegistryDelta, u64)> {
+            Err(not_supported())
+        }
+
+        async fn register_schema(
+            &self,
+            wallet: &dyn BaseWallet,
+            schema: Schema,
+        ) -> VcxCoreResult<()> {
+            Err(not_supported())
+        }
+
+        async fn register_cred_def(
+            &self,
+            wallet: &dyn BaseWallet,
+            cred_def: CredentialDefinition,
+        ) -> VcxCoreResult<()> {
+            Err(not_supported())
+        }
+
+        async fn register_rev_reg_def(
+            &self,
+            wallet: &dyn BaseWallet,
+            issuer_id: &IssuerId,
+            rev_reg_def: RevocationRegistryDefinition,
+        ) -> VcxCoreResult<()> {
+            Err(not_supported())
+        }
+
+        async fn register_rev_reg_entry(
+            &self,
+            wallet: &dyn BaseWallet,
+            issuer_id: &IssuerId,
+            rev_reg_def_id: &RevocationRegistryDefinitionId,
+            rev_reg_delta: RevocationRegistryDelta,
+        ) -> VcxCoreResult<()> {
+            Err(not_supported())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_router_dispatches_by_method() {
+        let router = AnoncredsRegistryRouter::new()
+            .register(Arc::new(NamedRegistry { method: "sov" }))
+            .register(Arc::new(NamedRegistry { method: "web" }));
+
+        let schema = AnoncredsRegistry::get_schema(
+            &router,
+            &SchemaId::new_unchecked("V4SGRU86Z58d6TV7PBUe6f:2:gvt:1.0"),
+        )
+        .await
+        .unwrap();
+        assert_eq!("sov", schema.name);
+
+        let schema = AnoncredsLedgerRead::get_schema(
+            &router,
+            &SchemaId::new_unchecked("did:web:example.org/schemas/gvt"),
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!("web", schema.name);
+
+        let err = AnoncredsRegistry::get_schema(
+            &router,
+            &SchemaId::new_unchecked("did:cheqd:mainnet:abc/resources/1"),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(AriesVcxCoreErrorKind::ActionNotSupported, err.kind());
+
+        let err = AnoncredsRegistry::get_cred_def(
+            &router,
+            &CredentialDefinitionId::new_unchecked("V4SGRU86Z58d6TV7PBUe6f:3:CL:1:tag"),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(AriesVcxCoreErrorKind::ActionNotSupported, err.kind());
+    }
+}
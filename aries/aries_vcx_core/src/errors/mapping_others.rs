@@ -14,6 +14,12 @@ impl From<serde_json::Error> for AriesVcxCoreError {
     }
 }
 
+impl From<std::io::Error> for AriesVcxCoreError {
+    fn from(err: std::io::Error) -> Self {
+        AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::IOError, err.to_string())
+    }
+}
+
 impl<T> From<PoisonError<T>> for AriesVcxCoreError {
     fn from(err: PoisonError<T>) -> Self {
         AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidState, err.to_string())
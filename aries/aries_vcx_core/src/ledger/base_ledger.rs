@@ -2,13 +2,13 @@ use std::fmt::Debug;
 
 use anoncreds_types::data_types::{
     identifiers::{
-        cred_def_id::CredentialDefinitionId, rev_reg_def_id::RevocationRegistryDefinitionId,
-        schema_id::SchemaId,
+        cred_def_id::CredentialDefinitionId, issuer_id::IssuerId,
+        rev_reg_def_id::RevocationRegistryDefinitionId, schema_id::SchemaId,
     },
     ledger::{
         cred_def::CredentialDefinition, rev_reg::RevocationRegistry,
         rev_reg_def::RevocationRegistryDefinition, rev_reg_delta::RevocationRegistryDelta,
-        schema::Schema,
+        rev_status_list::RevocationStatusList, schema::Schema,
     },
 };
 use async_trait::async_trait;
@@ -17,12 +17,30 @@ use indy_vdr::ledger::constants::UpdateRole;
 use public_key::Key;
 use serde::Serialize;
 
-use crate::{errors::error::VcxCoreResult, wallet::base_wallet::BaseWallet};
+use crate::{
+    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
+    wallet::base_wallet::BaseWallet,
+};
 
 #[async_trait]
 pub trait IndyLedgerRead: Debug + Send + Sync {
     async fn get_attr(&self, target_did: &Did, attr_name: &str) -> VcxCoreResult<String>;
     async fn get_nym(&self, did: &Did) -> VcxCoreResult<String>;
+    /// Reads the attribute as of the transaction with `seq_no`, or as of `timestamp`.
+    async fn get_attr_version(
+        &self,
+        target_did: &Did,
+        attr_name: &str,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<String>;
+    /// Reads the NYM as of the transaction with `seq_no`, or as of `timestamp`.
+    async fn get_nym_version(
+        &self,
+        did: &Did,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<String>;
     async fn get_txn_author_agreement(&self) -> VcxCoreResult<Option<String>>;
     async fn get_ledger_txn(
         &self,
@@ -70,6 +88,8 @@ pub trait IndyLedgerWrite: Debug + Send + Sync {
         role: Option<UpdateRole>,
         alias: Option<String>,
     ) -> VcxCoreResult<String>;
+    /// Deactivates the DID by clearing its verkey, signed by the DID itself.
+    async fn deactivate_nym(&self, wallet: &impl BaseWallet, did: &Did) -> VcxCoreResult<String>;
 }
 
 #[async_trait]
@@ -99,36 +119,93 @@ pub trait AnoncredsLedgerRead: Debug + Send + Sync {
         rev_reg_id: &RevocationRegistryDefinitionId,
         timestamp: u64,
     ) -> VcxCoreResult<(RevocationRegistry, u64)>;
+    /// Returns the revocation status list valid at `timestamp`, or the latest one, along with
+    /// the time it was published at. The definition of the registry is fetched if not provided.
+    async fn get_rev_status_list(
+        &self,
+        rev_reg_id: &RevocationRegistryDefinitionId,
+        timestamp: Option<u64>,
+        rev_reg_def: Option<&RevocationRegistryDefinition>,
+    ) -> VcxCoreResult<(RevocationStatusList, u64)> {
+        let max_cred_num = match rev_reg_def {
+            Some(rev_reg_def) => rev_reg_def.value.max_cred_num,
+            None => {
+                self.get_rev_reg_def_json(rev_reg_id)
+                    .await?
+                    .value
+                    .max_cred_num
+            }
+        };
+        let (delta, timestamp) = self
+            .get_rev_reg_delta_json(rev_reg_id, None, timestamp)
+            .await?;
+        let rev_status_list = RevocationStatusList::from_delta(
+            rev_reg_id,
+            rev_reg_issuer_id(rev_reg_id)?,
+            max_cred_num,
+            &delta.value,
+            Some(timestamp),
+        )?;
+        Ok((rev_status_list, timestamp))
+    }
 }
 
 #[async_trait]
 pub trait AnoncredsLedgerWrite: Debug + Send + Sync {
     async fn publish_schema(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         schema_json: Schema,
         submitter_did: &Did,
         endorser_did: Option<&Did>,
     ) -> VcxCoreResult<()>;
     async fn publish_cred_def(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         cred_def_json: CredentialDefinition,
         submitter_did: &Did,
     ) -> VcxCoreResult<()>;
     async fn publish_rev_reg_def(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         rev_reg_def: RevocationRegistryDefinition,
         submitter_did: &Did,
     ) -> VcxCoreResult<()>;
     async fn publish_rev_reg_delta(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         rev_reg_id: &RevocationRegistryDefinitionId,
         rev_reg_entry_json: RevocationRegistryDelta,
         submitter_did: &Did,
     ) -> VcxCoreResult<()>;
+    /// Publishes the entry updating the registry from `prev_rev_status_list`, the list currently
+    /// on the ledger, to `rev_status_list`.
+    async fn publish_rev_status_list(
+        &self,
+        wallet: &(impl BaseWallet + ?Sized),
+        prev_rev_status_list: &RevocationStatusList,
+        rev_status_list: &RevocationStatusList,
+        submitter_did: &Did,
+    ) -> VcxCoreResult<()> {
+        let rev_reg_id = rev_status_list.rev_reg_def_id().ok_or_else(|| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidInput,
+                "revocation status list is missing the revocation registry id",
+            )
+        })?;
+        let delta = rev_status_list.to_delta(Some(prev_rev_status_list))?;
+        self.publish_rev_reg_delta(wallet, rev_reg_id, delta, submitter_did)
+            .await
+    }
+}
+
+/// Extracts the issuer from a legacy Indy (`<did>:4:...`) or DID based revocation registry id.
+pub fn rev_reg_issuer_id(rev_reg_id: &RevocationRegistryDefinitionId) -> VcxCoreResult<IssuerId> {
+    let issuer_id = match rev_reg_id.0.split_once(":4:") {
+        Some((did, _)) if !did.starts_with("did:") => did,
+        _ => rev_reg_id.0.split('/').next().unwrap_or_default(),
+    };
+    Ok(IssuerId::new(issuer_id)?)
 }
 
 pub trait TaaConfigurator: Debug + Send + Sync {
@@ -180,7 +180,7 @@ where
     }
 
     async fn sign_request(
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         did: &Did,
         request: &PreparedRequest,
     ) -> VcxCoreResult<Vec<u8>> {
@@ -192,7 +192,7 @@ where
 
     async fn sign_and_submit_request(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         submitter_did: &Did,
         request: PreparedRequest,
     ) -> VcxCoreResult<String> {
@@ -247,28 +247,54 @@ where
     V: ResponseCacher + Send + Sync,
 {
     async fn get_attr(&self, target_did: &Did, attr_name: &str) -> VcxCoreResult<String> {
-        debug!("get_attr >> target_did: {target_did}, attr_name: {attr_name}");
+        self.get_attr_version(target_did, attr_name, None, None)
+            .await
+    }
+
+    async fn get_nym(&self, did: &Did) -> VcxCoreResult<String> {
+        self.get_nym_version(did, None, None).await
+    }
+
+    async fn get_attr_version(
+        &self,
+        target_did: &Did,
+        attr_name: &str,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<String> {
+        debug!(
+            "get_attr_version >> target_did: {target_did}, attr_name: {attr_name}, seq_no: \
+             {seq_no:?}, timestamp: {timestamp:?}"
+        );
         let request = self.request_builder()?.build_get_attrib_request(
             None,
             &target_did.convert(())?,
             Some(attr_name.to_string()),
             None,
             None,
-            None,
-            None,
+            seq_no,
+            timestamp,
         )?;
         let response = self.submit_request(None, request).await?;
-        debug!("get_attr << response: {response}");
+        debug!("get_attr_version << response: {response}");
         Ok(response)
     }
 
-    async fn get_nym(&self, did: &Did) -> VcxCoreResult<String> {
-        debug!("get_nym >> did: {did}");
-        let request =
-            self.request_builder()?
-                .build_get_nym_request(None, &did.convert(())?, None, None)?;
+    async fn get_nym_version(
+        &self,
+        did: &Did,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<String> {
+        debug!("get_nym_version >> did: {did}, seq_no: {seq_no:?}, timestamp: {timestamp:?}");
+        let request = self.request_builder()?.build_get_nym_request(
+            None,
+            &did.convert(())?,
+            seq_no,
+            timestamp,
+        )?;
         let response = self.submit_request(None, request).await?;
-        debug!("get_nym << response: {response}");
+        debug!("get_nym_version << response: {response}");
         Ok(response)
     }
 
@@ -439,6 +465,23 @@ where
         debug!("write_did << response: {response}");
         return Ok(response);
     }
+
+    async fn deactivate_nym(&self, wallet: &impl BaseWallet, did: &Did) -> VcxCoreResult<String> {
+        let identifier = did.convert(())?;
+        let mut request = self.request_builder()?.build_nym_request(
+            &identifier,
+            &identifier,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        // a NYM without verkey leaves it unchanged, deactivation takes an explicit null
+        request.req_json["operation"]["verkey"] = Value::Null;
+        let request = self.append_txn_author_agreement_to_request(request).await?;
+        self.sign_and_submit_request(wallet, did, request).await
+    }
 }
 
 #[async_trait]
@@ -575,7 +618,7 @@ where
 {
     async fn publish_schema(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         schema_json: Schema,
         submitter_did: &Did,
         _endorser_did: Option<&Did>,
@@ -611,7 +654,7 @@ where
 
     async fn publish_cred_def(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         cred_def_json: CredentialDefinition,
         submitter_did: &Did,
     ) -> VcxCoreResult<()> {
@@ -627,7 +670,7 @@ where
 
     async fn publish_rev_reg_def(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         rev_reg_def: RevocationRegistryDefinition,
         submitter_did: &Did,
     ) -> VcxCoreResult<()> {
@@ -643,7 +686,7 @@ where
 
     async fn publish_rev_reg_delta(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         rev_reg_id: &RevocationRegistryDefinitionId,
         rev_reg_entry_json: RevocationRegistryDelta,
         submitter_did: &Did,
@@ -23,6 +23,7 @@ extern crate serde_json;
 extern crate log;
 
 pub mod anoncreds;
+pub mod anoncreds_registry;
 pub mod errors;
 pub mod global;
 pub mod ledger;
@@ -9,8 +9,10 @@ use public_key::{Key, KeyType};
 
 use super::{
     base_wallet::{
-        did_data::DidData, record::Record, record_category::RecordCategory,
-        search_filter::SearchFilter, BaseWallet, DidWallet, RecordWallet,
+        did_data::DidData, key_data::KeyData, record::Record, record_category::RecordCategory,
+        record_iterator::RecordIterator, search_filter::SearchFilter,
+        search_options::SearchOptions, wallet_archive::WalletArchive, BackupWallet, BaseWallet,
+        DidWallet, KeyWallet, RecordWallet,
     },
     record_tags::RecordTags,
     structs_io::UnpackMessageOutput,
@@ -68,6 +70,25 @@ impl RecordWallet for AgencyClientWallet {
     ) -> VcxCoreResult<Vec<Record>> {
         Err(unimplemented_agency_client_wallet_method("search_record"))
     }
+
+    async fn search_record_iter(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+        options: SearchOptions,
+    ) -> VcxCoreResult<RecordIterator> {
+        Err(unimplemented_agency_client_wallet_method(
+            "search_record_iter",
+        ))
+    }
+
+    async fn count_records(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+    ) -> VcxCoreResult<usize> {
+        Err(unimplemented_agency_client_wallet_method("count_records"))
+    }
 }
 
 #[async_trait]
@@ -142,6 +163,47 @@ impl DidWallet for AgencyClientWallet {
     }
 }
 
+#[async_trait]
+#[allow(unused_variables)]
+impl KeyWallet for AgencyClientWallet {
+    async fn create_key(
+        &self,
+        key_type: KeyType,
+        seed: Option<&str>,
+        tags: RecordTags,
+    ) -> VcxCoreResult<KeyData> {
+        Err(unimplemented_agency_client_wallet_method("create_key"))
+    }
+
+    async fn get_key(&self, name: &str) -> VcxCoreResult<KeyData> {
+        Err(unimplemented_agency_client_wallet_method("get_key"))
+    }
+
+    async fn list_keys(&self, key_type: Option<KeyType>) -> VcxCoreResult<Vec<KeyData>> {
+        Err(unimplemented_agency_client_wallet_method("list_keys"))
+    }
+
+    async fn update_key_tags(&self, name: &str, new_tags: RecordTags) -> VcxCoreResult<()> {
+        Err(unimplemented_agency_client_wallet_method("update_key_tags"))
+    }
+
+    async fn delete_key(&self, name: &str) -> VcxCoreResult<()> {
+        Err(unimplemented_agency_client_wallet_method("delete_key"))
+    }
+}
+
+#[async_trait]
+#[allow(unused_variables)]
+impl BackupWallet for AgencyClientWallet {
+    async fn export_archive(&self) -> VcxCoreResult<WalletArchive> {
+        Err(unimplemented_agency_client_wallet_method("export_archive"))
+    }
+
+    async fn import_archive(&self, archive: WalletArchive) -> VcxCoreResult<()> {
+        Err(unimplemented_agency_client_wallet_method("import_archive"))
+    }
+}
+
 pub trait ToBaseWallet {
     fn to_base_wallet(&self) -> AgencyClientWallet;
 }
@@ -0,0 +1,106 @@
+use aries_askar::{entry::EntryTag, kms::LocalKey};
+use async_trait::async_trait;
+
+use super::{
+    askar_utils::{
+        bs58_to_bytes, bytes_to_bs58, key_type_to_key_alg, local_key_to_bs58_name,
+        local_key_to_public_key,
+    },
+    AskarWallet,
+};
+use crate::{
+    errors::error::VcxCoreResult,
+    wallet::base_wallet::{
+        did_value::DidValue,
+        record_category::RecordCategory,
+        wallet_archive::{ArchivedDid, ArchivedKey, ArchivedRecord, WalletArchive},
+        BackupWallet, RecordWallet,
+    },
+};
+
+#[async_trait]
+impl BackupWallet for AskarWallet {
+    async fn export_archive(&self) -> VcxCoreResult<WalletArchive> {
+        let mut session = self.session().await?;
+        let mut archive = WalletArchive::default();
+
+        for entry in session
+            .fetch_all(Some(&RecordCategory::Did.to_string()), None, None, false)
+            .await?
+        {
+            let did_value: DidValue = serde_json::from_slice(&entry.value)?;
+            archive.dids.push(ArchivedDid {
+                did: entry.name,
+                verkey: did_value.verkey().to_owned(),
+            });
+        }
+
+        for entry in session
+            .fetch_all_keys(None, None, None, None, false)
+            .await?
+        {
+            let local_key = entry.load_local_key()?;
+            archive.keys.push(ArchivedKey {
+                key: local_key_to_public_key(&local_key)?,
+                secret: bytes_to_bs58(&local_key.to_secret_bytes()?),
+                tags: entry.tags_as_slice().to_vec().into(),
+            });
+        }
+
+        for category in RecordCategory::vcx_categories() {
+            archive.records.extend(
+                self.search_record(category, None)
+                    .await?
+                    .into_iter()
+                    .map(ArchivedRecord::from),
+            );
+        }
+
+        Ok(archive)
+    }
+
+    async fn import_archive(&self, archive: WalletArchive) -> VcxCoreResult<()> {
+        let mut tx = self.transaction().await?;
+
+        for archived_key in archive.keys {
+            let local_key = LocalKey::from_secret_bytes(
+                key_type_to_key_alg(*archived_key.key.key_type())?,
+                &bs58_to_bytes(archived_key.secret.as_bytes())?,
+            )?;
+            let askar_tags: Vec<EntryTag> = archived_key.tags.into();
+            tx.insert_key(
+                &local_key_to_bs58_name(&local_key)?,
+                &local_key,
+                None,
+                Some(&askar_tags),
+                None,
+            )
+            .await?;
+        }
+
+        for archived_did in archive.dids {
+            self.insert_did(
+                &mut tx,
+                &archived_did.did,
+                &RecordCategory::Did.to_string(),
+                &archived_did.verkey,
+                None,
+            )
+            .await?;
+        }
+
+        for archived_record in archive.records {
+            let askar_tags: Vec<EntryTag> = archived_record.tags.into();
+            tx.insert(
+                &archived_record.category,
+                &archived_record.name,
+                archived_record.value.as_bytes(),
+                Some(&askar_tags),
+                None,
+            )
+            .await?;
+        }
+
+        Ok(tx.commit().await?)
+    }
+}
@@ -0,0 +1,105 @@
+use aries_askar::{
+    entry::EntryTag,
+    kms::{KeyEntry, LocalKey},
+};
+use async_trait::async_trait;
+use public_key::KeyType;
+
+use super::{
+    askar_utils::{key_type_to_key_alg, local_key_to_bs58_name, local_key_to_public_key},
+    rng_method::RngMethod,
+    AskarWallet,
+};
+use crate::{
+    errors::error::VcxCoreResult,
+    wallet::{
+        base_wallet::{key_data::KeyData, KeyWallet},
+        record_tags::RecordTags,
+    },
+};
+
+#[async_trait]
+impl KeyWallet for AskarWallet {
+    async fn create_key(
+        &self,
+        key_type: KeyType,
+        seed: Option<&str>,
+        tags: RecordTags,
+    ) -> VcxCoreResult<KeyData> {
+        let alg = key_type_to_key_alg(key_type)?;
+        let local_key = match seed {
+            Some(seed) => {
+                let rng_method = match key_type {
+                    KeyType::Bls12381g1 | KeyType::Bls12381g2 | KeyType::Bls12381g1g2 => {
+                        RngMethod::Bls
+                    }
+                    _ => RngMethod::RandomDet,
+                };
+                LocalKey::from_seed(alg, seed.as_bytes(), rng_method.into())?
+            }
+            None => LocalKey::generate(alg, false)?,
+        };
+
+        let name = local_key_to_bs58_name(&local_key)?;
+        let askar_tags: Vec<EntryTag> = tags.clone().into();
+        self.session()
+            .await?
+            .insert_key(&name, &local_key, None, Some(&askar_tags), None)
+            .await?;
+
+        Ok(KeyData::new(
+            &name,
+            &local_key_to_public_key(&local_key)?,
+            tags,
+        ))
+    }
+
+    async fn get_key(&self, name: &str) -> VcxCoreResult<KeyData> {
+        let entry = self
+            .fetch_key_entry(&mut self.session().await?, name)
+            .await?;
+
+        key_entry_to_key_data(&entry)
+    }
+
+    async fn list_keys(&self, key_type: Option<KeyType>) -> VcxCoreResult<Vec<KeyData>> {
+        let entries = self
+            .session()
+            .await?
+            .fetch_all_keys(None, None, None, None, false)
+            .await?;
+
+        let mut res = Vec::new();
+        for entry in entries.iter() {
+            let key_data = key_entry_to_key_data(entry)?;
+            if key_type.map_or(true, |key_type| key_data.key().key_type() == &key_type) {
+                res.push(key_data);
+            }
+        }
+
+        Ok(res)
+    }
+
+    async fn update_key_tags(&self, name: &str, new_tags: RecordTags) -> VcxCoreResult<()> {
+        let mut tx = self.transaction().await?;
+        let entry = self.fetch_key_entry(&mut tx, name).await?;
+        let askar_tags: Vec<EntryTag> = new_tags.into();
+        tx.update_key(name, entry.metadata(), Some(&askar_tags), None)
+            .await?;
+        Ok(tx.commit().await?)
+    }
+
+    async fn delete_key(&self, name: &str) -> VcxCoreResult<()> {
+        Ok(self.session().await?.remove_key(name).await?)
+    }
+}
+
+fn key_entry_to_key_data(entry: &KeyEntry) -> VcxCoreResult<KeyData> {
+    let local_key = entry.load_local_key()?;
+
+    Ok(KeyData::new(
+        entry.name(),
+        &local_key_to_public_key(&local_key)?,
+        entry.tags_as_slice().to_vec().into(),
+    ))
+}
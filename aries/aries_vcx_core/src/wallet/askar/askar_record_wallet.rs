@@ -1,12 +1,22 @@
-use aries_askar::entry::EntryTag;
+use std::{collections::VecDeque, str::FromStr, sync::Mutex};
+
+use aries_askar::{
+    entry::{Entry, EntryTag, TagFilter},
+    Scan,
+};
 use async_trait::async_trait;
 
 use super::AskarWallet;
 use crate::{
     errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
+    utils::async_fn_iterator::AsyncFnIterator,
     wallet::{
         base_wallet::{
-            record::Record, record_category::RecordCategory, search_filter::SearchFilter,
+            record::Record,
+            record_category::RecordCategory,
+            record_iterator::{RecordIterator, VecRecordIterator},
+            search_filter::SearchFilter,
+            search_options::SearchOptions,
             RecordWallet,
         },
         record_tags::RecordTags,
@@ -102,7 +112,6 @@ impl RecordWallet for AskarWallet {
             .await?)
     }
 
-    #[allow(unreachable_patterns)]
     async fn search_record(
         &self,
         category: RecordCategory,
@@ -113,15 +122,7 @@ impl RecordWallet for AskarWallet {
             .await?
             .fetch_all(
                 Some(&category.to_string()),
-                search_filter
-                    .map(|filter| match filter {
-                        SearchFilter::TagFilter(inner) => Ok(inner),
-                        _ => Err(AriesVcxCoreError::from_msg(
-                            AriesVcxCoreErrorKind::WalletError,
-                            "unsupported search filter",
-                        )),
-                    })
-                    .transpose()?,
+                to_tag_filter(search_filter)?,
                 None,
                 false,
             )
@@ -132,4 +133,104 @@ impl RecordWallet for AskarWallet {
             .into_iter()
             .collect::<Result<_, _>>()?)
     }
+
+    async fn search_record_iter(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+        options: SearchOptions,
+    ) -> VcxCoreResult<RecordIterator> {
+        if options.sort.is_some() {
+            let records = self.search_record(category, search_filter).await?;
+            return Ok(Box::new(VecRecordIterator::new(options.apply(records))));
+        }
+
+        let scan = self
+            .backend
+            .scan(
+                Some(self.profile.clone()),
+                Some(category.to_string()),
+                to_tag_filter(search_filter)?,
+                options.offset.map(to_askar_int).transpose()?,
+                options.limit.map(to_askar_int).transpose()?,
+            )
+            .await?;
+
+        Ok(Box::new(AskarRecordIterator {
+            scan: Mutex::new(scan),
+            buffer: VecDeque::new(),
+            done: false,
+        }))
+    }
+
+    async fn count_records(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+    ) -> VcxCoreResult<usize> {
+        let count = self
+            .session()
+            .await?
+            .count(Some(&category.to_string()), to_tag_filter(search_filter)?)
+            .await?;
+
+        usize::try_from(count)
+            .map_err(|err| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::WalletError, err))
+    }
+}
+
+struct AskarRecordIterator {
+    // only accessed through `&mut self`, the mutex makes the scan `Sync`
+    scan: Mutex<Scan<'static, Entry>>,
+    buffer: VecDeque<Entry>,
+    done: bool,
+}
+
+#[async_trait]
+impl AsyncFnIterator for AskarRecordIterator {
+    type Item = VcxCoreResult<Record>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.buffer.pop_front() {
+                return Some(Record::try_from(entry));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            let scan = match self.scan.get_mut() {
+                Ok(scan) => scan,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err.into()));
+                }
+            };
+
+            match scan.fetch_next().await {
+                Ok(Some(entries)) if !entries.is_empty() => self.buffer.extend(entries),
+                Ok(_) => self.done = true,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err.into()));
+                }
+            }
+        }
+    }
+}
+
+#[allow(deprecated)]
+fn to_tag_filter(search_filter: Option<SearchFilter>) -> VcxCoreResult<Option<TagFilter>> {
+    search_filter
+        .map(|filter| match filter {
+            SearchFilter::TagFilter(inner) => Ok(inner),
+            filter => Ok(TagFilter::from_str(&filter.to_json()?)?),
+        })
+        .transpose()
+}
+
+fn to_askar_int(value: usize) -> VcxCoreResult<i64> {
+    i64::try_from(value)
+        .map_err(|err| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidInput, err))
 }
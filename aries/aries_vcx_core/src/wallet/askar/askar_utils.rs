@@ -1,4 +1,7 @@
-use aries_askar::kms::{KeyAlg, LocalKey};
+use aries_askar::{
+    crypto::alg::{BlsCurves, EcCurves},
+    kms::{KeyAlg, LocalKey},
+};
 use public_key::{Key, KeyType};
 use serde::Deserialize;
 
@@ -19,10 +22,45 @@ pub fn local_key_to_bs58_public_key(local_key: &LocalKey) -> VcxCoreResult<Strin
 pub fn local_key_to_public_key(local_key: &LocalKey) -> VcxCoreResult<Key> {
     Ok(Key::new(
         local_key.to_public_bytes()?.to_vec(),
-        KeyType::Ed25519,
+        key_alg_to_key_type(local_key.algorithm())?,
     )?)
 }
 
+pub fn key_type_to_key_alg(key_type: KeyType) -> VcxCoreResult<KeyAlg> {
+    match key_type {
+        KeyType::Ed25519 => Ok(KeyAlg::Ed25519),
+        KeyType::X25519 => Ok(KeyAlg::X25519),
+        KeyType::P256 => Ok(KeyAlg::EcCurve(EcCurves::Secp256r1)),
+        KeyType::P384 => Ok(KeyAlg::EcCurve(EcCurves::Secp384r1)),
+        KeyType::Secp256k1 => Ok(KeyAlg::EcCurve(EcCurves::Secp256k1)),
+        KeyType::Bls12381g1 => Ok(KeyAlg::Bls12_381(BlsCurves::G1)),
+        KeyType::Bls12381g2 => Ok(KeyAlg::Bls12_381(BlsCurves::G2)),
+        KeyType::Bls12381g1g2 => Ok(KeyAlg::Bls12_381(BlsCurves::G1G2)),
+        // askar implements no P-521 curve
+        KeyType::P521 => Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidInput,
+            format!("key type not supported by askar wallet: {:?}", key_type),
+        )),
+    }
+}
+
+pub fn key_alg_to_key_type(key_alg: KeyAlg) -> VcxCoreResult<KeyType> {
+    match key_alg {
+        KeyAlg::Ed25519 => Ok(KeyType::Ed25519),
+        KeyAlg::X25519 => Ok(KeyType::X25519),
+        KeyAlg::EcCurve(EcCurves::Secp256r1) => Ok(KeyType::P256),
+        KeyAlg::EcCurve(EcCurves::Secp384r1) => Ok(KeyType::P384),
+        KeyAlg::EcCurve(EcCurves::Secp256k1) => Ok(KeyType::Secp256k1),
+        KeyAlg::Bls12_381(BlsCurves::G1) => Ok(KeyType::Bls12381g1),
+        KeyAlg::Bls12_381(BlsCurves::G2) => Ok(KeyType::Bls12381g2),
+        KeyAlg::Bls12_381(BlsCurves::G1G2) => Ok(KeyType::Bls12381g1g2),
+        _ => Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidInput,
+            format!("key algorithm has no public key type: {:?}", key_alg),
+        )),
+    }
+}
+
 pub fn ed25519_to_x25519(local_key: &LocalKey) -> VcxCoreResult<LocalKey> {
     Ok(local_key.convert_key(KeyAlg::X25519)?)
 }
@@ -9,7 +9,9 @@ use self::{askar_utils::local_key_to_bs58_name, rng_method::RngMethod};
 use super::base_wallet::{did_value::DidValue, record_category::RecordCategory, BaseWallet};
 use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult};
 
+mod askar_backup_wallet;
 mod askar_did_wallet;
+mod askar_key_wallet;
 mod askar_record_wallet;
 mod askar_utils;
 mod entry;
@@ -20,7 +22,7 @@ mod rng_method;
 mod sig_type;
 mod unpack;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct AskarWallet {
     backend: Store,
     profile: String,
@@ -57,6 +59,64 @@ impl AskarWallet {
         })
     }
 
+    /// Name of the profile this wallet reads from and writes to.
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+
+    /// Creates a new profile in the underlying store and returns a wallet scoped to it.
+    pub async fn create_profile(&self, profile: &str) -> VcxCoreResult<Self> {
+        let profile = self.backend.create_profile(Some(profile.into())).await?;
+
+        Ok(Self {
+            backend: self.backend.clone(),
+            profile,
+        })
+    }
+
+    pub async fn list_profiles(&self) -> VcxCoreResult<Vec<String>> {
+        Ok(self.backend.list_profiles().await?)
+    }
+
+    /// Returns a wallet sharing the underlying store, scoped to an existing profile.
+    pub async fn for_profile(&self, profile: &str) -> VcxCoreResult<Self> {
+        if !self
+            .list_profiles()
+            .await?
+            .iter()
+            .any(|name| name == profile)
+        {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::WalletNotFound,
+                format!("no profile with name '{}' found in wallet", profile),
+            ));
+        }
+
+        Ok(Self {
+            backend: self.backend.clone(),
+            profile: profile.into(),
+        })
+    }
+
+    /// Removes a profile together with all of its records and keys.
+    pub async fn remove_profile(&self, profile: &str) -> VcxCoreResult<()> {
+        if profile == self.profile {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidInput,
+                "cannot remove the profile of the wallet in use",
+            ));
+        }
+
+        if self.backend.remove_profile(profile.into()).await? {
+            Ok(())
+        } else {
+            Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::WalletNotFound,
+                format!("no profile with name '{}' found in wallet", profile),
+            ))
+        }
+    }
+
     async fn fetch_local_key(
         &self,
         session: &mut Session,
@@ -177,24 +237,113 @@ impl AskarWallet {
 
 #[cfg(test)]
 pub mod tests {
-    use crate::wallet::base_wallet::BaseWallet;
+    use public_key::KeyType;
+
+    use super::AskarWallet;
+    use crate::{
+        errors::error::AriesVcxCoreErrorKind,
+        wallet::{
+            base_wallet::{
+                record::Record, record_category::RecordCategory, BaseWallet, RecordWallet,
+            },
+            record_tags::RecordTags,
+        },
+    };
 
     pub async fn dev_setup_askar_wallet() -> Box<dyn BaseWallet> {
+        Box::new(dev_setup_askar_store().await)
+    }
+
+    async fn dev_setup_askar_store() -> AskarWallet {
         use aries_askar::StoreKeyMethod;
         use uuid::Uuid;
 
-        use crate::wallet::askar::AskarWallet;
+        AskarWallet::create(
+            "sqlite://:memory:",
+            StoreKeyMethod::Unprotected,
+            None.into(),
+            true,
+            &Uuid::new_v4().to_string(),
+        )
+        .await
+        .unwrap()
+    }
 
-        Box::new(
-            AskarWallet::create(
-                "sqlite://:memory:",
-                StoreKeyMethod::Unprotected,
-                None.into(),
-                true,
-                &Uuid::new_v4().to_string(),
-            )
+    #[tokio::test]
+    async fn askar_wallet_should_sign_and_verify_with_p256_key() {
+        let wallet = dev_setup_askar_wallet().await;
+
+        let key_data = wallet
+            .create_key(KeyType::P256, None, RecordTags::default())
             .await
-            .unwrap(),
-        )
+            .unwrap();
+        assert_eq!(&KeyType::P256, key_data.key().key_type());
+
+        let msg = "sign this".as_bytes();
+        let sig = wallet.sign(key_data.key(), msg).await.unwrap();
+
+        let res = wallet.verify(key_data.key(), msg, &sig).await.unwrap();
+        assert!(res);
+    }
+
+    #[tokio::test]
+    async fn askar_wallet_should_create_x25519_key() {
+        let wallet = dev_setup_askar_wallet().await;
+
+        let key_data = wallet
+            .create_key(KeyType::X25519, None, RecordTags::default())
+            .await
+            .unwrap();
+
+        let res = wallet.list_keys(Some(KeyType::X25519)).await.unwrap();
+        assert_eq!(1, res.len());
+        assert_eq!(key_data.key(), res[0].key());
+    }
+
+    #[tokio::test]
+    async fn askar_wallet_should_isolate_records_between_profiles() {
+        let wallet = dev_setup_askar_store().await;
+        let tenant = wallet.create_profile("tenant").await.unwrap();
+
+        let record = Record::builder()
+            .name("foo".into())
+            .category(RecordCategory::Cred)
+            .value("bar".into())
+            .build();
+        tenant.add_record(record).await.unwrap();
+
+        let res = wallet.get_record(RecordCategory::Cred, "foo").await;
+        assert_eq!(
+            AriesVcxCoreErrorKind::WalletRecordNotFound,
+            res.unwrap_err().kind()
+        );
+
+        let tenant = wallet.for_profile("tenant").await.unwrap();
+        let res = tenant
+            .get_record(RecordCategory::Cred, "foo")
+            .await
+            .unwrap();
+        assert_eq!("bar", res.value());
+    }
+
+    #[tokio::test]
+    async fn askar_wallet_should_list_and_remove_profiles() {
+        let wallet = dev_setup_askar_store().await;
+        wallet.create_profile("tenant").await.unwrap();
+
+        let profiles = wallet.list_profiles().await.unwrap();
+        assert!(profiles.iter().any(|name| name == "tenant"));
+        assert!(profiles.iter().any(|name| name == wallet.profile()));
+
+        wallet.remove_profile("tenant").await.unwrap();
+
+        let res = wallet.for_profile("tenant").await;
+        assert_eq!(
+            AriesVcxCoreErrorKind::WalletNotFound,
+            res.unwrap_err().kind()
+        );
+
+        let res = wallet.remove_profile(wallet.profile()).await;
+        assert_eq!(AriesVcxCoreErrorKind::InvalidInput, res.unwrap_err().kind());
     }
 }
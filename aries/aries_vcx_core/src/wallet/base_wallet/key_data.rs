@@ -0,0 +1,34 @@
+use public_key::Key;
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::record_tags::RecordTags;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KeyData {
+    name: String,
+    key: Key,
+    #[serde(default)]
+    tags: RecordTags,
+}
+
+impl KeyData {
+    pub fn new(name: &str, key: &Key, tags: RecordTags) -> Self {
+        Self {
+            name: name.into(),
+            key: key.clone(),
+            tags,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    pub fn tags(&self) -> &RecordTags {
+        &self.tags
+    }
+}
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
-use public_key::Key;
+use public_key::{Key, KeyType};
 
-use self::{did_data::DidData, record_category::RecordCategory};
+use self::{
+    did_data::DidData, key_data::KeyData, record_category::RecordCategory,
+    record_iterator::RecordIterator, search_options::SearchOptions, wallet_archive::WalletArchive,
+};
 use super::record_tags::RecordTags;
 use crate::{
     errors::error::VcxCoreResult,
@@ -13,11 +16,19 @@ use crate::{
 
 pub mod did_data;
 pub mod did_value;
+pub mod key_data;
 pub mod record;
 pub mod record_category;
+pub mod record_iterator;
 pub mod search_filter;
+pub mod search_options;
+pub mod wallet_archive;
+pub mod wql;
 
-pub trait BaseWallet: RecordWallet + DidWallet + Send + Sync + std::fmt::Debug {}
+pub trait BaseWallet:
+    RecordWallet + DidWallet + KeyWallet + BackupWallet + Send + Sync + std::fmt::Debug
+{
+}
 
 #[async_trait]
 pub trait DidWallet {
@@ -49,6 +60,37 @@ pub trait DidWallet {
     async fn unpack_message(&self, msg: &[u8]) -> VcxCoreResult<UnpackMessageOutput>;
 }
 
+/// Management of standalone keys which are not bound to a DID. Keys created here can be used
+/// with [`DidWallet::sign`], [`DidWallet::verify`] and [`DidWallet::pack_message`] like any
+/// DID key, provided the backend supports the key type for the operation.
+#[async_trait]
+pub trait KeyWallet {
+    async fn create_key(
+        &self,
+        key_type: KeyType,
+        seed: Option<&str>,
+        tags: RecordTags,
+    ) -> VcxCoreResult<KeyData>;
+
+    async fn get_key(&self, name: &str) -> VcxCoreResult<KeyData>;
+
+    async fn list_keys(&self, key_type: Option<KeyType>) -> VcxCoreResult<Vec<KeyData>>;
+
+    async fn update_key_tags(&self, name: &str, new_tags: RecordTags) -> VcxCoreResult<()>;
+
+    async fn delete_key(&self, name: &str) -> VcxCoreResult<()>;
+}
+
+/// Conversion of the whole wallet content into a backend-neutral [`WalletArchive`] and back.
+/// See [`wallet_archive::export_wallet`] and [`wallet_archive::import_wallet`] for encrypted
+/// backups on top of this.
+#[async_trait]
+pub trait BackupWallet {
+    async fn export_archive(&self) -> VcxCoreResult<WalletArchive>;
+
+    async fn import_archive(&self, archive: WalletArchive) -> VcxCoreResult<()>;
+}
+
 #[async_trait]
 pub trait RecordWallet {
     async fn add_record(&self, record: Record) -> VcxCoreResult<()>;
@@ -76,15 +118,40 @@ pub trait RecordWallet {
         category: RecordCategory,
         search_filter: Option<SearchFilter>,
     ) -> VcxCoreResult<Vec<Record>>;
+
+    /// Lazily fetches the records matching the filter, paginated and sorted as per `options`.
+    async fn search_record_iter(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+        options: SearchOptions,
+    ) -> VcxCoreResult<RecordIterator>;
+
+    /// Counts the records matching the filter, without fetching their values.
+    async fn count_records(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+    ) -> VcxCoreResult<usize>;
 }
 
 #[cfg(test)]
 mod tests {
+    use public_key::KeyType;
+
     use super::BaseWallet;
     use crate::{
         errors::error::AriesVcxCoreErrorKind,
         wallet::{
-            base_wallet::{record_category::RecordCategory, Record},
+            base_wallet::{
+                record_category::RecordCategory,
+                record_iterator::collect_records,
+                search_filter::SearchFilter,
+                search_options::{RecordSort, SearchOptions, SortBy, SortOrder},
+                wallet_archive::{export_wallet, import_wallet},
+                wql::Wql,
+                Record,
+            },
             record_tags::{RecordTag, RecordTags},
             utils::{did_from_key, random_seed},
         },
@@ -285,6 +352,179 @@ mod tests {
         assert_eq!(msg, unpacked.message);
     }
 
+    #[tokio::test]
+    async fn key_wallet_should_create_and_get_key() {
+        let wallet = build_test_wallet().await;
+
+        let tags: RecordTags = vec![RecordTag::new("purpose", "signing")].into();
+        let key_data = wallet
+            .create_key(KeyType::Ed25519, Some(&random_seed()), tags.clone())
+            .await
+            .unwrap();
+
+        let res = wallet.get_key(key_data.name()).await.unwrap();
+
+        assert_eq!(key_data.key(), res.key());
+        assert_eq!(&tags, res.tags());
+    }
+
+    #[tokio::test]
+    async fn key_wallet_should_sign_and_verify_with_standalone_key() {
+        let wallet = build_test_wallet().await;
+
+        let key_data = wallet
+            .create_key(KeyType::Ed25519, None, RecordTags::default())
+            .await
+            .unwrap();
+
+        let msg = "sign this".as_bytes();
+        let sig = wallet.sign(key_data.key(), msg).await.unwrap();
+
+        let res = wallet.verify(key_data.key(), msg, &sig).await.unwrap();
+        assert!(res);
+    }
+
+    #[tokio::test]
+    async fn key_wallet_should_list_keys() {
+        let wallet = build_test_wallet().await;
+
+        wallet
+            .create_key(KeyType::Ed25519, None, RecordTags::default())
+            .await
+            .unwrap();
+        wallet
+            .create_key(KeyType::Ed25519, None, RecordTags::default())
+            .await
+            .unwrap();
+
+        let res = wallet.list_keys(Some(KeyType::Ed25519)).await.unwrap();
+        assert_eq!(2, res.len());
+
+        let res = wallet.list_keys(Some(KeyType::X25519)).await.unwrap();
+        assert!(res.is_empty());
+    }
+
+    #[tokio::test]
+    async fn key_wallet_should_update_key_tags() {
+        let wallet = build_test_wallet().await;
+
+        let key_data = wallet
+            .create_key(
+                KeyType::Ed25519,
+                None,
+                vec![RecordTag::new("a", "b")].into(),
+            )
+            .await
+            .unwrap();
+
+        let new_tags: RecordTags = vec![RecordTag::new("c", "d")].into();
+        wallet
+            .update_key_tags(key_data.name(), new_tags.clone())
+            .await
+            .unwrap();
+
+        let res = wallet.get_key(key_data.name()).await.unwrap();
+        assert_eq!(&new_tags, res.tags());
+    }
+
+    #[tokio::test]
+    async fn key_wallet_should_delete_key() {
+        let wallet = build_test_wallet().await;
+
+        let key_data = wallet
+            .create_key(KeyType::Ed25519, None, RecordTags::default())
+            .await
+            .unwrap();
+
+        wallet.delete_key(key_data.name()).await.unwrap();
+
+        let err = wallet.get_key(key_data.name()).await.unwrap_err();
+        assert_eq!(AriesVcxCoreErrorKind::WalletRecordNotFound, err.kind());
+    }
+
+    #[tokio::test]
+    async fn backup_wallet_should_export_and_import_wallet() {
+        let wallet = build_test_wallet().await;
+
+        let did_data = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let record = Record::builder()
+            .name("foo".into())
+            .category(RecordCategory::Cred)
+            .value("bar".into())
+            .tags(vec![RecordTag::new("a", "b")].into())
+            .build();
+        wallet.add_record(record.clone()).await.unwrap();
+
+        let path = std::env::temp_dir().join(format!("wallet_{}.bak", uuid::Uuid::new_v4()));
+        export_wallet(wallet.as_ref(), &path, "backup_key")
+            .await
+            .unwrap();
+
+        let new_wallet = build_test_wallet().await;
+        import_wallet(new_wallet.as_ref(), &path, "backup_key")
+            .await
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let res = new_wallet
+            .get_record(RecordCategory::Cred, record.name())
+            .await
+            .unwrap();
+        assert_eq!(record.value(), res.value());
+        assert_eq!(record.tags(), res.tags());
+
+        let verkey = new_wallet.key_for_did(did_data.did()).await.unwrap();
+        assert_eq!(did_data.verkey(), &verkey);
+
+        let msg = "sign this".as_bytes();
+        let sig = new_wallet.sign(&verkey, msg).await.unwrap();
+        assert!(wallet.verify(&verkey, msg, &sig).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn backup_wallet_should_fail_import_with_wrong_backup_key() {
+        let wallet = build_test_wallet().await;
+        wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        let path = std::env::temp_dir().join(format!("wallet_{}.bak", uuid::Uuid::new_v4()));
+        export_wallet(wallet.as_ref(), &path, "backup_key")
+            .await
+            .unwrap();
+
+        let new_wallet = build_test_wallet().await;
+        let err = import_wallet(new_wallet.as_ref(), &path, "wrong_key")
+            .await
+            .unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(AriesVcxCoreErrorKind::WalletAccessFailed, err.kind());
+    }
+
+    #[tokio::test]
+    async fn backup_wallet_should_leave_wallet_unchanged_on_failed_import() {
+        let wallet = build_test_wallet().await;
+        let did_data = wallet.create_and_store_my_did(None, None).await.unwrap();
+        let record = Record::builder()
+            .name("foo".into())
+            .category(RecordCategory::Cred)
+            .value("bar".into())
+            .build();
+        wallet.add_record(record.clone()).await.unwrap();
+        let archive = wallet.export_archive().await.unwrap();
+
+        let new_wallet = build_test_wallet().await;
+        new_wallet.add_record(record).await.unwrap();
+
+        new_wallet.import_archive(archive).await.unwrap_err();
+
+        assert!(new_wallet.key_for_did(did_data.did()).await.is_err());
+        assert_eq!(0, new_wallet.key_count().await.unwrap());
+        assert!(new_wallet
+            .get_record(RecordCategory::Cred, "foo")
+            .await
+            .is_ok());
+    }
+
     #[tokio::test]
     async fn record_wallet_should_create_record() {
         let wallet = build_test_wallet().await;
@@ -375,6 +615,73 @@ mod tests {
         assert_eq!(2, res.len());
     }
 
+    #[tokio::test]
+    async fn record_wallet_should_search_for_records_with_wql() {
+        let wallet = build_test_wallet().await;
+
+        let category = RecordCategory::Cred;
+        for (name, tag_value) in [("foo", "a"), ("bar", "b"), ("baz", "a")] {
+            let record = Record::builder()
+                .name(name.into())
+                .category(category)
+                .value("xxx".into())
+                .tags(vec![RecordTag::new("~tag", tag_value)].into())
+                .build();
+            wallet.add_record(record).await.unwrap();
+        }
+
+        let filter: SearchFilter = Wql::eq("~tag", "a").into();
+        let res = wallet
+            .search_record(category, Some(filter.clone()))
+            .await
+            .unwrap();
+        assert_eq!(2, res.len());
+
+        let res = wallet.count_records(category, Some(filter)).await.unwrap();
+        assert_eq!(2, res);
+
+        let filter = SearchFilter::JsonFilter(r#"{"~tag": {"$neq": "a"}}"#.into());
+        let res = wallet.count_records(category, Some(filter)).await.unwrap();
+        assert_eq!(1, res);
+    }
+
+    #[tokio::test]
+    async fn record_wallet_should_search_records_paginated_and_sorted() {
+        let wallet = build_test_wallet().await;
+
+        let category = RecordCategory::Cred;
+        for name in ["c", "a", "d", "b", "e"] {
+            let record = Record::builder()
+                .name(name.into())
+                .category(category)
+                .value("xxx".into())
+                .build();
+            wallet.add_record(record).await.unwrap();
+        }
+
+        let options = SearchOptions::builder()
+            .offset(1)
+            .limit(3)
+            .sort(RecordSort::new(SortBy::Name, SortOrder::Descending))
+            .build();
+        let iterator = wallet
+            .search_record_iter(category, None, options)
+            .await
+            .unwrap();
+        let res = collect_records(iterator).await.unwrap();
+
+        let names: Vec<_> = res.iter().map(|record| record.name()).collect();
+        assert_eq!(vec!["d", "c", "b"], names);
+
+        let options = SearchOptions::builder().offset(2).limit(10).build();
+        let iterator = wallet
+            .search_record_iter(category, None, options)
+            .await
+            .unwrap();
+        let res = collect_records(iterator).await.unwrap();
+        assert_eq!(3, res.len());
+    }
+
     #[tokio::test]
     async fn record_wallet_should_update_record() {
         let wallet = build_test_wallet().await;
@@ -514,6 +821,27 @@ mod compat_tests {
         assert_eq!(msg, unpacked.message);
     }
 
+    #[tokio::test]
+    async fn wallet_compatibility_indy_archive_should_import_into_askar() {
+        let indy_wallet = dev_setup_indy_wallet().await;
+        let askar_wallet = dev_setup_askar_wallet().await;
+
+        let did_data = indy_wallet
+            .create_and_store_my_did(None, None)
+            .await
+            .unwrap();
+
+        let archive = indy_wallet.export_archive().await.unwrap();
+        askar_wallet.import_archive(archive).await.unwrap();
+
+        let verkey = askar_wallet.key_for_did(did_data.did()).await.unwrap();
+        assert_eq!(did_data.verkey(), &verkey);
+
+        let msg = "sign this".as_bytes();
+        let sig = askar_wallet.sign(&verkey, msg).await.unwrap();
+        assert!(indy_wallet.verify(&verkey, msg, &sig).await.unwrap());
+    }
+
     #[tokio::test]
     async fn wallet_compatibility_askar_should_pack_and_indy_should_unpack_anoncrypt() {
         let askar_wallet = dev_setup_askar_wallet().await;
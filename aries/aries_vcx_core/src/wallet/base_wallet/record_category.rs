@@ -7,6 +7,7 @@ use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind};
 
 const LINK_SECRET: &str = "VCX_LINK_SECRET";
 const CRED: &str = "VCX_CREDENTIAL";
+const CRED_METADATA: &str = "VCX_CRED_METADATA";
 const CRED_DEF: &str = "VCX_CRED_DEF";
 const CRED_KEY_CORRECTNESS_PROOF: &str = "VCX_CRED_KEY_CORRECTNESS_PROOF";
 const CRED_DEF_PRIV: &str = "VCX_CRED_DEF_PRIV";
@@ -17,6 +18,7 @@ const REV_REG_DELTA: &str = "VCX_REV_REG_DELTA";
 const REV_REG_INFO: &str = "VCX_REV_REG_INFO";
 const REV_REG_DEF: &str = "VCX_REV_REG_DEF";
 const REV_REG_DEF_PRIV: &str = "VCX_REV_REG_DEF_PRIV";
+const SUB_WALLET_ROUTE: &str = "VCX_SUB_WALLET_ROUTE";
 const DID: &str = "Indy::Did";
 const TMP_DID: &str = "Indy::TemporaryDid";
 
@@ -25,6 +27,7 @@ pub enum RecordCategory {
     #[default]
     LinkSecret,
     Cred,
+    CredMetadata,
     CredDef,
     CredKeyCorrectnessProof,
     CredDefPriv,
@@ -35,10 +38,34 @@ pub enum RecordCategory {
     RevRegInfo,
     RevRegDef,
     RevRegDefPriv,
+    SubWalletRoute,
     Did,
     TmpDid,
 }
 
+impl RecordCategory {
+    /// Categories of records written by aries_vcx_core itself, which carry the same value format
+    /// in every wallet backend. DID records are backend specific and are not included.
+    pub fn vcx_categories() -> [RecordCategory; 14] {
+        [
+            RecordCategory::LinkSecret,
+            RecordCategory::Cred,
+            RecordCategory::CredMetadata,
+            RecordCategory::CredDef,
+            RecordCategory::CredKeyCorrectnessProof,
+            RecordCategory::CredDefPriv,
+            RecordCategory::CredSchema,
+            RecordCategory::CredMapSchemaId,
+            RecordCategory::RevReg,
+            RecordCategory::RevRegDelta,
+            RecordCategory::RevRegInfo,
+            RecordCategory::RevRegDef,
+            RecordCategory::RevRegDefPriv,
+            RecordCategory::SubWalletRoute,
+        ]
+    }
+}
+
 impl FromStr for RecordCategory {
     type Err = AriesVcxCoreError;
 
@@ -46,6 +73,7 @@ impl FromStr for RecordCategory {
         match s {
             LINK_SECRET => Ok(RecordCategory::LinkSecret),
             CRED => Ok(RecordCategory::Cred),
+            CRED_METADATA => Ok(RecordCategory::CredMetadata),
             CRED_DEF => Ok(RecordCategory::CredDef),
             CRED_KEY_CORRECTNESS_PROOF => Ok(RecordCategory::CredKeyCorrectnessProof),
             CRED_DEF_PRIV => Ok(RecordCategory::CredDefPriv),
@@ -56,6 +84,7 @@ impl FromStr for RecordCategory {
             REV_REG_INFO => Ok(RecordCategory::RevRegInfo),
             REV_REG_DEF => Ok(RecordCategory::RevRegDef),
             REV_REG_DEF_PRIV => Ok(RecordCategory::RevRegDefPriv),
+            SUB_WALLET_ROUTE => Ok(RecordCategory::SubWalletRoute),
             DID => Ok(RecordCategory::Did),
             TMP_DID => Ok(RecordCategory::TmpDid),
             _ => Err(Self::Err::from_msg(
@@ -71,6 +100,7 @@ impl Display for RecordCategory {
         let value = match self {
             RecordCategory::LinkSecret => LINK_SECRET,
             RecordCategory::Cred => CRED,
+            RecordCategory::CredMetadata => CRED_METADATA,
             RecordCategory::CredDef => CRED_DEF,
             RecordCategory::CredKeyCorrectnessProof => CRED_KEY_CORRECTNESS_PROOF,
             RecordCategory::CredDefPriv => CRED_DEF_PRIV,
@@ -81,6 +111,7 @@ impl Display for RecordCategory {
             RecordCategory::RevRegInfo => REV_REG_INFO,
             RecordCategory::RevRegDef => REV_REG_DEF,
             RecordCategory::RevRegDefPriv => REV_REG_DEF_PRIV,
+            RecordCategory::SubWalletRoute => SUB_WALLET_ROUTE,
             RecordCategory::Did => DID,
             RecordCategory::TmpDid => TMP_DID,
         };
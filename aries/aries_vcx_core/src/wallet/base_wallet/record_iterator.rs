@@ -0,0 +1,41 @@
+use std::collections::VecDeque;
+
+use async_trait::async_trait;
+
+use super::record::Record;
+use crate::{errors::error::VcxCoreResult, utils::async_fn_iterator::AsyncFnIterator};
+
+pub type RecordIterator = Box<dyn AsyncFnIterator<Item = VcxCoreResult<Record>>>;
+
+/// Iterator over records which have already been fetched.
+#[derive(Debug, Default)]
+pub struct VecRecordIterator {
+    records: VecDeque<Record>,
+}
+
+impl VecRecordIterator {
+    pub fn new(records: Vec<Record>) -> Self {
+        Self {
+            records: records.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncFnIterator for VecRecordIterator {
+    type Item = VcxCoreResult<Record>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        self.records.pop_front().map(Ok)
+    }
+}
+
+/// Drains the iterator, stopping at the first error.
+pub async fn collect_records(mut iterator: RecordIterator) -> VcxCoreResult<Vec<Record>> {
+    let mut records = Vec::new();
+    while let Some(record) = iterator.next().await {
+        records.push(record?);
+    }
+
+    Ok(records)
+}
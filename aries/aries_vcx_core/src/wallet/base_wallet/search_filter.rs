@@ -1,6 +1,39 @@
+use std::str::FromStr;
+
+use super::wql::Wql;
+use crate::errors::error::VcxCoreResult;
+
+/// Backend-neutral filter for record searches.
+#[derive(Clone, Debug, PartialEq)]
 pub enum SearchFilter {
-    #[cfg(feature = "vdrtools_wallet")]
+    /// WQL query in its JSON representation.
     JsonFilter(String),
+    Wql(Wql),
     #[cfg(feature = "askar_wallet")]
+    #[deprecated(note = "use the backend-neutral `SearchFilter::Wql` instead")]
     TagFilter(aries_askar::entry::TagFilter),
 }
+
+impl SearchFilter {
+    /// Returns the filter as a WQL JSON query. Fails for the askar specific `TagFilter`, which
+    /// only the askar wallet can evaluate.
+    #[allow(deprecated)]
+    pub fn to_json(&self) -> VcxCoreResult<String> {
+        match self {
+            // validate the query, so that both backends reject the same inputs
+            Self::JsonFilter(json) => Ok(Wql::from_str(json)?.to_string()),
+            Self::Wql(wql) => Ok(wql.to_string()),
+            #[cfg(feature = "askar_wallet")]
+            Self::TagFilter(_) => Err(crate::errors::error::AriesVcxCoreError::from_msg(
+                crate::errors::error::AriesVcxCoreErrorKind::WalletError,
+                "askar tag filter is not supported by this wallet",
+            )),
+        }
+    }
+}
+
+impl From<Wql> for SearchFilter {
+    fn from(wql: Wql) -> Self {
+        Self::Wql(wql)
+    }
+}
@@ -0,0 +1,76 @@
+use std::cmp::Ordering;
+
+use typed_builder::TypedBuilder;
+
+use super::record::Record;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SortBy {
+    Name,
+    /// Value of the given tag. Records missing the tag are ordered first.
+    Tag(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordSort {
+    pub by: SortBy,
+    pub order: SortOrder,
+}
+
+impl RecordSort {
+    pub fn new(by: SortBy, order: SortOrder) -> Self {
+        Self { by, order }
+    }
+
+    pub fn compare(&self, a: &Record, b: &Record) -> Ordering {
+        let ordering = match &self.by {
+            SortBy::Name => a.name().cmp(b.name()),
+            SortBy::Tag(tag_name) => tag_value(a, tag_name).cmp(&tag_value(b, tag_name)),
+        };
+
+        match self.order {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    }
+}
+
+fn tag_value<'a>(record: &'a Record, tag_name: &str) -> Option<&'a str> {
+    record
+        .tags()
+        .iter()
+        .find(|tag| tag.key() == tag_name)
+        .map(|tag| tag.value())
+}
+
+/// Pagination and ordering of a record search. Neither backend sorts natively, so a search with
+/// `sort` set fetches all matching records before applying `offset` and `limit`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, TypedBuilder)]
+#[builder(field_defaults(default, setter(strip_option)))]
+pub struct SearchOptions {
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+    pub sort: Option<RecordSort>,
+}
+
+impl SearchOptions {
+    /// Sorts the records, if requested, and applies offset and limit.
+    pub fn apply(&self, mut records: Vec<Record>) -> Vec<Record> {
+        if let Some(sort) = &self.sort {
+            records.sort_by(|a, b| sort.compare(a, b));
+        }
+
+        records
+            .into_iter()
+            .skip(self.offset.unwrap_or_default())
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
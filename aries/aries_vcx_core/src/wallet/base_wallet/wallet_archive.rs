@@ -0,0 +1,215 @@
+use std::{path::Path, str::FromStr};
+
+use argon2::Argon2;
+use base64::{engine::general_purpose, Engine};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    XChaCha20Poly1305, XNonce,
+};
+use public_key::Key;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+
+use super::{record::Record, record_category::RecordCategory, BaseWallet};
+use crate::{
+    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
+    wallet::record_tags::RecordTags,
+};
+
+pub const WALLET_ARCHIVE_VERSION: u32 = 1;
+
+const ARCHIVE_KDF: &str = "argon2id";
+const ARCHIVE_CIPHER: &str = "xchacha20poly1305";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+
+/// Backend-neutral snapshot of everything held by a wallet. DIDs and keys are kept apart from
+/// plain records, because each backend stores them in its own format.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct WalletArchive {
+    pub dids: Vec<ArchivedDid>,
+    pub keys: Vec<ArchivedKey>,
+    pub records: Vec<ArchivedRecord>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ArchivedDid {
+    pub did: String,
+    pub verkey: Key,
+}
+
+/// A key pair, where `secret` holds the base58 encoded secret key bytes. For Ed25519 keys this
+/// is the 32 byte seed, regardless of the backend the key was exported from.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ArchivedKey {
+    pub key: Key,
+    pub secret: String,
+    #[serde(default)]
+    pub tags: RecordTags,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ArchivedRecord {
+    pub category: String,
+    pub name: String,
+    pub value: String,
+    #[serde(default)]
+    pub tags: RecordTags,
+}
+
+impl From<Record> for ArchivedRecord {
+    fn from(record: Record) -> Self {
+        Self {
+            category: record.category().to_string(),
+            name: record.name().into(),
+            value: record.value().into(),
+            tags: record.tags().clone(),
+        }
+    }
+}
+
+impl TryFrom<ArchivedRecord> for Record {
+    type Error = AriesVcxCoreError;
+
+    fn try_from(archived: ArchivedRecord) -> Result<Self, Self::Error> {
+        Ok(Record::builder()
+            .category(RecordCategory::from_str(&archived.category)?)
+            .name(archived.name)
+            .value(archived.value)
+            .tags(archived.tags)
+            .build())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct EncryptedWalletArchive {
+    version: u32,
+    kdf: String,
+    cipher: String,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Exports all DIDs, keys and records of the wallet into a single file encrypted with a key
+/// derived from `backup_key`.
+pub async fn export_wallet(
+    wallet: &(impl BaseWallet + ?Sized),
+    path: &Path,
+    backup_key: &str,
+) -> VcxCoreResult<()> {
+    trace!("export_wallet >>> path: {:?}, backup_key: ****", path);
+
+    let archive = wallet.export_archive().await?;
+    let plaintext = serde_json::to_vec(&archive)?;
+
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let cipher = archive_cipher(backup_key, &salt)?;
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce), plaintext.as_slice())
+        .map_err(|err| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::WalletError,
+                format!("failed to encrypt wallet archive: {err}"),
+            )
+        })?;
+
+    let encrypted = EncryptedWalletArchive {
+        version: WALLET_ARCHIVE_VERSION,
+        kdf: ARCHIVE_KDF.into(),
+        cipher: ARCHIVE_CIPHER.into(),
+        salt: general_purpose::STANDARD.encode(salt),
+        nonce: general_purpose::STANDARD.encode(nonce),
+        ciphertext: general_purpose::STANDARD.encode(ciphertext),
+    };
+
+    tokio::fs::write(path, serde_json::to_vec(&encrypted)?).await?;
+
+    Ok(())
+}
+
+/// Imports a file produced by [`export_wallet`] into the wallet. The wallet may be of a
+/// different backend than the one the archive was exported from.
+pub async fn import_wallet(
+    wallet: &(impl BaseWallet + ?Sized),
+    path: &Path,
+    backup_key: &str,
+) -> VcxCoreResult<()> {
+    trace!("import_wallet >>> path: {:?}, backup_key: ****", path);
+
+    let archive = read_wallet_archive(path, backup_key).await?;
+
+    wallet.import_archive(archive).await
+}
+
+/// Reads and decrypts a file produced by [`export_wallet`] without importing it.
+pub async fn read_wallet_archive(path: &Path, backup_key: &str) -> VcxCoreResult<WalletArchive> {
+    let encrypted: EncryptedWalletArchive = serde_json::from_slice(&tokio::fs::read(path).await?)?;
+
+    if encrypted.version != WALLET_ARCHIVE_VERSION {
+        return Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidInput,
+            format!(
+                "unsupported wallet archive version: {}, expected: {}",
+                encrypted.version, WALLET_ARCHIVE_VERSION
+            ),
+        ));
+    }
+
+    if encrypted.kdf != ARCHIVE_KDF || encrypted.cipher != ARCHIVE_CIPHER {
+        return Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidInput,
+            format!(
+                "unsupported wallet archive encryption: kdf: {}, cipher: {}",
+                encrypted.kdf, encrypted.cipher
+            ),
+        ));
+    }
+
+    let salt = decode_base64(&encrypted.salt)?;
+    let nonce = decode_base64(&encrypted.nonce)?;
+    let ciphertext = decode_base64(&encrypted.ciphertext)?;
+
+    if nonce.len() != NONCE_LEN {
+        return Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::InvalidInput,
+            "invalid wallet archive nonce length",
+        ));
+    }
+
+    let plaintext = archive_cipher(backup_key, &salt)?
+        .decrypt(XNonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|_| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::WalletAccessFailed,
+                "failed to decrypt wallet archive, invalid backup key or corrupted file",
+            )
+        })?;
+
+    Ok(serde_json::from_slice(&plaintext)?)
+}
+
+fn archive_cipher(backup_key: &str, salt: &[u8]) -> VcxCoreResult<XChaCha20Poly1305> {
+    let mut key = [0u8; KEY_LEN];
+    Argon2::default()
+        .hash_password_into(backup_key.as_bytes(), salt, &mut key)
+        .map_err(|err| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::WalletError,
+                format!("failed to derive wallet archive key: {err}"),
+            )
+        })?;
+
+    Ok(XChaCha20Poly1305::new(&key.into()))
+}
+
+fn decode_base64(value: &str) -> VcxCoreResult<Vec<u8>> {
+    general_purpose::STANDARD
+        .decode(value)
+        .map_err(|err| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidInput, err))
+}
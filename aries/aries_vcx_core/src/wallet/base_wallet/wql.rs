@@ -0,0 +1,244 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{json, Map, Value};
+
+use crate::errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind};
+
+/// Backend-neutral wallet query language (WQL) query over record tags. As with
+/// [`crate::wallet::record_tags::RecordTag`], tag names prefixed with `~` refer to unencrypted
+/// tags.
+///
+/// The JSON representation is the one used by both vdrtools and askar, e.g.
+/// `{"$and": [{"schema_id": "..."}, {"~attr::name::value": {"$neq": "Alice"}}]}`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Wql {
+    And(Vec<Wql>),
+    Or(Vec<Wql>),
+    Not(Box<Wql>),
+    Eq(String, String),
+    Neq(String, String),
+    Gt(String, String),
+    Gte(String, String),
+    Lt(String, String),
+    Lte(String, String),
+    Like(String, String),
+    In(String, Vec<String>),
+}
+
+impl Default for Wql {
+    fn default() -> Self {
+        Self::And(vec![])
+    }
+}
+
+impl Wql {
+    pub fn eq(name: &str, value: &str) -> Self {
+        Self::Eq(name.into(), value.into())
+    }
+
+    pub fn and(queries: Vec<Wql>) -> Self {
+        Self::And(queries)
+    }
+
+    pub fn or(queries: Vec<Wql>) -> Self {
+        Self::Or(queries)
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(query: Wql) -> Self {
+        Self::Not(Box::new(query))
+    }
+
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::And(queries) => {
+                if queries.is_empty() {
+                    Value::Object(Map::new())
+                } else {
+                    json!({ "$and": queries.iter().map(Wql::to_value).collect::<Vec<_>>() })
+                }
+            }
+            Self::Or(queries) => {
+                json!({ "$or": queries.iter().map(Wql::to_value).collect::<Vec<_>>() })
+            }
+            Self::Not(query) => json!({ "$not": query.to_value() }),
+            Self::Eq(name, value) => json!({ name: value }),
+            Self::Neq(name, value) => json!({ name: { "$neq": value } }),
+            Self::Gt(name, value) => json!({ name: { "$gt": value } }),
+            Self::Gte(name, value) => json!({ name: { "$gte": value } }),
+            Self::Lt(name, value) => json!({ name: { "$lt": value } }),
+            Self::Lte(name, value) => json!({ name: { "$lte": value } }),
+            Self::Like(name, value) => json!({ name: { "$like": value } }),
+            Self::In(name, values) => json!({ name: { "$in": values } }),
+        }
+    }
+
+    pub fn from_value(value: &Value) -> Result<Self, AriesVcxCoreError> {
+        let map = value
+            .as_object()
+            .ok_or_else(|| invalid_wql(format!("expected an object, got: {value}")))?;
+
+        let mut queries = map
+            .iter()
+            .map(|(key, value)| Self::parse_operator(key, value))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if queries.len() == 1 {
+            Ok(queries.remove(0))
+        } else {
+            Ok(Self::And(queries))
+        }
+    }
+
+    fn parse_operator(key: &str, value: &Value) -> Result<Self, AriesVcxCoreError> {
+        match key {
+            "$and" => Ok(Self::And(Self::parse_list(value)?)),
+            "$or" => Ok(Self::Or(Self::parse_list(value)?)),
+            "$not" => Ok(Self::not(Self::from_value(value)?)),
+            name => match value {
+                Value::String(value) => Ok(Self::Eq(name.into(), value.into())),
+                Value::Object(map) if map.len() == 1 => {
+                    let (operator, operand) = map.iter().next().unwrap();
+                    let name = name.to_owned();
+                    match operator.as_str() {
+                        "$neq" => Ok(Self::Neq(name, parse_str(operand)?)),
+                        "$gt" => Ok(Self::Gt(name, parse_str(operand)?)),
+                        "$gte" => Ok(Self::Gte(name, parse_str(operand)?)),
+                        "$lt" => Ok(Self::Lt(name, parse_str(operand)?)),
+                        "$lte" => Ok(Self::Lte(name, parse_str(operand)?)),
+                        "$like" => Ok(Self::Like(name, parse_str(operand)?)),
+                        "$in" => Ok(Self::In(
+                            name,
+                            operand
+                                .as_array()
+                                .ok_or_else(|| {
+                                    invalid_wql(format!("expected an array, got: {operand}"))
+                                })?
+                                .iter()
+                                .map(parse_str)
+                                .collect::<Result<_, _>>()?,
+                        )),
+                        operator => Err(invalid_wql(format!("unknown operator: {operator}"))),
+                    }
+                }
+                value => Err(invalid_wql(format!(
+                    "invalid value for tag {name}: {value}"
+                ))),
+            },
+        }
+    }
+
+    fn parse_list(value: &Value) -> Result<Vec<Self>, AriesVcxCoreError> {
+        value
+            .as_array()
+            .ok_or_else(|| invalid_wql(format!("expected an array, got: {value}")))?
+            .iter()
+            .map(Self::from_value)
+            .collect()
+    }
+}
+
+fn parse_str(value: &Value) -> Result<String, AriesVcxCoreError> {
+    value
+        .as_str()
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| invalid_wql(format!("expected a string, got: {value}")))
+}
+
+fn invalid_wql(msg: String) -> AriesVcxCoreError {
+    AriesVcxCoreError::from_msg(
+        AriesVcxCoreErrorKind::InvalidInput,
+        format!("invalid wql query: {msg}"),
+    )
+}
+
+impl Display for Wql {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_value())
+    }
+}
+
+impl FromStr for Wql {
+    type Err = AriesVcxCoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_value(&serde_json::from_str(s)?)
+    }
+}
+
+impl Serialize for Wql {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_value().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Wql {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        Self::from_value(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Wql;
+
+    #[test]
+    fn test_wql_parse_simple_query() {
+        let query: Wql = json!({"schema_id": "abc"}).to_string().parse().unwrap();
+
+        assert_eq!(Wql::eq("schema_id", "abc"), query);
+    }
+
+    #[test]
+    fn test_wql_parse_implicit_and() {
+        let query: Wql = json!({"a": "b", "c": {"$neq": "d"}})
+            .to_string()
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            Wql::and(vec![Wql::eq("a", "b"), Wql::Neq("c".into(), "d".into())]),
+            query
+        );
+    }
+
+    #[test]
+    fn test_wql_roundtrip_nested_query() {
+        let query = Wql::and(vec![
+            Wql::or(vec![Wql::eq("a", "b"), Wql::Gte("~c".into(), "1".into())]),
+            Wql::not(Wql::In("d".into(), vec!["e".into(), "f".into()])),
+        ]);
+
+        let res: Wql = query.to_string().parse().unwrap();
+
+        assert_eq!(query, res);
+    }
+
+    #[test]
+    fn test_wql_empty_query() {
+        let query: Wql = "{}".parse().unwrap();
+
+        assert_eq!(Wql::default(), query);
+        assert_eq!("{}", query.to_string());
+    }
+
+    #[test]
+    fn test_wql_invalid_operator() {
+        let res = json!({"a": {"$foo": "b"}}).to_string().parse::<Wql>();
+
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use indy_api_types::domain::wallet::IndyRecord;
+use public_key::{Key, KeyType};
+use serde::{Deserialize, Serialize};
+
+use super::{indy_tags::IndyTags, KEY_CATEGORY};
+use crate::{
+    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
+    wallet::{
+        base_wallet::{
+            record::Record,
+            record_category::RecordCategory,
+            wallet_archive::{ArchivedDid, ArchivedKey, ArchivedRecord, WalletArchive},
+            BackupWallet,
+        },
+        indy::IndySdkWallet,
+    },
+};
+
+const ED25519_SEED_LEN: usize = 32;
+
+/// Value of an `Indy::Did` record as stored by vdrtools.
+#[derive(Debug, Deserialize, Serialize)]
+struct IndyDidValue {
+    did: String,
+    verkey: String,
+}
+
+/// Value of an `Indy::Key` record as stored by vdrtools. The signkey is the 64 byte libsodium
+/// secret key, i.e. the 32 byte seed followed by the public key.
+#[derive(Debug, Deserialize, Serialize)]
+struct IndyKeyValue {
+    verkey: String,
+    signkey: String,
+}
+
+#[async_trait]
+impl BackupWallet for IndySdkWallet {
+    async fn export_archive(&self) -> VcxCoreResult<WalletArchive> {
+        let mut archive = WalletArchive::default();
+
+        for record in self.search(RecordCategory::Did, None).await? {
+            let did_value: IndyDidValue = serde_json::from_str(record.value())?;
+            archive.dids.push(ArchivedDid {
+                did: did_value.did,
+                verkey: Key::from_base58(&did_value.verkey, KeyType::Ed25519)?,
+            });
+        }
+
+        for record in self.search_indy_records(KEY_CATEGORY, None).await? {
+            let key_value: IndyKeyValue = serde_json::from_str(&record.value)?;
+            let signkey = bs58::decode(&key_value.signkey).into_vec().map_err(|err| {
+                AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::WalletError, err)
+            })?;
+            let seed = signkey.get(..ED25519_SEED_LEN).ok_or_else(|| {
+                AriesVcxCoreError::from_msg(
+                    AriesVcxCoreErrorKind::WalletError,
+                    format!("invalid signkey length for key: {}", key_value.verkey),
+                )
+            })?;
+            archive.keys.push(ArchivedKey {
+                key: Key::from_base58(&key_value.verkey, KeyType::Ed25519)?,
+                secret: bs58::encode(seed).into_string(),
+                tags: IndyTags::new(record.tags).into_record_tags(),
+            });
+        }
+
+        for category in RecordCategory::vcx_categories() {
+            archive.records.extend(
+                self.search(category, None)
+                    .await?
+                    .into_iter()
+                    .map(ArchivedRecord::from),
+            );
+        }
+
+        Ok(archive)
+    }
+
+    async fn import_archive(&self, archive: WalletArchive) -> VcxCoreResult<()> {
+        let records = archive_to_records(archive)?;
+
+        let mut imported: Vec<(String, String)> = Vec::with_capacity(records.len());
+        for record in records {
+            let id = (record.type_.clone(), record.id.clone());
+            if let Err(err) = self.add_indy_record(record).await {
+                self.rollback_import(imported).await;
+                return Err(err);
+            }
+            imported.push(id);
+        }
+
+        Ok(())
+    }
+}
+
+impl IndySdkWallet {
+    /// Removes records added by a failed import, so the wallet is left as it was before. The
+    /// indy wallet has no transactions, hence the import is undone record by record.
+    async fn rollback_import(&self, imported: Vec<(String, String)>) {
+        for (category, name) in imported.into_iter().rev() {
+            if let Err(err) = self.delete_indy_record(&category, &name).await {
+                warn!("failed to roll back imported record {category}/{name}: {err}");
+            }
+        }
+    }
+}
+
+/// Converts the archive into indy wallet records. All entries are validated before anything is
+/// written, so a malformed archive never leaves the wallet partially imported.
+fn archive_to_records(archive: WalletArchive) -> VcxCoreResult<Vec<IndyRecord>> {
+    let mut records = Vec::new();
+
+    for archived_key in archive.keys {
+        if archived_key.key.key_type() != &KeyType::Ed25519 {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidInput,
+                format!(
+                    "key type not supported by indy wallet: {:?}",
+                    archived_key.key.key_type()
+                ),
+            ));
+        }
+
+        let mut signkey = bs58::decode(&archived_key.secret)
+            .into_vec()
+            .map_err(|err| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::InvalidInput, err))?;
+        signkey.extend_from_slice(archived_key.key.key());
+
+        let verkey = archived_key.key.base58();
+        let value = IndyKeyValue {
+            verkey: verkey.clone(),
+            signkey: bs58::encode(signkey).into_string(),
+        };
+        records.push(IndyRecord {
+            type_: KEY_CATEGORY.into(),
+            id: verkey,
+            value: serde_json::to_string(&value)?,
+            tags: IndyTags::from_record_tags(archived_key.tags).into_inner(),
+        });
+    }
+
+    for archived_did in archive.dids {
+        let value = IndyDidValue {
+            did: archived_did.did.clone(),
+            verkey: archived_did.verkey.base58(),
+        };
+        records.push(
+            Record::builder()
+                .category(RecordCategory::Did)
+                .name(archived_did.did)
+                .value(serde_json::to_string(&value)?)
+                .build()
+                .into(),
+        );
+    }
+
+    for archived_record in archive.records {
+        records.push(Record::try_from(archived_record)?.into());
+    }
+
+    Ok(records)
+}
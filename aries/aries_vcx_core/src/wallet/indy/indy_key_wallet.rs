@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+use indy_api_types::domain::wallet::IndyRecord;
+use public_key::{Key, KeyType};
+use vdrtools::{KeyInfo, Locator};
+
+use super::{indy_tags::IndyTags, KEY_CATEGORY};
+use crate::{
+    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
+    wallet::{
+        base_wallet::{key_data::KeyData, KeyWallet},
+        indy::IndySdkWallet,
+        record_tags::RecordTags,
+    },
+};
+
+#[async_trait]
+impl KeyWallet for IndySdkWallet {
+    async fn create_key(
+        &self,
+        key_type: KeyType,
+        seed: Option<&str>,
+        tags: RecordTags,
+    ) -> VcxCoreResult<KeyData> {
+        if key_type != KeyType::Ed25519 {
+            return Err(AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::InvalidInput,
+                format!("key type not supported by indy wallet: {:?}", key_type),
+            ));
+        }
+
+        let key_info = KeyInfo {
+            seed: seed.map(Into::into),
+            ..Default::default()
+        };
+
+        let verkey = Locator::instance()
+            .crypto_controller
+            .create_key(self.wallet_handle, &key_info)
+            .await?;
+
+        if !tags.is_empty() {
+            self.update_indy_record_tags(KEY_CATEGORY, &verkey, tags.clone())
+                .await?;
+        }
+
+        Ok(KeyData::new(&verkey, &verkey_to_key(&verkey)?, tags))
+    }
+
+    async fn get_key(&self, name: &str) -> VcxCoreResult<KeyData> {
+        let record = self.get_indy_record(KEY_CATEGORY, name).await?;
+
+        record_to_key_data(record)
+    }
+
+    async fn list_keys(&self, key_type: Option<KeyType>) -> VcxCoreResult<Vec<KeyData>> {
+        if key_type.is_some_and(|key_type| key_type != KeyType::Ed25519) {
+            return Ok(vec![]);
+        }
+
+        self.search_indy_records(KEY_CATEGORY, None)
+            .await?
+            .into_iter()
+            .map(record_to_key_data)
+            .collect()
+    }
+
+    async fn update_key_tags(&self, name: &str, new_tags: RecordTags) -> VcxCoreResult<()> {
+        self.update_indy_record_tags(KEY_CATEGORY, name, new_tags)
+            .await
+    }
+
+    async fn delete_key(&self, name: &str) -> VcxCoreResult<()> {
+        self.delete_indy_record(KEY_CATEGORY, name).await
+    }
+}
+
+fn verkey_to_key(verkey: &str) -> VcxCoreResult<Key> {
+    Key::from_base58(verkey, KeyType::Ed25519)
+        .map_err(|err| AriesVcxCoreError::from_msg(AriesVcxCoreErrorKind::WalletError, err))
+}
+
+fn record_to_key_data(record: IndyRecord) -> VcxCoreResult<KeyData> {
+    Ok(KeyData::new(
+        &record.id,
+        &verkey_to_key(&record.id)?,
+        IndyTags::new(record.tags).into_record_tags(),
+    ))
+}
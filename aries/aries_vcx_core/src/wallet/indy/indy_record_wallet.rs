@@ -1,55 +1,41 @@
+use std::collections::VecDeque;
+
 use async_trait::async_trait;
 use indy_api_types::domain::wallet::IndyRecord;
+use serde::Deserialize;
 use vdrtools::Locator;
 
-use super::{indy_tags::IndyTags, WALLET_OPTIONS};
+use super::{COUNT_OPTIONS, SEARCH_OPTIONS};
 use crate::{
-    errors::error::VcxCoreResult,
+    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
+    utils::async_fn_iterator::AsyncFnIterator,
     wallet::{
         base_wallet::{
-            record::Record, record_category::RecordCategory, search_filter::SearchFilter,
+            record::Record,
+            record_category::RecordCategory,
+            record_iterator::{RecordIterator, VecRecordIterator},
+            search_filter::SearchFilter,
+            search_options::SearchOptions,
             RecordWallet,
         },
         indy::IndySdkWallet,
         record_tags::RecordTags,
     },
+    SearchHandle, WalletHandle,
 };
 
+const SEARCH_BATCH_SIZE: usize = 100;
+
 #[async_trait]
 impl RecordWallet for IndySdkWallet {
     async fn add_record(&self, record: Record) -> VcxCoreResult<()> {
-        let tags_map = if record.tags().is_empty() {
-            None
-        } else {
-            Some(IndyTags::from_record_tags(record.tags().clone()).into_inner())
-        };
-
-        Ok(Locator::instance()
-            .non_secret_controller
-            .add_record(
-                self.wallet_handle,
-                record.category().to_string(),
-                record.name().into(),
-                record.value().into(),
-                tags_map,
-            )
-            .await?)
+        self.add_indy_record(record.into()).await
     }
 
     async fn get_record(&self, category: RecordCategory, name: &str) -> VcxCoreResult<Record> {
-        let res = Locator::instance()
-            .non_secret_controller
-            .get_record(
-                self.wallet_handle,
-                category.to_string(),
-                name.into(),
-                WALLET_OPTIONS.into(),
-            )
-            .await?;
+        let indy_record = self.get_indy_record(&category.to_string(), name).await?;
 
-        let indy_record: IndyRecord = serde_json::from_str(&res)?;
-
-        Ok(Record::try_from_indy_record(indy_record)?)
+        Record::try_from_indy_record(indy_record)
     }
 
     async fn update_record_tags(
@@ -58,15 +44,8 @@ impl RecordWallet for IndySdkWallet {
         name: &str,
         new_tags: RecordTags,
     ) -> VcxCoreResult<()> {
-        Ok(Locator::instance()
-            .non_secret_controller
-            .update_record_tags(
-                self.wallet_handle,
-                category.to_string(),
-                name.into(),
-                IndyTags::from_record_tags(new_tags).into_inner(),
-            )
-            .await?)
+        self.update_indy_record_tags(&category.to_string(), name, new_tags)
+            .await
     }
 
     async fn update_record_value(
@@ -87,10 +66,7 @@ impl RecordWallet for IndySdkWallet {
     }
 
     async fn delete_record(&self, category: RecordCategory, name: &str) -> VcxCoreResult<()> {
-        Ok(Locator::instance()
-            .non_secret_controller
-            .delete_record(self.wallet_handle, category.to_string(), name.into())
-            .await?)
+        self.delete_indy_record(&category.to_string(), name).await
     }
 
     async fn search_record(
@@ -100,4 +76,164 @@ impl RecordWallet for IndySdkWallet {
     ) -> VcxCoreResult<Vec<Record>> {
         self.search(category, search_filter).await
     }
+
+    async fn search_record_iter(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+        options: SearchOptions,
+    ) -> VcxCoreResult<RecordIterator> {
+        if options.sort.is_some() {
+            let records = self.search(category, search_filter).await?;
+            return Ok(Box::new(VecRecordIterator::new(options.apply(records))));
+        }
+
+        let search_handle = self
+            .open_search(&category.to_string(), search_filter, SEARCH_OPTIONS)
+            .await?;
+
+        Ok(Box::new(IndyRecordIterator {
+            wallet_handle: self.wallet_handle,
+            search_handle: Some(search_handle),
+            skip: options.offset.unwrap_or_default(),
+            remaining: options.limit.unwrap_or(usize::MAX),
+            buffer: VecDeque::new(),
+        }))
+    }
+
+    async fn count_records(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+    ) -> VcxCoreResult<usize> {
+        let search_handle = self
+            .open_search(&category.to_string(), search_filter, COUNT_OPTIONS)
+            .await?;
+
+        let res = fetch_batch(self.wallet_handle, search_handle, 0).await;
+        close_search(search_handle).await?;
+
+        res?.total_count.ok_or_else(|| {
+            AriesVcxCoreError::from_msg(
+                AriesVcxCoreErrorKind::WalletError,
+                "wallet search did not return a total count",
+            )
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IndySearchBatch {
+    total_count: Option<usize>,
+    records: Option<Vec<IndyRecord>>,
+}
+
+async fn fetch_batch(
+    wallet_handle: WalletHandle,
+    search_handle: SearchHandle,
+    count: usize,
+) -> VcxCoreResult<IndySearchBatch> {
+    let res = Locator::instance()
+        .non_secret_controller
+        .fetch_search_next_records(wallet_handle, search_handle, count)
+        .await?;
+
+    Ok(serde_json::from_str(&res)?)
+}
+
+async fn close_search(search_handle: SearchHandle) -> VcxCoreResult<()> {
+    Ok(Locator::instance()
+        .non_secret_controller
+        .close_search(search_handle)
+        .await?)
+}
+
+struct IndyRecordIterator {
+    wallet_handle: WalletHandle,
+    // `None` once the search is exhausted and closed
+    search_handle: Option<SearchHandle>,
+    skip: usize,
+    remaining: usize,
+    buffer: VecDeque<IndyRecord>,
+}
+
+impl IndyRecordIterator {
+    async fn finish(&mut self) -> VcxCoreResult<()> {
+        self.buffer.clear();
+        match self.search_handle.take() {
+            Some(search_handle) => close_search(search_handle).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn fill_buffer(&mut self) -> VcxCoreResult<()> {
+        let Some(search_handle) = self.search_handle else {
+            return Ok(());
+        };
+
+        let records = fetch_batch(self.wallet_handle, search_handle, SEARCH_BATCH_SIZE)
+            .await?
+            .records
+            .unwrap_or_default();
+
+        if records.is_empty() {
+            return self.finish().await;
+        }
+
+        self.buffer.extend(records);
+        Ok(())
+    }
+}
+
+impl Drop for IndyRecordIterator {
+    // iterators dropped before being exhausted would otherwise leak their search handle
+    fn drop(&mut self) {
+        let Some(search_handle) = self.search_handle.take() else {
+            return;
+        };
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                runtime.spawn(async move {
+                    if let Err(err) = close_search(search_handle).await {
+                        warn!("failed to close wallet search: {err}");
+                    }
+                });
+            }
+            Err(_) => warn!("failed to close wallet search: no async runtime available"),
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncFnIterator for IndyRecordIterator {
+    type Item = VcxCoreResult<Record>;
+
+    async fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining == 0 {
+                return self.finish().await.err().map(Err);
+            }
+
+            if let Some(indy_record) = self.buffer.pop_front() {
+                if self.skip > 0 {
+                    self.skip -= 1;
+                    continue;
+                }
+
+                self.remaining -= 1;
+                return Some(Record::try_from_indy_record(indy_record));
+            }
+
+            if self.search_handle.is_none() {
+                return None;
+            }
+
+            if let Err(err) = self.fill_buffer().await {
+                self.search_handle = None;
+                return Some(Err(err));
+            }
+        }
+    }
 }
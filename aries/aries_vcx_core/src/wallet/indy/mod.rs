@@ -7,15 +7,20 @@ use typed_builder::TypedBuilder;
 use vdrtools::Locator;
 
 use self::indy_tags::IndyTags;
-use super::base_wallet::{
-    record::Record, record_category::RecordCategory, search_filter::SearchFilter, BaseWallet,
+use super::{
+    base_wallet::{
+        record::Record, record_category::RecordCategory, search_filter::SearchFilter, BaseWallet,
+    },
+    record_tags::RecordTags,
 };
 use crate::{
-    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
-    WalletHandle,
+    errors::error::{AriesVcxCoreError, VcxCoreResult},
+    SearchHandle, WalletHandle,
 };
 
+mod indy_backup_wallet;
 mod indy_did_wallet;
+mod indy_key_wallet;
 mod indy_record_wallet;
 mod indy_tags;
 pub mod internal;
@@ -35,32 +40,47 @@ impl IndySdkWallet {
         self.wallet_handle
     }
 
-    #[allow(unreachable_patterns)]
-    async fn search(
+    async fn open_search(
         &self,
-        category: RecordCategory,
+        category: &str,
         search_filter: Option<SearchFilter>,
-    ) -> VcxCoreResult<Vec<Record>> {
-        let json_filter = search_filter
-            .map(|filter| match filter {
-                SearchFilter::JsonFilter(inner) => Ok::<String, AriesVcxCoreError>(inner),
-                _ => Err(AriesVcxCoreError::from_msg(
-                    AriesVcxCoreErrorKind::InvalidInput,
-                    "filter type not supported",
-                )),
-            })
-            .transpose()?;
-
-        let query_json = json_filter.unwrap_or("{}".into());
-
-        let search_handle = Locator::instance()
+        options: &str,
+    ) -> VcxCoreResult<SearchHandle> {
+        let query_json = search_filter
+            .map(|filter| filter.to_json())
+            .transpose()?
+            .unwrap_or("{}".into());
+
+        Ok(Locator::instance()
             .non_secret_controller
             .open_search(
                 self.wallet_handle,
-                category.to_string(),
+                category.into(),
                 query_json,
-                SEARCH_OPTIONS.into(),
+                options.into(),
             )
+            .await?)
+    }
+
+    async fn search(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+    ) -> VcxCoreResult<Vec<Record>> {
+        self.search_indy_records(&category.to_string(), search_filter)
+            .await?
+            .into_iter()
+            .map(Record::try_from_indy_record)
+            .collect()
+    }
+
+    async fn search_indy_records(
+        &self,
+        category: &str,
+        search_filter: Option<SearchFilter>,
+    ) -> VcxCoreResult<Vec<IndyRecord>> {
+        let search_handle = self
+            .open_search(category, search_filter, SEARCH_OPTIONS)
             .await?;
 
         let next = || async {
@@ -81,11 +101,68 @@ impl IndySdkWallet {
 
         let mut records = Vec::new();
         while let Some(indy_record) = next().await? {
-            records.push(Record::try_from_indy_record(indy_record)?);
+            records.push(indy_record);
         }
 
         Ok(records)
     }
+
+    async fn add_indy_record(&self, record: IndyRecord) -> VcxCoreResult<()> {
+        let tags_map = if record.tags.is_empty() {
+            None
+        } else {
+            Some(record.tags)
+        };
+
+        Ok(Locator::instance()
+            .non_secret_controller
+            .add_record(
+                self.wallet_handle,
+                record.type_,
+                record.id,
+                record.value,
+                tags_map,
+            )
+            .await?)
+    }
+
+    async fn get_indy_record(&self, category: &str, name: &str) -> VcxCoreResult<IndyRecord> {
+        let res = Locator::instance()
+            .non_secret_controller
+            .get_record(
+                self.wallet_handle,
+                category.into(),
+                name.into(),
+                WALLET_OPTIONS.into(),
+            )
+            .await?;
+
+        Ok(serde_json::from_str(&res)?)
+    }
+
+    async fn update_indy_record_tags(
+        &self,
+        category: &str,
+        name: &str,
+        new_tags: RecordTags,
+    ) -> VcxCoreResult<()> {
+        Ok(Locator::instance()
+            .non_secret_controller
+            .update_record_tags(
+                self.wallet_handle,
+                category.into(),
+                name.into(),
+                IndyTags::from_record_tags(new_tags).into_inner(),
+            )
+            .await?)
+    }
+
+    async fn delete_indy_record(&self, category: &str, name: &str) -> VcxCoreResult<()> {
+        Ok(Locator::instance()
+            .non_secret_controller
+            .delete_record(self.wallet_handle, category.into(), name.into())
+            .await?)
+    }
 }
 
 #[derive(Clone, Debug, TypedBuilder, Serialize, Deserialize)]
@@ -192,6 +269,12 @@ const WALLET_OPTIONS: &str =
 
 const SEARCH_OPTIONS: &str = r#"{"retrieveType": true, "retrieveValue": true, "retrieveTags": true, "retrieveRecords": true}"#;
 
+/// Category of the records in which vdrtools keeps the signing keys of `create_key`. They are
+/// only accessed by the key and backup wallets, never through `RecordWallet`.
+const KEY_CATEGORY: &str = "Indy::Key";
+
+const COUNT_OPTIONS: &str = r#"{"retrieveRecords": false, "retrieveTotalCount": true}"#;
+
 impl BaseWallet for IndySdkWallet {}
 
 #[cfg(test)]
@@ -103,6 +103,10 @@ impl RecordTags {
         self.inner.is_empty()
     }
 
+    pub fn iter(&self) -> std::slice::Iter<'_, RecordTag> {
+        self.inner.iter()
+    }
+
     pub fn into_inner(self) -> Vec<RecordTag> {
         self.inner
     }
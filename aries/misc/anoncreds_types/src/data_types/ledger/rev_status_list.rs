@@ -2,7 +2,10 @@ use crate::{
     cl::{Accumulator, RevocationRegistry as CryptoRevocationRegistry},
     data_types::{
         identifiers::{issuer_id::IssuerId, rev_reg_def_id::RevocationRegistryDefinitionId},
-        ledger::rev_reg::RevocationRegistry,
+        ledger::{
+            rev_reg::RevocationRegistry,
+            rev_reg_delta::{RevocationRegistryDelta, RevocationRegistryDeltaValue},
+        },
     },
     Result,
 };
@@ -50,6 +53,25 @@ impl RevocationStatusList {
         self.accum
     }
 
+    pub const fn rev_reg_def_id(&self) -> Option<&RevocationRegistryDefinitionId> {
+        self.rev_reg_def_id.as_ref()
+    }
+
+    pub const fn issuer_id(&self) -> &IssuerId {
+        &self.issuer_id
+    }
+
+    pub const fn timestamp(&self) -> Option<u64> {
+        self.timestamp
+    }
+
+    pub fn is_revoked(&self, idx: u32) -> bool {
+        self.revocation_list
+            .get(idx as usize)
+            .map(|bit| *bit)
+            .unwrap_or_default()
+    }
+
     pub fn set_registry(&mut self, registry: CryptoRevocationRegistry) -> Result<()> {
         self.accum = Some(registry.accum);
         Ok(())
@@ -72,6 +94,68 @@ impl RevocationStatusList {
             timestamp,
         })
     }
+
+    /// Builds the status list of a registry issuing by default from the accumulated delta of
+    /// its entries, as returned by legacy Indy ledgers.
+    pub fn from_delta(
+        rev_reg_def_id: &RevocationRegistryDefinitionId,
+        issuer_id: IssuerId,
+        max_cred_num: u32,
+        delta: &RevocationRegistryDeltaValue,
+        timestamp: Option<u64>,
+    ) -> Result<Self> {
+        let mut revocation_list = bitvec::bitvec![0; max_cred_num as usize];
+        let updates = delta
+            .issued
+            .iter()
+            .map(|idx| (idx, false))
+            .chain(delta.revoked.iter().map(|idx| (idx, true)));
+        for (idx, revoked) in updates {
+            let mut bit = revocation_list.get_mut(*idx as usize).ok_or_else(|| {
+                invalid!("Revocation index {idx} exceeds the registry size {max_cred_num}")
+            })?;
+            *bit = revoked;
+        }
+
+        Ok(Self {
+            rev_reg_def_id: Some(rev_reg_def_id.clone()),
+            issuer_id,
+            revocation_list,
+            accum: Some(delta.accum),
+            timestamp,
+        })
+    }
+
+    /// Returns the delta of the registry entry updating `prev` to this list, or the whole state
+    /// if there is no previous list.
+    pub fn to_delta(&self, prev: Option<&Self>) -> Result<RevocationRegistryDelta> {
+        let accum = self
+            .accum
+            .ok_or_else(|| invalid!("Revocation status list has no accumulator"))?;
+        let was_revoked = |idx: usize| {
+            prev.and_then(|prev| prev.revocation_list.get(idx).map(|bit| *bit))
+                .unwrap_or_default()
+        };
+
+        let mut issued = Vec::new();
+        let mut revoked = Vec::new();
+        for (idx, is_revoked) in self.revocation_list.iter().enumerate() {
+            match (*is_revoked, was_revoked(idx)) {
+                (true, false) => revoked.push(idx as u32),
+                (false, true) => issued.push(idx as u32),
+                _ => {}
+            }
+        }
+
+        Ok(RevocationRegistryDelta {
+            value: RevocationRegistryDeltaValue {
+                prev_accum: prev.and_then(|prev| prev.accum),
+                accum,
+                issued,
+                revoked,
+            },
+        })
+    }
 }
 
 pub mod serde_revocation_list {
@@ -175,4 +259,44 @@ mod rev_reg_tests {
         let ser2 = serde_json::to_string(&des).unwrap();
         assert_eq!(ser, ser2)
     }
+
+    #[test]
+    fn test_revocation_list_delta_roundtrip() {
+        let prev = serde_json::from_str::<RevocationStatusList>(REVOCATION_LIST).unwrap();
+        let prev = RevocationStatusList {
+            revocation_list: bitvec![0, 1, 0, 0],
+            ..prev
+        };
+        let next = RevocationStatusList {
+            revocation_list: bitvec![1, 0, 0, 1],
+            ..prev.clone()
+        };
+
+        let delta = next.to_delta(Some(&prev)).unwrap();
+        assert_eq!(vec![1], delta.value.issued);
+        assert_eq!(vec![0, 3], delta.value.revoked);
+        assert!(delta.value.prev_accum.is_some());
+
+        let full_delta = next.to_delta(None).unwrap();
+        let res = RevocationStatusList::from_delta(
+            next.rev_reg_def_id().unwrap(),
+            next.issuer_id().clone(),
+            4,
+            &full_delta.value,
+            Some(1234),
+        )
+        .unwrap();
+        assert_eq!(next.state(), res.state());
+        assert!(res.is_revoked(3));
+        assert!(!res.is_revoked(2));
+
+        let err = RevocationStatusList::from_delta(
+            next.rev_reg_def_id().unwrap(),
+            next.issuer_id().clone(),
+            2,
+            &full_delta.value,
+            None,
+        );
+        assert!(err.is_err());
+    }
 }
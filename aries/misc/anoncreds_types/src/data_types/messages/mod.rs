@@ -8,3 +8,4 @@ pub mod nonce;
 pub mod pres_request;
 pub mod presentation;
 pub mod revocation_state;
+pub mod w3c;
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::data_types::identifiers::{cred_def_id::CredentialDefinitionId, issuer_id::IssuerId};
+
+pub const W3C_CREDENTIALS_CONTEXT_V1: &str = "https://www.w3.org/2018/credentials/v1";
+pub const W3C_CREDENTIALS_CONTEXT_V2: &str = "https://www.w3.org/ns/credentials/v2";
+pub const W3C_ANONCREDS_CONTEXT: &str =
+    "https://raw.githubusercontent.com/hyperledger/anoncreds-spec/main/data/anoncreds-w3c-context.json";
+
+pub const W3C_CREDENTIAL_TYPE: &str = "VerifiableCredential";
+pub const W3C_ANONCREDS_CREDENTIAL_TYPE: &str = "AnonCredsCredential";
+pub const W3C_PRESENTATION_TYPE: &str = "VerifiablePresentation";
+pub const W3C_ANONCREDS_PRESENTATION_TYPE: &str = "AnonCredsPresentation";
+
+pub const DATA_INTEGRITY_PROOF_TYPE: &str = "DataIntegrityProof";
+pub const ANONCREDS_CRYPTOSUITE: &str = "anoncreds-2023";
+
+/// AnonCreds credential encoded according to the W3C Verifiable Credentials Data Model.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<Value>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub issuer: IssuerId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuance_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<String>,
+    pub credential_subject: CredentialSubject,
+    pub proof: DataIntegrityProof,
+}
+
+impl W3CCredential {
+    /// Id of the credential definition the credential was issued with, which AnonCreds uses as
+    /// verification method of the proof.
+    pub fn cred_def_id(&self) -> CredentialDefinitionId {
+        CredentialDefinitionId::new_unchecked(self.proof.verification_method.as_str())
+    }
+
+    pub fn get_attribute(&self, name: &str) -> Option<&Value> {
+        self.credential_subject.attributes.get(name)
+    }
+
+    pub fn is_anoncreds(&self) -> bool {
+        self.type_
+            .iter()
+            .any(|t| t == W3C_ANONCREDS_CREDENTIAL_TYPE)
+            && self.proof.cryptosuite == ANONCREDS_CRYPTOSUITE
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct CredentialSubject {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Raw attribute values, or the predicates they satisfy when presented.
+    #[serde(flatten)]
+    pub attributes: HashMap<String, Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataIntegrityProof {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub cryptosuite: String,
+    pub proof_purpose: String,
+    pub verification_method: String,
+    pub proof_value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<String>,
+}
+
+/// AnonCreds presentation encoded according to the W3C Verifiable Credentials Data Model.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CPresentation {
+    #[serde(rename = "@context")]
+    pub context: Vec<Value>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub verifiable_credential: Vec<W3CCredential>,
+    pub proof: DataIntegrityProof,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_w3c_credential_serde() {
+        let json = json!({
+            "@context": [W3C_CREDENTIALS_CONTEXT_V1, W3C_ANONCREDS_CONTEXT],
+            "type": [W3C_CREDENTIAL_TYPE, W3C_ANONCREDS_CREDENTIAL_TYPE],
+            "issuer": "did:sov:V4SGRU86Z58d6TV7PBUe6f",
+            "issuanceDate": "2024-01-01T00:00:00Z",
+            "credentialSubject": {
+                "name": "Alice",
+                "age": "28"
+            },
+            "proof": {
+                "type": DATA_INTEGRITY_PROOF_TYPE,
+                "cryptosuite": ANONCREDS_CRYPTOSUITE,
+                "proofPurpose": "assertionMethod",
+                "verificationMethod": "V4SGRU86Z58d6TV7PBUe6f:3:CL:1:tag",
+                "proofValue": "ukgGEqXNjaGVtYV9pZNlA"
+            }
+        });
+
+        let credential: W3CCredential = serde_json::from_value(json.clone()).unwrap();
+
+        assert!(credential.is_anoncreds());
+        assert_eq!(
+            "V4SGRU86Z58d6TV7PBUe6f:3:CL:1:tag",
+            credential.cred_def_id().0
+        );
+        assert_eq!(Some(&json!("28")), credential.get_attribute("age"));
+        assert_eq!(json, serde_json::to_value(credential).unwrap());
+    }
+}
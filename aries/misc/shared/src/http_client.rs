@@ -28,6 +28,36 @@ pub async fn post_message(body_content: Vec<u8>, url: Url) -> HttpResult<Vec<u8>
     process_response(response).await
 }
 
+pub async fn get_bytes(url: Url) -> HttpResult<Vec<u8>> {
+    debug!("get_bytes >> http client sending request GET {}", &url);
+
+    let response = HTTP_CLIENT
+        .get(url)
+        .header(USER_AGENT, "reqwest")
+        .send()
+        .await
+        .map_err(|err| {
+            HttpError::from_msg(format!("HTTP Client could not connect, err: {}", err))
+        })?;
+    process_binary_response("GET", response).await
+}
+
+pub async fn put_bytes(body_content: Vec<u8>, url: Url) -> HttpResult<Vec<u8>> {
+    debug!("put_bytes >> http client sending request PUT {}", &url);
+
+    let response = HTTP_CLIENT
+        .put(url)
+        .body(body_content)
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .header(USER_AGENT, "reqwest")
+        .send()
+        .await
+        .map_err(|err| {
+            HttpError::from_msg(format!("HTTP Client could not connect, err: {}", err))
+        })?;
+    process_binary_response("PUT", response).await
+}
+
 async fn send_post_request(url: &Url, body_content: Vec<u8>) -> HttpResult<Response> {
     HTTP_CLIENT
         .post(url.clone())
@@ -60,3 +90,23 @@ async fn process_response(response: Response) -> HttpResult<Vec<u8>> {
         ))),
     }
 }
+
+async fn process_binary_response(method: &str, response: Response) -> HttpResult<Vec<u8>> {
+    let response_status = response.status();
+    let payload = response.bytes().await.map_err(|err| {
+        HttpError::from_msg(format!(
+            "{method} failed because response body could not be read, HTTP status: {}, error: {:?}",
+            response_status, err
+        ))
+    })?;
+
+    if response_status.is_success() {
+        Ok(payload.to_vec())
+    } else {
+        Err(HttpError::from_msg(format!(
+            "{method} failed due to non-success HTTP status: {}, response body: {}",
+            response_status,
+            String::from_utf8_lossy(&payload)
+        )))
+    }
+}
@@ -2,8 +2,10 @@ use aries_vcx_core::{
     errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind, VcxCoreResult},
     wallet::{
         base_wallet::{
-            did_data::DidData, record::Record, record_category::RecordCategory,
-            search_filter::SearchFilter, BaseWallet, DidWallet, RecordWallet,
+            did_data::DidData, key_data::KeyData, record::Record, record_category::RecordCategory,
+            record_iterator::RecordIterator, search_filter::SearchFilter,
+            search_options::SearchOptions, wallet_archive::WalletArchive, BackupWallet, BaseWallet,
+            DidWallet, KeyWallet, RecordWallet,
         },
         record_tags::RecordTags,
         structs_io::UnpackMessageOutput,
@@ -67,6 +69,29 @@ impl RecordWallet for MockWallet {
             "unimplemented mock method: search_record",
         ))
     }
+
+    async fn search_record_iter(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+        options: SearchOptions,
+    ) -> VcxCoreResult<RecordIterator> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: search_record_iter",
+        ))
+    }
+
+    async fn count_records(
+        &self,
+        category: RecordCategory,
+        search_filter: Option<SearchFilter>,
+    ) -> VcxCoreResult<usize> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: count_records",
+        ))
+    }
 }
 
 #[async_trait]
@@ -124,3 +149,62 @@ impl DidWallet for MockWallet {
         })
     }
 }
+
+#[async_trait]
+#[allow(unused_variables)]
+impl KeyWallet for MockWallet {
+    async fn create_key(
+        &self,
+        key_type: KeyType,
+        seed: Option<&str>,
+        tags: RecordTags,
+    ) -> VcxCoreResult<KeyData> {
+        Ok(KeyData::new(
+            VERKEY,
+            &Key::new(VERKEY.into(), KeyType::Ed25519).unwrap(),
+            tags,
+        ))
+    }
+
+    async fn get_key(&self, name: &str) -> VcxCoreResult<KeyData> {
+        Ok(KeyData::new(
+            VERKEY,
+            &Key::new(VERKEY.into(), KeyType::Ed25519).unwrap(),
+            RecordTags::default(),
+        ))
+    }
+
+    async fn list_keys(&self, key_type: Option<KeyType>) -> VcxCoreResult<Vec<KeyData>> {
+        Ok(vec![KeyData::new(
+            VERKEY,
+            &Key::new(VERKEY.into(), KeyType::Ed25519).unwrap(),
+            RecordTags::default(),
+        )])
+    }
+
+    async fn update_key_tags(&self, name: &str, new_tags: RecordTags) -> VcxCoreResult<()> {
+        Ok(())
+    }
+
+    async fn delete_key(&self, name: &str) -> VcxCoreResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+#[allow(unused_variables)]
+impl BackupWallet for MockWallet {
+    async fn export_archive(&self) -> VcxCoreResult<WalletArchive> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: export_archive",
+        ))
+    }
+
+    async fn import_archive(&self, archive: WalletArchive) -> VcxCoreResult<()> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: import_archive",
+        ))
+    }
+}
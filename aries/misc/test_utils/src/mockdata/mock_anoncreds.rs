@@ -10,6 +10,7 @@ use anoncreds_types::data_types::{
         rev_reg::RevocationRegistry,
         rev_reg_def::RevocationRegistryDefinition,
         rev_reg_delta::RevocationRegistryDelta,
+        rev_status_list::RevocationStatusList,
         schema::{AttributeNames, Schema},
     },
     messages::{
@@ -22,6 +23,7 @@ use anoncreds_types::data_types::{
         pres_request::PresentationRequest,
         presentation::{Presentation, RequestedCredentials},
         revocation_state::CredentialRevocationState,
+        w3c::{W3CCredential, W3CPresentation},
     },
 };
 use aries_vcx_core::{
@@ -105,6 +107,39 @@ impl BaseAnonCreds for MockAnoncreds {
         Ok(serde_json::from_str(LIBINDY_CRED_OFFER)?)
     }
 
+    async fn issuer_create_w3c_credential(
+        &self,
+        __wallet: &impl BaseWallet,
+        _cred_offer_json: CredentialOffer,
+        _cred_req_json: CredentialRequest,
+        _cred_values_json: CredentialValues,
+        _rev_reg_id: Option<&RevocationRegistryDefinitionId>,
+        _tails_dir: Option<&Path>,
+    ) -> VcxCoreResult<(W3CCredential, Option<u32>)> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: issuer_create_w3c_credential",
+        ))
+    }
+
+    async fn credential_to_w3c(
+        &self,
+        _credential: Credential,
+        _cred_def_json: CredentialDefinition,
+    ) -> VcxCoreResult<W3CCredential> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: credential_to_w3c",
+        ))
+    }
+
+    async fn credential_from_w3c(&self, _credential: W3CCredential) -> VcxCoreResult<Credential> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: credential_from_w3c",
+        ))
+    }
+
     async fn issuer_create_credential(
         &self,
         __wallet: &impl BaseWallet,
@@ -130,6 +165,38 @@ impl BaseAnonCreds for MockAnoncreds {
         Ok(serde_json::from_str(PROOF_JSON).unwrap())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    async fn prover_create_w3c_presentation(
+        &self,
+        __wallet: &impl BaseWallet,
+        _proof_req_json: PresentationRequest,
+        _requested_credentials_json: RequestedCredentials,
+        _link_secret_id: &LinkSecretId,
+        _schemas_json: SchemasMap,
+        _credential_defs_json: CredentialDefinitionsMap,
+        _revoc_states_json: Option<RevocationStatesMap>,
+    ) -> VcxCoreResult<W3CPresentation> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: prover_create_w3c_presentation",
+        ))
+    }
+
+    async fn verifier_verify_w3c_presentation(
+        &self,
+        _proof_request_json: PresentationRequest,
+        _presentation_json: W3CPresentation,
+        _schemas_json: SchemasMap,
+        _credential_defs_json: CredentialDefinitionsMap,
+        _rev_reg_defs_json: Option<RevocationRegistryDefinitionsMap>,
+        _rev_regs_json: Option<RevocationRegistriesMap>,
+    ) -> VcxCoreResult<bool> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: verifier_verify_w3c_presentation",
+        ))
+    }
+
     async fn prover_get_credential(
         &self,
         __wallet: &impl BaseWallet,
@@ -191,6 +258,16 @@ impl BaseAnonCreds for MockAnoncreds {
         Ok(serde_json::from_str(REV_STATE_JSON)?)
     }
 
+    async fn create_revocation_state_from_status_list(
+        &self,
+        _tails_dir: &Path,
+        _rev_reg_def_json: RevocationRegistryDefinition,
+        _rev_status_list: RevocationStatusList,
+        _cred_rev_id: u32,
+    ) -> VcxCoreResult<CredentialRevocationState> {
+        Ok(serde_json::from_str(REV_STATE_JSON)?)
+    }
+
     async fn prover_store_credential(
         &self,
         _wallet: &impl BaseWallet,
@@ -202,6 +279,20 @@ impl BaseAnonCreds for MockAnoncreds {
         Ok("cred_id".to_string())
     }
 
+    async fn prover_store_w3c_credential(
+        &self,
+        __wallet: &impl BaseWallet,
+        _cred_req_metadata_json: CredentialRequestMetadata,
+        _cred_json: W3CCredential,
+        _cred_def_json: CredentialDefinition,
+        _rev_reg_def_json: Option<RevocationRegistryDefinition>,
+    ) -> VcxCoreResult<CredentialId> {
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: prover_store_w3c_credential",
+        ))
+    }
+
     async fn prover_delete_credential(
         &self,
         _wallet: &impl BaseWallet,
@@ -246,6 +337,16 @@ impl BaseAnonCreds for MockAnoncreds {
         Ok(())
     }
 
+    async fn issuer_update_revocation_status_list(
+        &self,
+        _wallet: &impl BaseWallet,
+        _rev_reg_id: &RevocationRegistryDefinitionId,
+        rev_status_list: RevocationStatusList,
+        _revoked: &[u32],
+    ) -> VcxCoreResult<RevocationStatusList> {
+        Ok(rev_status_list)
+    }
+
     async fn get_rev_reg_delta(
         &self,
         _wallet: &impl BaseWallet,
@@ -262,6 +363,14 @@ impl BaseAnonCreds for MockAnoncreds {
         Ok(())
     }
 
+    async fn issuer_get_rev_reg_issued_count(
+        &self,
+        _wallet: &impl BaseWallet,
+        _rev_reg_id: &RevocationRegistryDefinitionId,
+    ) -> VcxCoreResult<u32> {
+        Ok(0)
+    }
+
     async fn generate_nonce(&self) -> VcxCoreResult<Nonce> {
         Ok(Nonce::from_dec(LARGE_NONCE.to_string()).unwrap())
     }
@@ -48,6 +48,29 @@ impl IndyLedgerRead for MockLedger {
         Ok(r#"{"rc":"success"}"#.to_string())
     }
 
+    async fn get_attr_version(
+        &self,
+        target_did: &Did,
+        attr_name: &str,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<String> {
+        Ok(r#"{"rc":"success"}"#.to_string())
+    }
+
+    async fn get_nym_version(
+        &self,
+        did: &Did,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> VcxCoreResult<String> {
+        // not needed yet
+        Err(AriesVcxCoreError::from_msg(
+            AriesVcxCoreErrorKind::UnimplementedFeature,
+            "unimplemented mock method: get_nym_version",
+        ))
+    }
+
     async fn get_ledger_txn(
         &self,
         seq_no: i32,
@@ -111,6 +134,10 @@ impl IndyLedgerWrite for MockLedger {
     ) -> VcxCoreResult<String> {
         Ok(r#"{"rc":"success"}"#.to_string())
     }
+
+    async fn deactivate_nym(&self, wallet: &impl BaseWallet, did: &Did) -> VcxCoreResult<String> {
+        Ok(r#"{"rc":"success"}"#.to_string())
+    }
 }
 
 #[allow(unused)]
@@ -162,7 +189,7 @@ impl AnoncredsLedgerRead for MockLedger {
 impl AnoncredsLedgerWrite for MockLedger {
     async fn publish_schema(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         schema_json: Schema,
         submitter_did: &Did,
         endorser_did: Option<&Did>,
@@ -172,7 +199,7 @@ impl AnoncredsLedgerWrite for MockLedger {
 
     async fn publish_cred_def(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         cred_def_json: CredentialDefinition,
         submitter_did: &Did,
     ) -> VcxCoreResult<()> {
@@ -181,7 +208,7 @@ impl AnoncredsLedgerWrite for MockLedger {
 
     async fn publish_rev_reg_def(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         rev_reg_def: RevocationRegistryDefinition,
         submitter_did: &Did,
     ) -> VcxCoreResult<()> {
@@ -190,7 +217,7 @@ impl AnoncredsLedgerWrite for MockLedger {
 
     async fn publish_rev_reg_delta(
         &self,
-        wallet: &impl BaseWallet,
+        wallet: &(impl BaseWallet + ?Sized),
         rev_reg_id: &RevocationRegistryDefinitionId,
         rev_reg_entry_json: RevocationRegistryDelta,
         submitter_did: &Did,
@@ -1,5 +1,6 @@
 pub mod credx2anoncreds;
 pub mod error;
+pub mod vdrtools2askar;
 pub mod vdrtools2credx;
 
 use std::fmt::Display;
@@ -0,0 +1,412 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    str::FromStr,
+};
+
+use aries_vcx_core::{
+    errors::error::{AriesVcxCoreError, AriesVcxCoreErrorKind},
+    wallet::{
+        askar::AskarWallet,
+        base_wallet::{
+            record_category::RecordCategory,
+            wallet_archive::{ArchivedRecord, WalletArchive},
+            BackupWallet, DidWallet, KeyWallet, RecordWallet,
+        },
+        indy::{
+            internal::{close_search_wallet, fetch_next_records_wallet, open_search_wallet},
+            IndySdkWallet,
+        },
+        record_tags::RecordTag,
+    },
+};
+use log::{info, trace};
+use serde_json::Value;
+use vdrtools::types::domain::wallet::IndyRecord;
+
+use crate::{
+    error::MigrationResult,
+    vdrtools2credx::{
+        migrate_any_record, INDY_CRED, INDY_CRED_DEF, INDY_CRED_DEF_CR_PROOF, INDY_CRED_DEF_PRIV,
+        INDY_MASTER_SECRET, INDY_REV_REG, INDY_REV_REG_DEF, INDY_REV_REG_DEF_PRIV,
+        INDY_REV_REG_DELTA, INDY_REV_REG_INFO, INDY_SCHEMA, INDY_SCHEMA_ID,
+    },
+};
+
+/// Record types written by the legacy vdrtools anoncreds implementation,
+/// which get converted to their [`RecordCategory`] counterparts.
+const LEGACY_RECORD_TYPES: [&str; 12] = [
+    INDY_MASTER_SECRET,
+    INDY_CRED,
+    INDY_CRED_DEF,
+    INDY_CRED_DEF_PRIV,
+    INDY_CRED_DEF_CR_PROOF,
+    INDY_SCHEMA,
+    INDY_SCHEMA_ID,
+    INDY_REV_REG,
+    INDY_REV_REG_DELTA,
+    INDY_REV_REG_INFO,
+    INDY_REV_REG_DEF,
+    INDY_REV_REG_DEF_PRIV,
+];
+
+const SEARCH_OPTIONS: &str = r#"{"retrieveType": true, "retrieveValue": true, "retrieveTags": true, "retrieveRecords": true}"#;
+const SEARCH_BATCH_SIZE: usize = 50;
+
+#[derive(Clone, Copy, Debug)]
+pub struct MigrationOptions {
+    /// Only compute the [`MigrationReport`], without writing anything to the destination wallet.
+    pub dry_run: bool,
+    /// Number of items written to the destination wallet in a single transaction.
+    pub batch_size: usize,
+}
+
+impl Default for MigrationOptions {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            batch_size: 100,
+        }
+    }
+}
+
+/// Number of items migrated (or to be migrated, on a dry run) and of items skipped
+/// because they were already present in the destination wallet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MigrationCount {
+    pub migrated: usize,
+    pub skipped: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MigrationReport {
+    pub dry_run: bool,
+    pub dids: MigrationCount,
+    pub keys: MigrationCount,
+    /// Counts per destination [`RecordCategory`].
+    pub records: BTreeMap<String, MigrationCount>,
+}
+
+/// Migrates DIDs, keys and anoncreds records from a vdrtools wallet into an [`AskarWallet`].
+///
+/// Legacy vdrtools anoncreds records are converted the same way as in
+/// [`crate::vdrtools2credx`]. Items already present in the destination wallet are skipped,
+/// so an interrupted migration can be resumed by running it again. Items are written
+/// in batches of [`MigrationOptions::batch_size`], each batch in its own transaction.
+pub async fn migrate_wallet_to_askar(
+    src_wallet: &IndySdkWallet,
+    dest_wallet: &AskarWallet,
+    options: MigrationOptions,
+) -> MigrationResult<MigrationReport> {
+    info!(
+        "Starting migration of vdrtools wallet with handle {:?} to askar wallet, dry run: {}",
+        src_wallet.get_wallet_handle(),
+        options.dry_run
+    );
+
+    let mut archive = src_wallet.export_archive().await?;
+    archive
+        .records
+        .extend(fetch_legacy_records(src_wallet).await?);
+
+    let mut report = MigrationReport {
+        dry_run: options.dry_run,
+        ..Default::default()
+    };
+
+    let existing_keys: HashSet<String> = dest_wallet
+        .list_keys(None)
+        .await?
+        .into_iter()
+        .map(|key_data| key_data.key().base58())
+        .collect();
+
+    let mut pending = WalletArchive::default();
+
+    for archived_key in archive.keys {
+        if existing_keys.contains(&archived_key.key.base58()) {
+            report.keys.skipped += 1;
+        } else {
+            report.keys.migrated += 1;
+            pending.keys.push(archived_key);
+        }
+    }
+
+    for archived_did in archive.dids {
+        if exists(dest_wallet.key_for_did(&archived_did.did).await)? {
+            report.dids.skipped += 1;
+        } else {
+            report.dids.migrated += 1;
+            pending.dids.push(archived_did);
+        }
+    }
+
+    let mut seen_records = HashSet::new();
+    for archived_record in archive.records {
+        if !seen_records.insert((
+            archived_record.category.clone(),
+            archived_record.name.clone(),
+        )) {
+            continue;
+        }
+
+        let category = RecordCategory::from_str(&archived_record.category)?;
+        let count = report
+            .records
+            .entry(archived_record.category.clone())
+            .or_default();
+        if exists(
+            dest_wallet
+                .get_record(category, &archived_record.name)
+                .await,
+        )? {
+            count.skipped += 1;
+        } else {
+            count.migrated += 1;
+            pending.records.push(archived_record);
+        }
+    }
+
+    if !options.dry_run {
+        import_in_batches(dest_wallet, pending, options.batch_size.max(1)).await?;
+    }
+
+    info!("Completed migration of vdrtools wallet to askar wallet: {report:?}");
+
+    Ok(report)
+}
+
+async fn import_in_batches(
+    dest_wallet: &AskarWallet,
+    pending: WalletArchive,
+    batch_size: usize,
+) -> MigrationResult<()> {
+    // Keys go first, so that a DID is never stored without its key.
+    for keys in pending.keys.chunks(batch_size) {
+        dest_wallet
+            .import_archive(WalletArchive {
+                keys: keys.to_vec(),
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    for dids in pending.dids.chunks(batch_size) {
+        dest_wallet
+            .import_archive(WalletArchive {
+                dids: dids.to_vec(),
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    for records in pending.records.chunks(batch_size) {
+        trace!("Importing batch of {} records", records.len());
+        dest_wallet
+            .import_archive(WalletArchive {
+                records: records.to_vec(),
+                ..Default::default()
+            })
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn fetch_legacy_records(src_wallet: &IndySdkWallet) -> MigrationResult<Vec<ArchivedRecord>> {
+    let mut records = Vec::new();
+
+    for record_type in LEGACY_RECORD_TYPES {
+        for indy_record in fetch_indy_records(src_wallet, record_type).await? {
+            if let Some(converted) = migrate_any_record(indy_record)? {
+                records.push(ArchivedRecord {
+                    category: converted.type_,
+                    name: converted.id,
+                    value: converted.value,
+                    tags: converted
+                        .tags
+                        .into_iter()
+                        .map(RecordTag::from_pair)
+                        .collect(),
+                });
+            }
+        }
+    }
+
+    Ok(records)
+}
+
+async fn fetch_indy_records(
+    src_wallet: &IndySdkWallet,
+    record_type: &str,
+) -> MigrationResult<Vec<IndyRecord>> {
+    let wallet_handle = src_wallet.get_wallet_handle();
+    let search_handle = open_search_wallet(wallet_handle, record_type, "{}", SEARCH_OPTIONS).await?;
+
+    let mut records = Vec::new();
+    loop {
+        let res = fetch_next_records_wallet(wallet_handle, search_handle, SEARCH_BATCH_SIZE).await;
+        let res: Value = match res {
+            Ok(res) => serde_json::from_str(&res)?,
+            Err(err) => {
+                close_search_wallet(search_handle).await?;
+                return Err(err.into());
+            }
+        };
+
+        match res.get("records").and_then(Value::as_array) {
+            Some(batch) if !batch.is_empty() => {
+                for item in batch {
+                    records.push(serde_json::from_value(item.clone())?);
+                }
+            }
+            _ => break,
+        }
+    }
+
+    close_search_wallet(search_handle).await?;
+
+    Ok(records)
+}
+
+fn exists<T>(res: Result<T, AriesVcxCoreError>) -> MigrationResult<bool> {
+    match res {
+        Ok(_) => Ok(true),
+        Err(err) if err.kind() == AriesVcxCoreErrorKind::WalletRecordNotFound => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aries_askar::StoreKeyMethod;
+    use aries_vcx_core::wallet::{
+        askar::AskarWallet,
+        base_wallet::{record_category::RecordCategory, DidWallet, RecordWallet},
+        indy::{wallet::create_and_open_wallet, IndySdkWallet, WalletConfig},
+    };
+    use vdrtools::Locator;
+
+    use super::*;
+
+    async fn setup_wallets() -> (IndySdkWallet, AskarWallet) {
+        let config_wallet = WalletConfig {
+            wallet_name: format!("wallet_{}", uuid::Uuid::new_v4()),
+            wallet_key: "8dvfYSt5d1taSd6yJdpjq4emkwsPDDLYxkNFysFD2cZY".into(),
+            wallet_key_derivation: "RAW".into(),
+            wallet_type: None,
+            storage_config: None,
+            storage_credentials: None,
+            rekey: None,
+            rekey_derivation_method: None,
+        };
+        let wallet_handle = create_and_open_wallet(&config_wallet).await.unwrap();
+
+        let askar_wallet = AskarWallet::create(
+            "sqlite://:memory:",
+            StoreKeyMethod::Unprotected,
+            None.into(),
+            true,
+            &uuid::Uuid::new_v4().to_string(),
+        )
+        .await
+        .unwrap();
+
+        (IndySdkWallet::new(wallet_handle), askar_wallet)
+    }
+
+    #[tokio::test]
+    async fn test_migration_to_askar() {
+        let (src_wallet, dest_wallet) = setup_wallets().await;
+
+        let did_data = src_wallet.create_and_store_my_did(None, None).await.unwrap();
+        Locator::instance()
+            .non_secret_controller
+            .add_record(
+                src_wallet.get_wallet_handle(),
+                INDY_SCHEMA_ID.to_owned(),
+                "schema_id".to_owned(),
+                "NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_owned(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let report = migrate_wallet_to_askar(&src_wallet, &dest_wallet, MigrationOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(1, report.dids.migrated);
+        assert_eq!(1, report.keys.migrated);
+        assert_eq!(
+            Some(&MigrationCount {
+                migrated: 1,
+                skipped: 0
+            }),
+            report
+                .records
+                .get(&RecordCategory::CredMapSchemaId.to_string())
+        );
+
+        let verkey = dest_wallet.key_for_did(did_data.did()).await.unwrap();
+        assert_eq!(did_data.verkey(), &verkey);
+
+        let record = dest_wallet
+            .get_record(RecordCategory::CredMapSchemaId, "schema_id")
+            .await
+            .unwrap();
+        assert_eq!("NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0", record.value());
+    }
+
+    #[tokio::test]
+    async fn test_migration_to_askar_dry_run_writes_nothing() {
+        let (src_wallet, dest_wallet) = setup_wallets().await;
+
+        let did_data = src_wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        let options = MigrationOptions {
+            dry_run: true,
+            ..Default::default()
+        };
+        let report = migrate_wallet_to_askar(&src_wallet, &dest_wallet, options)
+            .await
+            .unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(1, report.dids.migrated);
+
+        let err = dest_wallet.key_for_did(did_data.did()).await.unwrap_err();
+        assert_eq!(AriesVcxCoreErrorKind::WalletRecordNotFound, err.kind());
+    }
+
+    #[tokio::test]
+    async fn test_migration_to_askar_is_resumable() {
+        let (src_wallet, dest_wallet) = setup_wallets().await;
+
+        src_wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        migrate_wallet_to_askar(&src_wallet, &dest_wallet, MigrationOptions::default())
+            .await
+            .unwrap();
+
+        src_wallet.create_and_store_my_did(None, None).await.unwrap();
+
+        let report = migrate_wallet_to_askar(&src_wallet, &dest_wallet, MigrationOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            MigrationCount {
+                migrated: 1,
+                skipped: 1
+            },
+            report.dids
+        );
+        assert_eq!(
+            MigrationCount {
+                migrated: 1,
+                skipped: 1
+            },
+            report.keys
+        );
+    }
+}
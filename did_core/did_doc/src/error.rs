@@ -14,6 +14,7 @@ pub enum DidDocumentBuilderError {
     HexDecodeError(hex::FromHexError),
     UnsupportedVerificationMethodType(VerificationMethodType),
     PublicKeyError(public_key::PublicKeyError),
+    UnsupportedKeyType(public_key::KeyType),
 }
 
 impl std::fmt::Display for DidDocumentBuilderError {
@@ -49,6 +50,9 @@ impl std::fmt::Display for DidDocumentBuilderError {
             DidDocumentBuilderError::PublicKeyError(error) => {
                 write!(f, "Public key error: {}", error)
             }
+            DidDocumentBuilderError::UnsupportedKeyType(key_type) => {
+                write!(f, "Unsupported key type: {:?}", key_type)
+            }
         }
     }
 }
@@ -108,3 +112,9 @@ impl From<public_key::PublicKeyError> for DidDocumentBuilderError {
         DidDocumentBuilderError::PublicKeyError(error)
     }
 }
+
+impl From<elliptic_curve::Error> for DidDocumentBuilderError {
+    fn from(_: elliptic_curve::Error) -> Self {
+        DidDocumentBuilderError::InvalidInput("Invalid elliptic curve point".to_string())
+    }
+}
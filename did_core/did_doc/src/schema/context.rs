@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::utils::OneOrList;
+
+pub const DID_V1_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+pub const ED25519_2018_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2018/v1";
+pub const ED25519_2020_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2020/v1";
+pub const X25519_2019_CONTEXT: &str = "https://w3id.org/security/suites/x25519-2019/v1";
+pub const X25519_2020_CONTEXT: &str = "https://w3id.org/security/suites/x25519-2020/v1";
+pub const JWS_2020_CONTEXT: &str = "https://w3id.org/security/suites/jws-2020/v1";
+pub const SECP256K1_2019_CONTEXT: &str = "https://w3id.org/security/suites/secp256k1-2019/v1";
+pub const BLS12381_2020_CONTEXT: &str = "https://w3id.org/security/suites/bls12381-2020/v1";
+pub const MULTIKEY_CONTEXT: &str = "https://w3id.org/security/multikey/v1";
+
+/// Entry of a JSON-LD `@context`, either the URI of a remote context or an embedded context
+/// definition.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum ContextEntry {
+    Uri(String),
+    Definition(Map<String, Value>),
+}
+
+impl From<&str> for ContextEntry {
+    fn from(uri: &str) -> Self {
+        ContextEntry::Uri(uri.to_string())
+    }
+}
+
+impl From<String> for ContextEntry {
+    fn from(uri: String) -> Self {
+        ContextEntry::Uri(uri)
+    }
+}
+
+/// JSON-LD `@context` of a DID document. Accepts a single entry or a list when deserialized and
+/// is always serialized as a list.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+#[serde(from = "OneOrList<ContextEntry>", into = "Vec<ContextEntry>")]
+pub struct Context(Vec<ContextEntry>);
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn entries(&self) -> &[ContextEntry] {
+        self.0.as_ref()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains_uri(&self, uri: &str) -> bool {
+        self.0
+            .iter()
+            .any(|entry| matches!(entry, ContextEntry::Uri(entry) if entry == uri))
+    }
+
+    /// Appends the entry, unless the context already contains it.
+    pub fn add(&mut self, entry: ContextEntry) {
+        if !self.0.contains(&entry) {
+            self.0.push(entry);
+        }
+    }
+
+    /// Inserts the entry as the first one, moving it there if already present. The DID core
+    /// context must come first in the JSON-LD representation.
+    pub fn prepend(&mut self, entry: ContextEntry) {
+        self.0.retain(|existing| existing != &entry);
+        self.0.insert(0, entry);
+    }
+}
+
+impl From<OneOrList<ContextEntry>> for Context {
+    fn from(context: OneOrList<ContextEntry>) -> Self {
+        match context {
+            OneOrList::One(entry) => Self(vec![entry]),
+            OneOrList::List(entries) => Self(entries),
+        }
+    }
+}
+
+impl From<Context> for Vec<ContextEntry> {
+    fn from(context: Context) -> Self {
+        context.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_one_or_list() {
+        let context: Context = serde_json::from_value(json!(DID_V1_CONTEXT)).unwrap();
+        assert_eq!(&[ContextEntry::from(DID_V1_CONTEXT)], context.entries());
+
+        let context: Context =
+            serde_json::from_value(json!([DID_V1_CONTEXT, { "@base": "did:example:123" }]))
+                .unwrap();
+        assert!(context.contains_uri(DID_V1_CONTEXT));
+        assert!(matches!(context.entries()[1], ContextEntry::Definition(_)));
+        assert_eq!(
+            json!([DID_V1_CONTEXT, { "@base": "did:example:123" }]),
+            serde_json::to_value(&context).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add_and_prepend() {
+        let mut context = Context::new();
+        context.add(JWS_2020_CONTEXT.into());
+        context.add(JWS_2020_CONTEXT.into());
+        context.prepend(DID_V1_CONTEXT.into());
+
+        assert_eq!(
+            &[
+                ContextEntry::from(DID_V1_CONTEXT),
+                ContextEntry::from(JWS_2020_CONTEXT)
+            ],
+            context.entries()
+        );
+    }
+}
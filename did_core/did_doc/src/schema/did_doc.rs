@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use super::{
+    context::{Context, ContextEntry, DID_V1_CONTEXT},
     service::Service,
     types::uri::Uri,
     utils::OneOrList,
@@ -18,6 +19,8 @@ pub type ControllerAlias = OneOrList<Did>;
 #[serde(default)]
 #[serde(rename_all = "camelCase")]
 pub struct DidDocument<E> {
+    #[serde(rename = "@context", skip_serializing_if = "Context::is_empty")]
+    context: Context,
     id: Did,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     also_known_as: Vec<Uri>,
@@ -57,6 +60,10 @@ impl<E> DidDocument<E> {
         DidDocumentBuilder::new(id)
     }
 
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
     pub fn id(&self) -> &Did {
         &self.id
     }
@@ -111,10 +118,41 @@ impl<E> DidDocument<E> {
     pub fn validate(&self) -> Result<(), DidDocumentBuilderError> {
         Ok(())
     }
+
+    /// Adds the contexts required by the JSON-LD representation of the document: the DID core
+    /// context, first, and the contexts of the verification method types used in the document.
+    pub fn with_json_ld_context(mut self) -> Self {
+        self.context.prepend(DID_V1_CONTEXT.into());
+        let relationships = [
+            &self.authentication,
+            &self.assertion_method,
+            &self.key_agreement,
+            &self.capability_invocation,
+            &self.capability_delegation,
+        ];
+        let embedded_methods = relationships
+            .into_iter()
+            .flatten()
+            .filter_map(|vm| match vm {
+                VerificationMethodKind::Resolved(vm) => Some(vm),
+                VerificationMethodKind::Resolvable(_) => None,
+            });
+        let contexts: Vec<&str> = self
+            .verification_method
+            .iter()
+            .chain(embedded_methods)
+            .filter_map(|vm| vm.verification_method_type().json_ld_context())
+            .collect();
+        for context in contexts {
+            self.context.add(context.into());
+        }
+        self
+    }
 }
 
 #[derive(Debug)]
 pub struct DidDocumentBuilder<E> {
+    context: Context,
     id: Did,
     also_known_as: Vec<Uri>,
     controller: Vec<Did>,
@@ -131,6 +169,7 @@ pub struct DidDocumentBuilder<E> {
 impl<E> Default for DidDocumentBuilder<E> {
     fn default() -> Self {
         Self {
+            context: Default::default(),
             id: Default::default(),
             also_known_as: Default::default(),
             controller: Default::default(),
@@ -154,6 +193,11 @@ impl<E> DidDocumentBuilder<E> {
         }
     }
 
+    pub fn add_context(mut self, context: ContextEntry) -> Self {
+        self.context.add(context);
+        self
+    }
+
     pub fn add_also_known_as(mut self, also_known_as: Uri) -> Self {
         self.also_known_as.push(also_known_as);
         self
@@ -246,6 +290,7 @@ impl<E> DidDocumentBuilder<E> {
             Some(OneOrList::List(self.controller))
         };
         DidDocument {
+            context: self.context,
             id: self.id,
             also_known_as: self.also_known_as,
             controller,
@@ -269,6 +314,7 @@ impl<E> From<DidDocument<E>> for DidDocumentBuilder<E> {
         };
 
         Self {
+            context: did_document.context,
             id: did_document.id,
             also_known_as: did_document.also_known_as,
             controller,
@@ -392,4 +438,44 @@ mod tests {
             panic!("Verification method not found")
         };
     }
+
+    #[test]
+    fn test_with_json_ld_context() {
+        let id = Did::parse("did:example:123456789abcdefghi".to_string()).unwrap();
+        let verification_method = VerificationMethod::builder(
+            DidUrl::parse("did:example:123456789abcdefghi#key-1".to_string()).unwrap(),
+            id.clone(),
+            VerificationMethodType::Ed25519VerificationKey2020,
+        )
+        .add_public_key_multibase("z6MkmjY8GnV5i9YTDtPETC2uUAW6ejw3nk5mXF5yci5ab7th".to_string())
+        .build();
+        let key_agreement = VerificationMethod::builder(
+            DidUrl::parse("did:example:123456789abcdefghi#key-2".to_string()).unwrap(),
+            id.clone(),
+            VerificationMethodType::X25519KeyAgreementKey2020,
+        )
+        .add_public_key_multibase("z6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc".to_string())
+        .build();
+
+        let document = DidDocumentBuilder::<()>::new(id)
+            .add_context("https://didcomm.org/messaging/contexts/v2".into())
+            .add_verification_method(verification_method)
+            .add_key_agreement(key_agreement)
+            .build()
+            .with_json_ld_context();
+
+        assert_eq!(
+            serde_json::json!([
+                DID_V1_CONTEXT,
+                "https://didcomm.org/messaging/contexts/v2",
+                "https://w3id.org/security/suites/ed25519-2020/v1",
+                "https://w3id.org/security/suites/x25519-2020/v1"
+            ]),
+            serde_json::to_value(document)
+                .unwrap()
+                .get("@context")
+                .cloned()
+                .unwrap()
+        );
+    }
 }
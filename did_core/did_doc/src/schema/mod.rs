@@ -1,3 +1,4 @@
+pub mod context;
 pub mod did_doc;
 pub mod service;
 pub mod types;
@@ -4,19 +4,35 @@ use std::{
     str::FromStr,
 };
 
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use elliptic_curve::{
+    sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    AffinePoint, CurveArithmetic, FieldBytesSize, PublicKey,
+};
+use k256::Secp256k1;
+use p256::NistP256;
+use p384::NistP384;
+use p521::NistP521;
+use public_key::{Key, KeyType};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
 
 use crate::error::DidDocumentBuilderError;
 
+const KTY_OKP: &str = "OKP";
+const KTY_EC: &str = "EC";
+
+/// Public JSON Web Key of an `OKP` (RFC 8037) or `EC` (RFC 7518) key. Parameters other than the
+/// key type, curve and coordinates, e.g. `use` or `kid`, are kept as extra fields.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
-// TODO: Introduce proper custom type
-// Unfortunately only supports curves from the original RFC
-// pub struct JsonWebKey(jsonwebkey::JsonWebKey);
 pub struct JsonWebKey {
     kty: String,
     crv: String,
     x: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    y: Option<String>,
     #[serde(flatten)]
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     #[serde(default)]
@@ -28,9 +44,158 @@ impl JsonWebKey {
         Ok(serde_json::from_str(jwk)?)
     }
 
+    /// JWK of the public key. Keys on NIST curves and secp256k1 are expected SEC1 encoded, as in
+    /// their multicodec representation.
+    pub fn from_key(key: &Key) -> Result<Self, DidDocumentBuilderError> {
+        let (kty, crv, x, y) = match key.key_type() {
+            KeyType::Ed25519 => (KTY_OKP, "Ed25519", key.key().to_vec(), None),
+            KeyType::X25519 => (KTY_OKP, "X25519", key.key().to_vec(), None),
+            KeyType::Bls12381g1 => (KTY_OKP, "BLS12381G1", key.key().to_vec(), None),
+            KeyType::Bls12381g2 => (KTY_OKP, "BLS12381G2", key.key().to_vec(), None),
+            KeyType::P256 => ec_coordinates::<NistP256>("P-256", key.key())?,
+            KeyType::P384 => ec_coordinates::<NistP384>("P-384", key.key())?,
+            KeyType::P521 => ec_coordinates::<NistP521>("P-521", key.key())?,
+            KeyType::Secp256k1 => ec_coordinates::<Secp256k1>("secp256k1", key.key())?,
+            // concatenation of a G1 and a G2 key, which have a JWK each
+            KeyType::Bls12381g1g2 => {
+                return Err(DidDocumentBuilderError::UnsupportedKeyType(*key.key_type()))
+            }
+        };
+        Ok(Self {
+            kty: kty.to_string(),
+            crv: crv.to_string(),
+            x: URL_SAFE_NO_PAD.encode(x),
+            y: y.map(|y| URL_SAFE_NO_PAD.encode(y)),
+            extra: HashMap::new(),
+        })
+    }
+
+    pub fn kty(&self) -> &str {
+        self.kty.as_ref()
+    }
+
+    pub fn crv(&self) -> &str {
+        self.crv.as_ref()
+    }
+
+    pub fn x(&self) -> &str {
+        self.x.as_ref()
+    }
+
+    pub fn y(&self) -> Option<&str> {
+        self.y.as_deref()
+    }
+
+    pub fn extra_field(&self, key: &str) -> Option<&Value> {
+        self.extra.get(key)
+    }
+
+    pub fn add_extra_field(mut self, key: String, value: Value) -> Self {
+        self.extra.insert(key, value);
+        self
+    }
+
     pub fn to_vec(&self) -> Result<Vec<u8>, DidDocumentBuilderError> {
         serde_json::to_vec(self).map_err(|e| e.into())
     }
+
+    /// Public key of the JWK. Keys on NIST curves and secp256k1 are SEC1 compressed, as in their
+    /// multicodec representation.
+    pub fn to_key(&self) -> Result<Key, DidDocumentBuilderError> {
+        let x = URL_SAFE_NO_PAD.decode(&self.x)?;
+        let (key, key_type) = match (self.kty.as_str(), self.crv.as_str()) {
+            (KTY_OKP, "Ed25519") => (x, KeyType::Ed25519),
+            (KTY_OKP, "X25519") => (x, KeyType::X25519),
+            // BLS12381_G1/G2 are the curve names of earlier drafts, which use either key type
+            (KTY_OKP, "BLS12381G1") | (KTY_OKP | KTY_EC, "BLS12381_G1") => (x, KeyType::Bls12381g1),
+            (KTY_OKP, "BLS12381G2") | (KTY_OKP | KTY_EC, "BLS12381_G2") => (x, KeyType::Bls12381g2),
+            (KTY_EC, "P-256") => (
+                compressed_point::<NistP256>(&x, &self.decoded_y()?)?,
+                KeyType::P256,
+            ),
+            (KTY_EC, "P-384") => (
+                compressed_point::<NistP384>(&x, &self.decoded_y()?)?,
+                KeyType::P384,
+            ),
+            (KTY_EC, "P-521") => (
+                compressed_point::<NistP521>(&x, &self.decoded_y()?)?,
+                KeyType::P521,
+            ),
+            (KTY_EC, "secp256k1") => (
+                compressed_point::<Secp256k1>(&x, &self.decoded_y()?)?,
+                KeyType::Secp256k1,
+            ),
+            (kty, crv) => {
+                return Err(DidDocumentBuilderError::InvalidInput(format!(
+                    "Unsupported JWK key type {kty} with curve {crv}"
+                )))
+            }
+        };
+        Ok(Key::new(key, key_type)?)
+    }
+
+    /// JWK thumbprint as per RFC 7638: the base64url encoded SHA-256 digest of the required
+    /// parameters of the key, in lexicographic order and without whitespace.
+    pub fn thumbprint(&self) -> Result<String, DidDocumentBuilderError> {
+        let crv = serde_json::to_string(&self.crv)?;
+        let kty = serde_json::to_string(&self.kty)?;
+        let x = serde_json::to_string(&self.x)?;
+        let required = match self.kty.as_str() {
+            KTY_OKP => format!(r#"{{"crv":{crv},"kty":{kty},"x":{x}}}"#),
+            KTY_EC => {
+                let y = self
+                    .y
+                    .as_ref()
+                    .ok_or(DidDocumentBuilderError::MissingField("y"))?;
+                let y = serde_json::to_string(y)?;
+                format!(r#"{{"crv":{crv},"kty":{kty},"x":{x},"y":{y}}}"#)
+            }
+            kty => {
+                return Err(DidDocumentBuilderError::InvalidInput(format!(
+                    "Unsupported JWK key type {kty}"
+                )))
+            }
+        };
+        Ok(URL_SAFE_NO_PAD.encode(Sha256::digest(required)))
+    }
+
+    fn decoded_y(&self) -> Result<Vec<u8>, DidDocumentBuilderError> {
+        let y = self
+            .y
+            .as_ref()
+            .ok_or(DidDocumentBuilderError::MissingField("y"))?;
+        Ok(URL_SAFE_NO_PAD.decode(y)?)
+    }
+}
+
+type EcParameters = (&'static str, &'static str, Vec<u8>, Option<Vec<u8>>);
+
+/// JWK parameters of a SEC1 encoded point, compressed or not.
+fn ec_coordinates<C>(
+    crv: &'static str,
+    sec1_bytes: &[u8],
+) -> Result<EcParameters, DidDocumentBuilderError>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let point = PublicKey::<C>::from_sec1_bytes(sec1_bytes)?.to_encoded_point(false);
+    match (point.x(), point.y()) {
+        (Some(x), Some(y)) => Ok((KTY_EC, crv, x.to_vec(), Some(y.to_vec()))),
+        _ => Err(elliptic_curve::Error.into()),
+    }
+}
+
+fn compressed_point<C>(x: &[u8], y: &[u8]) -> Result<Vec<u8>, DidDocumentBuilderError>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let uncompressed = [&[0x04], x, y].concat();
+    let point = PublicKey::<C>::from_sec1_bytes(&uncompressed)?.to_encoded_point(true);
+    Ok(point.as_bytes().to_vec())
 }
 
 impl FromStr for JsonWebKey {
@@ -46,3 +211,123 @@ impl Display for JsonWebKey {
         write!(f, "{}", serde_json::to_string(&self).unwrap())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // SEC1 compressed P-256 generator point
+    const P256_GENERATOR: &str = "A2sX0fLhLEJH-Lzm5WOkQPJ3A32BLeszoPShOUXYmMKW";
+
+    #[test]
+    fn test_to_key_okp() {
+        let jwk = JsonWebKey::new(
+            r#"{"kty":"OKP","crv":"Ed25519","x":"bpcTF7ADRHnmazKt6qs0FEQJIE6XJuT98wpRjUDB0jc"}"#,
+        )
+        .unwrap();
+
+        let key = jwk.to_key().unwrap();
+
+        assert_eq!(&KeyType::Ed25519, key.key_type());
+        assert_eq!(
+            URL_SAFE_NO_PAD
+                .decode("bpcTF7ADRHnmazKt6qs0FEQJIE6XJuT98wpRjUDB0jc")
+                .unwrap(),
+            key.key()
+        );
+    }
+
+    #[test]
+    fn test_to_key_ec() {
+        let jwk = JsonWebKey::new(
+            r#"{
+                "kty": "EC",
+                "crv": "P-256",
+                "x": "axfR8uEsQkf4vOblY6RA8ncDfYEt6zOg9KE5RdiYwpY",
+                "y": "T-NC4v4af5uO5-tKfA-eFivOM1drMV7Oy7ZAaDe_UfU"
+            }"#,
+        )
+        .unwrap();
+
+        let key = jwk.to_key().unwrap();
+
+        assert_eq!(&KeyType::P256, key.key_type());
+        assert_eq!(URL_SAFE_NO_PAD.decode(P256_GENERATOR).unwrap(), key.key());
+    }
+
+    #[test]
+    fn test_to_key_invalid_point() {
+        let jwk = JsonWebKey::new(
+            r#"{
+                "kty": "EC",
+                "crv": "P-256",
+                "x": "axfR8uEsQkf4vOblY6RA8ncDfYEt6zOg9KE5RdiYwpY",
+                "y": "axfR8uEsQkf4vOblY6RA8ncDfYEt6zOg9KE5RdiYwpY"
+            }"#,
+        )
+        .unwrap();
+
+        assert!(jwk.to_key().is_err());
+    }
+
+    #[test]
+    fn test_from_key_round_trip() {
+        let keys = [
+            Key::new(vec![7u8; 32], KeyType::Ed25519).unwrap(),
+            Key::new(vec![3u8; 32], KeyType::X25519).unwrap(),
+            Key::new(vec![1u8; 48], KeyType::Bls12381g1).unwrap(),
+            Key::new(vec![2u8; 96], KeyType::Bls12381g2).unwrap(),
+            Key::new(
+                URL_SAFE_NO_PAD.decode(P256_GENERATOR).unwrap(),
+                KeyType::P256,
+            )
+            .unwrap(),
+            Key::from_fingerprint("zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme").unwrap(),
+        ];
+
+        for key in keys {
+            let jwk = JsonWebKey::from_key(&key).unwrap();
+            assert_eq!(key, jwk.to_key().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bls_curve_names() {
+        let key = Key::new(vec![1u8; 48], KeyType::Bls12381g1).unwrap();
+        let jwk = JsonWebKey::from_key(&key).unwrap();
+        assert_eq!("BLS12381G1", jwk.crv());
+
+        let legacy = JsonWebKey::new(&format!(
+            r#"{{"kty":"EC","crv":"BLS12381_G1","x":"{}"}}"#,
+            jwk.x()
+        ))
+        .unwrap();
+        assert_eq!(key, legacy.to_key().unwrap());
+    }
+
+    #[test]
+    fn test_from_key_unsupported() {
+        let key = Key::new(vec![1u8; 144], KeyType::Bls12381g1g2).unwrap();
+
+        assert!(matches!(
+            JsonWebKey::from_key(&key),
+            Err(DidDocumentBuilderError::UnsupportedKeyType(
+                KeyType::Bls12381g1g2
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_thumbprint() {
+        // RFC 8037, appendix A.3
+        let jwk = JsonWebKey::new(
+            r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            "kPrK_qmxVWaYVA9wwBF6Iuo3vVzz7TxHCTwXBygrS4k",
+            jwk.thumbprint().unwrap()
+        );
+    }
+}
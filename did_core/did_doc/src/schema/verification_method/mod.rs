@@ -47,11 +47,22 @@ impl VerificationMethod {
         &self.public_key
     }
 
+    /// Public key of the verification method. The key type is taken from the JWK or the
+    /// multicodec prefix of `Multikey` methods, and from the method type otherwise.
     pub fn public_key(&self) -> Result<Key, DidDocumentBuilderError> {
-        Ok(Key::new(
-            self.public_key.key_decoded()?,
-            self.verification_method_type.try_into()?,
-        )?)
+        match (&self.public_key, self.verification_method_type) {
+            (PublicKeyField::Jwk { public_key_jwk }, _) => public_key_jwk.to_key(),
+            (
+                PublicKeyField::Multibase {
+                    public_key_multibase,
+                },
+                VerificationMethodType::Multikey,
+            ) => Ok(Key::from_fingerprint(public_key_multibase)?),
+            (public_key, verification_method_type) => Ok(Key::new(
+                public_key.key_decoded()?,
+                verification_method_type.try_into()?,
+            )?),
+        }
     }
 }
 
@@ -317,4 +328,59 @@ mod tests {
         let public_key = vm.public_key().unwrap();
         assert_eq!(public_key.multibase58(), public_key_multibase_expected);
     }
+
+    #[test]
+    fn test_verification_method_multikey_public_key() {
+        let key =
+            Key::from_fingerprint("zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme").unwrap();
+
+        let vm = VerificationMethod::builder(
+            create_valid_did_url(),
+            create_valid_did(),
+            VerificationMethodType::Multikey,
+        )
+        .add_public_key_multibase(key.fingerprint())
+        .build();
+
+        assert_eq!(vm.public_key().unwrap(), key);
+    }
+
+    #[test]
+    fn test_verification_method_public_key_representations() {
+        let key =
+            Key::from_fingerprint("zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme").unwrap();
+        let builder = || {
+            VerificationMethod::builder(
+                create_valid_did_url(),
+                create_valid_did(),
+                VerificationMethodType::EcdsaSecp256k1VerificationKey2019,
+            )
+        };
+
+        let base58 = builder().add_public_key_base58(key.base58()).build();
+        let multibase = builder()
+            .add_public_key_multibase(key.fingerprint())
+            .build();
+        let jwk = VerificationMethod::builder(
+            create_valid_did_url(),
+            create_valid_did(),
+            VerificationMethodType::JsonWebKey2020,
+        )
+        .add_public_key_jwk(
+            JsonWebKey::new(
+                r#"{
+                    "kty": "EC",
+                    "crv": "secp256k1",
+                    "x": "h0wVx_2iDlOcblulc8E5iEw1EYh5n1RYtLQfeSTyNc0",
+                    "y": "O2EATIGbu6DezKFptj5scAIRntgfecanVNXxat1rnwE"
+                }"#,
+            )
+            .unwrap(),
+        )
+        .build();
+
+        assert_eq!(base58.public_key().unwrap(), key);
+        assert_eq!(multibase.public_key().unwrap(), key);
+        assert_eq!(jwk.public_key().unwrap(), key);
+    }
 }
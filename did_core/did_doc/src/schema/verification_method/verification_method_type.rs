@@ -3,7 +3,7 @@ use std::fmt::Display;
 use public_key::KeyType;
 use serde::{Deserialize, Serialize};
 
-use crate::error::DidDocumentBuilderError;
+use crate::{error::DidDocumentBuilderError, schema::context};
 
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
 pub enum VerificationMethodType {
@@ -18,6 +18,33 @@ pub enum VerificationMethodType {
     X25519KeyAgreementKey2019,
     X25519KeyAgreementKey2020,
     EcdsaSecp256k1RecoveryMethod2020,
+    Multikey,
+}
+
+impl VerificationMethodType {
+    /// JSON-LD context defining the verification method type, if any.
+    pub fn json_ld_context(&self) -> Option<&'static str> {
+        match self {
+            VerificationMethodType::JsonWebKey2020 => Some(context::JWS_2020_CONTEXT),
+            VerificationMethodType::EcdsaSecp256k1VerificationKey2019 => {
+                Some(context::SECP256K1_2019_CONTEXT)
+            }
+            VerificationMethodType::Ed25519VerificationKey2018 => {
+                Some(context::ED25519_2018_CONTEXT)
+            }
+            VerificationMethodType::Ed25519VerificationKey2020 => {
+                Some(context::ED25519_2020_CONTEXT)
+            }
+            VerificationMethodType::Bls12381G1Key2020
+            | VerificationMethodType::Bls12381G2Key2020 => Some(context::BLS12381_2020_CONTEXT),
+            VerificationMethodType::X25519KeyAgreementKey2019 => Some(context::X25519_2019_CONTEXT),
+            VerificationMethodType::X25519KeyAgreementKey2020 => Some(context::X25519_2020_CONTEXT),
+            VerificationMethodType::Multikey => Some(context::MULTIKEY_CONTEXT),
+            VerificationMethodType::PgpVerificationKey2021
+            | VerificationMethodType::RsaVerificationKey2018
+            | VerificationMethodType::EcdsaSecp256k1RecoveryMethod2020 => None,
+        }
+    }
 }
 
 impl Display for VerificationMethodType {
@@ -46,6 +73,7 @@ impl Display for VerificationMethodType {
             VerificationMethodType::EcdsaSecp256k1RecoveryMethod2020 => {
                 write!(f, "EcdsaSecp256k1RecoveryMethod2020")
             }
+            VerificationMethodType::Multikey => write!(f, "Multikey"),
         }
     }
 }
@@ -61,6 +89,7 @@ impl TryFrom<VerificationMethodType> for KeyType {
             VerificationMethodType::Bls12381G2Key2020 => Ok(KeyType::Bls12381g2),
             VerificationMethodType::X25519KeyAgreementKey2019
             | VerificationMethodType::X25519KeyAgreementKey2020 => Ok(KeyType::X25519),
+            VerificationMethodType::EcdsaSecp256k1VerificationKey2019 => Ok(KeyType::Secp256k1),
             _ => Err(DidDocumentBuilderError::UnsupportedVerificationMethodType(
                 value,
             )),
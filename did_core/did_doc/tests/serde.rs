@@ -83,6 +83,9 @@ fn test_deserialization() {
         did_doc.also_known_as(),
         vec![Uri::from_str("https://example.com/user-profile/123").unwrap()]
     );
+    assert!(did_doc
+        .context()
+        .contains_uri("https://w3id.org/security/suites/ed25519-2018/v1"));
 
     let controller: Did = "did:web:did-actor-alice".to_string().try_into().unwrap();
 
@@ -11,6 +11,7 @@ use std::collections::HashMap;
 use did_doc::{
     did_parser::{Did, DidUrl},
     schema::{
+        context::Context,
         did_doc::{ControllerAlias, DidDocument, DidDocumentBuilder},
         service::Service,
         utils::OneOrList,
@@ -33,6 +34,10 @@ impl DidDocumentSov {
         DidDocumentSovBuilder::new(id)
     }
 
+    pub fn context(&self) -> &Context {
+        self.did_doc.context()
+    }
+
     pub fn id(&self) -> &Did {
         self.did_doc.id()
     }
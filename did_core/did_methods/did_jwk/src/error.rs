@@ -0,0 +1,25 @@
+use std::convert::Infallible;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DidJwkError {
+    #[error("DID method not supported: {0}")]
+    MethodNotSupported(String),
+    #[error("Public key error: {0}")]
+    PublicKeyError(#[from] public_key::PublicKeyError),
+    #[error("DID parser error: {0}")]
+    DidParserError(#[from] did_resolver::did_parser::ParseError),
+    #[error("DID document builder error: {0}")]
+    DidDocumentBuilderError(#[from] did_resolver::did_doc::error::DidDocumentBuilderError),
+    #[error("Base 64 decoding error")]
+    Base64DecodingError(#[from] base64::DecodeError),
+    #[error("JSON error: {0}")]
+    JsonError(#[from] serde_json::Error),
+}
+
+impl From<Infallible> for DidJwkError {
+    fn from(_: Infallible) -> Self {
+        panic!("Attempted to convert an Infallible error")
+    }
+}
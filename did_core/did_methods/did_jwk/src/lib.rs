@@ -0,0 +1,141 @@
+pub mod error;
+pub mod resolver;
+
+use core::fmt;
+use std::fmt::Display;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use did_resolver::{did_doc::schema::types::jsonwebkey::JsonWebKey, did_parser::Did};
+use error::DidJwkError;
+use public_key::Key;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A did:jwk DID, whose method specific id is the base64url encoded JWK of its only key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DidJwk {
+    jwk: JsonWebKey,
+    key: Key,
+    did: Did,
+}
+
+impl DidJwk {
+    pub fn parse<T>(did: T) -> Result<DidJwk, DidJwkError>
+    where
+        Did: TryFrom<T>,
+        <Did as TryFrom<T>>::Error: Into<DidJwkError>,
+    {
+        let did: Did = did.try_into().map_err(Into::into)?;
+        match did.method() {
+            Some("jwk") => {}
+            method => {
+                return Err(DidJwkError::MethodNotSupported(
+                    method.unwrap_or_default().to_string(),
+                ))
+            }
+        }
+        let jwk: JsonWebKey = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(did.id())?)?;
+        let key = jwk.to_key()?;
+
+        Ok(Self { jwk, key, did })
+    }
+
+    pub fn jwk(&self) -> &JsonWebKey {
+        &self.jwk
+    }
+
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    pub fn did(&self) -> &Did {
+        &self.did
+    }
+}
+
+impl TryFrom<Key> for DidJwk {
+    type Error = DidJwkError;
+
+    fn try_from(key: Key) -> Result<Self, Self::Error> {
+        let jwk = JsonWebKey::from_key(&key)?;
+        let did = Did::parse(format!(
+            "did:jwk:{}",
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&jwk)?)
+        ))?;
+        Ok(Self { jwk, key, did })
+    }
+}
+
+impl Display for DidJwk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.did)
+    }
+}
+
+impl Serialize for DidJwk {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.did.did())
+    }
+}
+
+impl<'de> Deserialize<'de> for DidJwk {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+
+        DidJwk::parse(s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use public_key::KeyType;
+
+    use super::*;
+
+    // P-256 example of the did:jwk specification
+    const VALID_DID_JWK: &str = "did:jwk:eyJjcnYiOiJQLTI1NiIsImt0eSI6IkVDIiwieCI6ImFjYklRaXVNczNpOF91c3pFakoydHBUdFJNNEVVM3l6OTFQSDZDZEgyVjAiLCJ5IjoiX0tjeUxqOXZXTXB0bm1LdG00NkdxRHo4d2Y3NEk1TEtncmwyR3pIM25TRSJ9";
+
+    #[test]
+    fn test_parse() {
+        let did_jwk = DidJwk::parse(VALID_DID_JWK.to_string()).unwrap();
+
+        assert_eq!(&KeyType::P256, did_jwk.key().key_type());
+        assert_eq!(VALID_DID_JWK, did_jwk.did().did());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(DidJwk::parse("did:jwk:somenonsense".to_string()).is_err());
+        assert!(matches!(
+            DidJwk::parse("did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK".to_string()),
+            Err(DidJwkError::MethodNotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_try_from_key_round_trip() {
+        let key = Key::new(vec![3u8; 32], KeyType::X25519).unwrap();
+        let did_jwk = DidJwk::try_from(key.clone()).unwrap();
+
+        let parsed = DidJwk::parse(did_jwk.to_string()).unwrap();
+        assert_eq!(did_jwk, parsed);
+        assert_eq!(&key, parsed.key());
+    }
+
+    #[test]
+    fn test_serde() {
+        let did_jwk = DidJwk::parse(VALID_DID_JWK.to_string()).unwrap();
+        let serialized = serde_json::to_string(&did_jwk).unwrap();
+
+        assert_eq!(format!("\"{VALID_DID_JWK}\""), serialized);
+        assert_eq!(
+            did_jwk,
+            serde_json::from_str::<DidJwk>(&serialized).unwrap()
+        );
+    }
+}
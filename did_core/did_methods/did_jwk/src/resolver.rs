@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use did_resolver::{
+    did_doc::schema::{
+        context::{DID_V1_CONTEXT, JWS_2020_CONTEXT},
+        did_doc::DidDocument,
+        verification_method::{VerificationMethod, VerificationMethodType},
+    },
+    did_parser::{Did, DidUrl},
+    error::GenericError,
+    shared_types::media_type::MediaType,
+    traits::resolvable::{
+        resolution_options::DidResolutionOptions, resolution_output::DidResolutionOutput,
+        DidResolvable,
+    },
+};
+
+use crate::{error::DidJwkError, DidJwk};
+
+#[derive(Default)]
+pub struct DidJwkResolver;
+
+impl DidJwkResolver {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl DidResolvable for DidJwkResolver {
+    type ExtraFieldsService = ();
+    type ExtraFieldsOptions = ();
+
+    async fn resolve(
+        &self,
+        did: &Did,
+        options: &DidResolutionOptions<Self::ExtraFieldsOptions>,
+    ) -> Result<DidResolutionOutput<Self::ExtraFieldsService>, GenericError> {
+        let did_jwk = DidJwk::parse(did.to_owned())?;
+        Ok(DidResolutionOutput::builder(did_jwk_to_did_doc(&did_jwk)?)
+            .build()
+            .into_representation(options.accept().unwrap_or(&MediaType::DidJson)))
+    }
+}
+
+/// DID document of a did:jwk DID, as per the did:jwk method specification. The `use` member of
+/// the JWK restricts the key to either signing or key agreement.
+pub fn did_jwk_to_did_doc(did_jwk: &DidJwk) -> Result<DidDocument<()>, DidJwkError> {
+    let did = did_jwk.did();
+    let vm_id = DidUrl::parse(format!("{did}#0"))?;
+    let verification_method = VerificationMethod::builder(
+        vm_id.clone(),
+        did.clone(),
+        VerificationMethodType::JsonWebKey2020,
+    )
+    .add_public_key_jwk(did_jwk.jwk().clone())
+    .build();
+    let key_use = did_jwk
+        .jwk()
+        .extra_field("use")
+        .and_then(|key_use| key_use.as_str());
+
+    let mut builder = DidDocument::builder(did.clone())
+        .add_context(DID_V1_CONTEXT.into())
+        .add_context(JWS_2020_CONTEXT.into())
+        .add_verification_method(verification_method);
+    if key_use != Some("enc") {
+        builder = builder
+            .add_assertion_method_reference(vm_id.clone())
+            .add_authentication_reference(vm_id.clone())
+            .add_capability_invocation_reference(vm_id.clone())
+            .add_capability_delegation_refrence(vm_id.clone());
+    }
+    if key_use != Some("sig") {
+        builder = builder.add_key_agreement_reference(vm_id);
+    }
+    Ok(builder.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use public_key::{Key, KeyType};
+    use serde_json::json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolve() {
+        let did_jwk = DidJwk::try_from(Key::new(vec![5u8; 32], KeyType::Ed25519).unwrap()).unwrap();
+
+        let output = DidJwkResolver::new()
+            .resolve(did_jwk.did(), &DidResolutionOptions::default())
+            .await
+            .unwrap();
+
+        let ddo = serde_json::to_value(output.did_document()).unwrap();
+        let vm_id = format!("{did_jwk}#0");
+        assert_eq!(did_jwk.to_string(), ddo["id"]);
+        assert_eq!("JsonWebKey2020", ddo["verificationMethod"][0]["type"]);
+        assert_eq!(
+            serde_json::to_value(did_jwk.jwk()).unwrap(),
+            ddo["verificationMethod"][0]["publicKeyJwk"]
+        );
+        assert_eq!(json!([vm_id]), ddo["authentication"]);
+        assert_eq!(json!([vm_id]), ddo["keyAgreement"]);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_json_ld() {
+        let did_jwk = DidJwk::try_from(Key::new(vec![5u8; 32], KeyType::Ed25519).unwrap()).unwrap();
+        let options = DidResolutionOptions::default().set_accept(MediaType::DidLdJson);
+
+        let output = DidJwkResolver::new()
+            .resolve(did_jwk.did(), &options)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some(&"application/did+ld+json".to_string()),
+            output.did_resolution_metadata().content_type()
+        );
+        assert_eq!(
+            json!([DID_V1_CONTEXT, JWS_2020_CONTEXT]),
+            serde_json::to_value(output.did_document()).unwrap()["@context"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_signing_key() {
+        let jwk = json!({
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "use": "sig",
+            "x": "3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"
+        });
+        let did = format!(
+            "did:jwk:{}",
+            base64::Engine::encode(
+                &base64::engine::general_purpose::URL_SAFE_NO_PAD,
+                jwk.to_string()
+            )
+        );
+        let did_jwk = DidJwk::parse(did).unwrap();
+
+        let ddo = serde_json::to_value(did_jwk_to_did_doc(&did_jwk).unwrap()).unwrap();
+
+        assert_eq!(1, ddo["assertionMethod"].as_array().unwrap().len());
+        assert!(ddo.get("keyAgreement").is_none());
+    }
+}
@@ -35,6 +35,12 @@ pub enum DidPeerError {
     RegexError(#[from] regex::Error),
     #[error("Public key error: {0}")]
     PublicKeyError(#[from] public_key::PublicKeyError),
+    #[error("DID key error: {0}")]
+    DidKeyError(#[from] did_key::error::DidKeyError),
+    #[error("Wallet error: {0}")]
+    WalletError(#[from] aries_vcx_core::errors::error::AriesVcxCoreError),
+    #[error("Operation not supported by peer DIDs: {0}")]
+    UnsupportedOperation(String),
 }
 
 impl From<Infallible> for DidPeerError {
@@ -2,4 +2,5 @@ extern crate display_as_json;
 
 pub mod error;
 pub mod peer_did;
+pub mod registrar;
 pub mod resolver;
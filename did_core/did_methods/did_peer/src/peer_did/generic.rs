@@ -5,7 +5,7 @@ use super::PeerDid;
 use crate::{
     error::DidPeerError,
     peer_did::{
-        numalgos::{kind::NumalgoKind, numalgo2::Numalgo2, numalgo3::Numalgo3},
+        numalgos::{kind::NumalgoKind, numalgo2::Numalgo2, numalgo3::Numalgo3, numalgo4::Numalgo4},
         parse::parse_numalgo,
         validate::validate,
     },
@@ -15,6 +15,7 @@ use crate::{
 pub enum AnyPeerDid {
     Numalgo2(PeerDid<Numalgo2>),
     Numalgo3(PeerDid<Numalgo3>),
+    Numalgo4(PeerDid<Numalgo4>),
 }
 
 impl AnyPeerDid {
@@ -30,6 +31,9 @@ impl AnyPeerDid {
             NumalgoKind::MultipleInceptionKeys(numalgo) => {
                 AnyPeerDid::Numalgo2(PeerDid { did, numalgo })
             }
+            NumalgoKind::ShortAndLongForm(numalgo) => {
+                AnyPeerDid::Numalgo4(PeerDid { did, numalgo })
+            }
             _ => AnyPeerDid::Numalgo3(PeerDid {
                 did,
                 numalgo: Numalgo3,
@@ -42,6 +46,7 @@ impl AnyPeerDid {
         match self {
             AnyPeerDid::Numalgo2(peer_did) => NumalgoKind::MultipleInceptionKeys(peer_did.numalgo),
             AnyPeerDid::Numalgo3(peer_did) => NumalgoKind::DidShortening(peer_did.numalgo),
+            AnyPeerDid::Numalgo4(peer_did) => NumalgoKind::ShortAndLongForm(peer_did.numalgo),
         }
     }
 }
@@ -54,6 +59,7 @@ impl Serialize for AnyPeerDid {
         match &self {
             AnyPeerDid::Numalgo2(peer_did) => serializer.serialize_str(peer_did.did().did()),
             AnyPeerDid::Numalgo3(peer_did) => serializer.serialize_str(peer_did.did().did()),
+            AnyPeerDid::Numalgo4(peer_did) => serializer.serialize_str(peer_did.did().did()),
         }
     }
 }
@@ -1,5 +1,8 @@
-use did_doc::schema::verification_method::{
-    IncompleteVerificationMethodBuilder, VerificationMethod, VerificationMethodType,
+use did_doc::{
+    error::DidDocumentBuilderError,
+    schema::verification_method::{
+        IncompleteVerificationMethodBuilder, VerificationMethod, VerificationMethodType,
+    },
 };
 use did_parser::{Did, DidUrl};
 use public_key::{Key, KeyType};
@@ -20,6 +23,7 @@ pub fn get_verification_methods_by_key(
         KeyType::P256 => VerificationMethodType::JsonWebKey2020,
         KeyType::P384 => VerificationMethodType::JsonWebKey2020,
         KeyType::P521 => VerificationMethodType::JsonWebKey2020,
+        KeyType::Secp256k1 => VerificationMethodType::EcdsaSecp256k1VerificationKey2019,
         KeyType::Bls12381g1g2 => {
             return Ok(build_verification_methods_from_bls_multikey(
                 &Key::new(key.key()[..48].to_vec(), KeyType::Bls12381g1)?,
@@ -39,20 +43,13 @@ pub fn get_verification_methods_by_key(
 }
 
 pub fn get_key_by_verification_method(vm: &VerificationMethod) -> Result<Key, DidPeerError> {
-    let key_type = match vm.verification_method_type() {
-        VerificationMethodType::Ed25519VerificationKey2018
-        | VerificationMethodType::Ed25519VerificationKey2020 => KeyType::Ed25519,
-        VerificationMethodType::Bls12381G1Key2020 => KeyType::Bls12381g1,
-        VerificationMethodType::Bls12381G2Key2020 => KeyType::Bls12381g2,
-        VerificationMethodType::X25519KeyAgreementKey2019
-        | VerificationMethodType::X25519KeyAgreementKey2020 => KeyType::X25519,
-        t => {
-            return Err(DidPeerError::UnsupportedVerificationMethodType(
-                t.to_owned(),
-            ));
+    match vm.public_key() {
+        Ok(key) => Ok(key),
+        Err(DidDocumentBuilderError::UnsupportedVerificationMethodType(t)) => {
+            Err(DidPeerError::UnsupportedVerificationMethodType(t))
         }
-    };
-    Ok(Key::new(vm.public_key_field().key_decoded()?, key_type)?)
+        Err(err) => Err(err.into()),
+    }
 }
 
 fn build_verification_methods_from_type_and_key(
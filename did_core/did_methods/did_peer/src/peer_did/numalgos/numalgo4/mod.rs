@@ -0,0 +1,146 @@
+use did_doc::schema::did_doc::DidDocument;
+use did_doc_sov::extra_fields::ExtraFieldsSov;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    error::DidPeerError,
+    peer_did::{numalgos::Numalgo, FromDidDoc, PeerDid},
+};
+
+/// Multicodec code of JSON, prefixing the encoded input document.
+const MULTICODEC_JSON: u64 = 0x0200;
+/// Multihash code of SHA2-256, prefixing the hash of the encoded input document.
+const MULTIHASH_SHA2_256: u64 = 0x12;
+
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct Numalgo4;
+
+impl Numalgo for Numalgo4 {
+    const NUMALGO_CHAR: char = '4';
+}
+
+impl FromDidDoc for Numalgo4 {
+    /// Creates the long form DID of `did_document`. The `id` of the document is left out of the
+    /// encoded input document, so its elements should be identified by relative references.
+    fn from_did_doc(
+        did_document: DidDocument<ExtraFieldsSov>,
+    ) -> Result<PeerDid<Numalgo4>, DidPeerError> {
+        let encoded_document = multibase::encode(
+            multibase::Base::Base58Btc,
+            multicodec_prefixed(
+                MULTICODEC_JSON,
+                &serde_json::to_vec(&input_document(&did_document)?)?,
+            ),
+        );
+        let digest = Sha256::digest(encoded_document.as_bytes());
+        let hash = multibase::encode(
+            multibase::Base::Base58Btc,
+            multicodec_prefixed(
+                MULTIHASH_SHA2_256,
+                &multicodec_prefixed(digest.len() as u64, &digest),
+            ),
+        );
+        PeerDid::<Numalgo4>::parse(format!("did:peer:4{hash}:{encoded_document}"))
+    }
+}
+
+impl PeerDid<Numalgo4> {
+    /// Whether the DID carries its encoded input document, as opposed to only its hash.
+    pub fn is_long_form(&self) -> bool {
+        self.did().id().contains(':')
+    }
+
+    /// Short form of the DID, the hash of its input document, by which it is known once the
+    /// long form has been shared.
+    pub fn short_form(&self) -> Result<PeerDid<Numalgo4>, DidPeerError> {
+        let hash = self.did().id().split(':').next().unwrap_or_default();
+        PeerDid::<Numalgo4>::parse(format!("did:peer:{hash}"))
+    }
+}
+
+/// Strips `did_document` of its `id` and of the controllers of its verification methods which
+/// refer to it, as the DID is derived from the input document.
+fn input_document(did_document: &DidDocument<ExtraFieldsSov>) -> Result<Value, DidPeerError> {
+    let id = did_document.id().did();
+    let mut document = serde_json::to_value(did_document)?;
+    if let Some(fields) = document.as_object_mut() {
+        fields.remove("id");
+        for elements in fields.values_mut().filter_map(Value::as_array_mut) {
+            for element in elements.iter_mut().filter_map(Value::as_object_mut) {
+                if element.get("controller").and_then(Value::as_str) == Some(id) {
+                    element.remove("controller");
+                }
+            }
+        }
+    }
+    Ok(document)
+}
+
+fn multicodec_prefixed(code: u64, bytes: &[u8]) -> Vec<u8> {
+    let mut buffer = [0u8; 10];
+    let mut prefixed = unsigned_varint::encode::u64(code, &mut buffer).to_vec();
+    prefixed.extend_from_slice(bytes);
+    prefixed
+}
+
+#[cfg(test)]
+mod tests {
+    use did_doc::schema::verification_method::{VerificationMethod, VerificationMethodType};
+    use did_parser::{Did, DidUrl};
+
+    use super::*;
+
+    fn did_document() -> DidDocument<ExtraFieldsSov> {
+        let verification_method = VerificationMethod::builder(
+            DidUrl::from_fragment("6MkqRYqQ".to_string()).unwrap(),
+            Did::default(),
+            VerificationMethodType::Ed25519VerificationKey2020,
+        )
+        .add_public_key_base58("8HH5gYEeNc3z7PYXmd54d4x6qAfCNrqQqEB3nS7Zfu7K".to_string())
+        .build();
+        DidDocument::builder(Did::default())
+            .add_verification_method(verification_method)
+            .build()
+    }
+
+    #[test]
+    fn test_generate_numalgo4() {
+        let peer_did = PeerDid::<Numalgo4>::from_did_doc(did_document()).unwrap();
+
+        assert!(peer_did.is_long_form());
+        let (hash, encoded_document) = peer_did.did().id()[1..].split_once(':').unwrap();
+        let digest = Sha256::digest(encoded_document.as_bytes());
+        let (_, multihash) = multibase::decode(hash).unwrap();
+        assert_eq!(multihash[..2], [0x12, 0x20]);
+        assert_eq!(multihash[2..], digest[..]);
+
+        let (_, multicodec) = multibase::decode(encoded_document).unwrap();
+        assert_eq!(multicodec[..2], [0x80, 0x04]);
+        let document: Value = serde_json::from_slice(&multicodec[2..]).unwrap();
+        assert_eq!(
+            document,
+            serde_json::json!({
+                "verificationMethod": [{
+                    "id": "#6MkqRYqQ",
+                    "type": "Ed25519VerificationKey2020",
+                    "publicKeyBase58": "8HH5gYEeNc3z7PYXmd54d4x6qAfCNrqQqEB3nS7Zfu7K"
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_short_form() {
+        let peer_did = PeerDid::<Numalgo4>::from_did_doc(did_document()).unwrap();
+
+        let short_form = peer_did.short_form().unwrap();
+
+        assert!(!short_form.is_long_form());
+        assert!(peer_did
+            .did()
+            .did()
+            .starts_with(&format!("{}:z", short_form.did())));
+        assert_eq!(short_form, short_form.short_form().unwrap());
+    }
+}
@@ -0,0 +1,322 @@
+use aries_vcx_core::wallet::{base_wallet::BaseWallet, record_tags::RecordTags};
+use async_trait::async_trait;
+use did_doc::schema::{
+    did_doc::{DidDocument, DidDocumentBuilder},
+    service::Service,
+    types::uri::Uri,
+    verification_method::{VerificationMethod, VerificationMethodType},
+};
+use did_doc_sov::{
+    extra_fields::{didcommv1::ExtraFieldsDidCommV1, ExtraFieldsSov, KeyKind},
+    service::ServiceType,
+};
+use did_parser::{Did, DidUrl};
+use did_registrar::{
+    registration_options::DidRegistrationOptions, registration_output::DidRegistrationOutput,
+    DidRegistrar,
+};
+use did_resolver::error::GenericError;
+use public_key::{Key, KeyType};
+
+use crate::{
+    error::DidPeerError,
+    peer_did::{
+        numalgos::{numalgo2::Numalgo2, numalgo4::Numalgo4, Numalgo},
+        PeerDid,
+    },
+    registrar::options::PeerDidCreateOptions,
+};
+
+pub mod options;
+
+/// Registrar of numalgo 2 and numalgo 4 peer DIDs. Their keys and service are encoded in the DID
+/// itself, so they are created without any registry and can neither be updated nor deactivated.
+///
+/// The created DIDs have an Ed25519 verification key, generated from the seed of the options if
+/// any, an X25519 key agreement key and, given an endpoint, a DIDComm v1 service with the key
+/// agreement key as its recipient key. Numalgo 4 DIDs are created in long form and know their
+/// short form as `alsoKnownAs`, numalgo 2 ones their numalgo 3 form.
+#[derive(Default)]
+pub struct PeerDidRegistrar;
+
+impl PeerDidRegistrar {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Creates the keys of a new peer DID in `wallet` and returns its document, along with the
+    /// key agreement key DIDComm v1 messages to the DID are packed for.
+    pub async fn create_did_document(
+        &self,
+        wallet: &impl BaseWallet,
+        options: &DidRegistrationOptions<PeerDidCreateOptions>,
+    ) -> Result<(DidDocument<ExtraFieldsSov>, Key), DidPeerError> {
+        let numalgo = options.extra().numalgo();
+        if ![Numalgo2::NUMALGO_CHAR, Numalgo4::NUMALGO_CHAR].contains(&numalgo) {
+            return Err(DidPeerError::UnsupportedOperation(format!(
+                "create numalgo {numalgo} DID"
+            )));
+        }
+
+        let key_ver = wallet
+            .create_key(KeyType::Ed25519, options.seed(), RecordTags::default())
+            .await?
+            .key()
+            .clone();
+        // DIDComm v1 packs messages with the Ed25519 keys of the wallet, which are converted to
+        // X25519 ones to encrypt for
+        let key_enc = Key::from_base58(
+            &wallet
+                .create_key(KeyType::Ed25519, None, RecordTags::default())
+                .await?
+                .key()
+                .base58(),
+            KeyType::X25519,
+        )?;
+        let service = options
+            .extra()
+            .service_endpoint()
+            .map(|service_endpoint| {
+                let extra = ExtraFieldsDidCommV1::builder()
+                    .set_routing_keys(
+                        options
+                            .extra()
+                            .routing_keys()
+                            .iter()
+                            .cloned()
+                            .map(KeyKind::Value)
+                            .collect(),
+                    )
+                    .set_recipient_keys(vec![KeyKind::DidKey(key_enc.clone().try_into()?)])
+                    .build();
+                Ok::<_, DidPeerError>(
+                    Service::builder(
+                        Uri::new("#0")?,
+                        service_endpoint.clone(),
+                        ExtraFieldsSov::DIDCommV1(extra),
+                    )
+                    .add_service_type(ServiceType::DIDCommV1.to_string())?
+                    .build(),
+                )
+            })
+            .transpose()?;
+
+        let did_document =
+            did_doc_from_keys(Did::default(), &key_ver, &key_enc, service.clone())?.build();
+        let (did, also_known_as) = if numalgo == Numalgo4::NUMALGO_CHAR {
+            let peer_did = PeerDid::<Numalgo4>::from_did_doc(did_document)?;
+            let short_form = peer_did.short_form()?.to_string();
+            (peer_did.into(), short_form)
+        } else {
+            let peer_did = PeerDid::<Numalgo2>::from_did_doc(did_document)?;
+            let numalgo3 = peer_did.to_numalgo3()?.to_string();
+            (peer_did.into(), numalgo3)
+        };
+        let did_document = did_doc_from_keys(did, &key_ver, &key_enc, service)?
+            .add_also_known_as(also_known_as.parse()?)
+            .build();
+        Ok((did_document, key_enc))
+    }
+}
+
+fn did_doc_from_keys(
+    did: Did,
+    key_ver: &Key,
+    key_enc: &Key,
+    service: Option<Service<ExtraFieldsSov>>,
+) -> Result<DidDocumentBuilder<ExtraFieldsSov>, DidPeerError> {
+    let vm_ver = VerificationMethod::builder(
+        DidUrl::from_fragment(key_ver.short_prefixless_fingerprint())?,
+        did.clone(),
+        VerificationMethodType::Ed25519VerificationKey2020,
+    )
+    .add_public_key_base58(key_ver.base58())
+    .build();
+    let vm_ka = VerificationMethod::builder(
+        DidUrl::from_fragment(key_enc.short_prefixless_fingerprint())?,
+        did.clone(),
+        VerificationMethodType::X25519KeyAgreementKey2020,
+    )
+    .add_public_key_base58(key_enc.base58())
+    .build();
+    let mut builder = DidDocument::builder(did)
+        .add_verification_method(vm_ver)
+        .add_key_agreement(vm_ka);
+    if let Some(service) = service {
+        builder = builder.add_service(service);
+    }
+    Ok(builder)
+}
+
+#[async_trait]
+impl DidRegistrar for PeerDidRegistrar {
+    type ExtraFieldsService = ExtraFieldsSov;
+    type CreateOptions = PeerDidCreateOptions;
+    type UpdateOptions = ();
+    type DeactivateOptions = ();
+
+    async fn create(
+        &self,
+        wallet: &impl BaseWallet,
+        options: &DidRegistrationOptions<Self::CreateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError> {
+        let (did_document, _) = self.create_did_document(wallet, options).await?;
+        Ok(DidRegistrationOutput::builder(did_document.id().clone())
+            .did_document(did_document)
+            .build())
+    }
+
+    async fn update(
+        &self,
+        _wallet: &impl BaseWallet,
+        _did: &Did,
+        _options: &DidRegistrationOptions<Self::UpdateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError> {
+        Err(Box::new(DidPeerError::UnsupportedOperation(
+            "update".to_string(),
+        )))
+    }
+
+    async fn deactivate(
+        &self,
+        _wallet: &impl BaseWallet,
+        _did: &Did,
+        _options: &DidRegistrationOptions<Self::DeactivateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError> {
+        Err(Box::new(DidPeerError::UnsupportedOperation(
+            "deactivate".to_string(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use did_doc::schema::verification_method::VerificationMethodKind;
+    use did_resolver::traits::resolvable::{
+        resolution_options::DidResolutionOptions, DidResolvable,
+    };
+    use test_utils::{constants::TRUSTEE_SEED, devsetup::dev_build_featured_wallet};
+
+    use super::*;
+    use crate::resolver::{options::ExtraFieldsOptions, PeerDidResolver};
+
+    fn recipient_keys(did_document: &DidDocument<ExtraFieldsSov>) -> Vec<String> {
+        match did_document.service()[0].extra() {
+            ExtraFieldsSov::DIDCommV1(extra) => extra
+                .recipient_keys()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            _ => panic!("expected a DIDComm v1 service"),
+        }
+    }
+
+    fn key_agreement_key(did_document: &DidDocument<ExtraFieldsSov>) -> Key {
+        match &did_document.key_agreement()[0] {
+            VerificationMethodKind::Resolved(vm) => vm.public_key().unwrap(),
+            VerificationMethodKind::Resolvable(did_url) => did_document
+                .dereference_key(did_url)
+                .unwrap()
+                .public_key()
+                .unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_and_resolve() {
+        let (_, wallet) = dev_build_featured_wallet(TRUSTEE_SEED).await;
+        let options = DidRegistrationOptions::new(
+            PeerDidCreateOptions::new()
+                .set_service_endpoint("https://example.com/endpoint".parse().unwrap()),
+        );
+
+        let (did_document, key_enc) = PeerDidRegistrar::new()
+            .create_did_document(&wallet, &options)
+            .await
+            .unwrap();
+
+        assert!(did_document.id().did().starts_with("did:peer:2"));
+        assert_eq!(key_enc, key_agreement_key(&did_document));
+        let did_key: did_key::DidKey = key_enc.clone().try_into().unwrap();
+        assert_eq!(vec![did_key.to_string()], recipient_keys(&did_document));
+
+        let resolution_output = PeerDidResolver::new()
+            .resolve(
+                did_document.id(),
+                &DidResolutionOptions::new(ExtraFieldsOptions::new()),
+            )
+            .await
+            .unwrap();
+        let resolved = resolution_output.did_document();
+        assert_eq!(key_enc.base58(), key_agreement_key(resolved).base58());
+        assert_eq!(did_document.also_known_as(), resolved.also_known_as());
+        assert_eq!(
+            did_document.service()[0].service_endpoint(),
+            resolved.service()[0].service_endpoint()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_numalgo4() {
+        let (_, wallet) = dev_build_featured_wallet(TRUSTEE_SEED).await;
+        let options = DidRegistrationOptions::new(
+            PeerDidCreateOptions::new()
+                .set_service_endpoint("https://example.com/endpoint".parse().unwrap())
+                .set_numalgo('4'),
+        );
+
+        let output = PeerDidRegistrar::new()
+            .create(&wallet, &options)
+            .await
+            .unwrap();
+
+        let peer_did = PeerDid::<Numalgo4>::parse(output.did().to_owned()).unwrap();
+        assert!(peer_did.is_long_form());
+        let did_document = output.did_document().unwrap();
+        assert_eq!(output.did(), did_document.id());
+        assert_eq!(
+            vec![peer_did.short_form().unwrap().to_string()],
+            did_document
+                .also_known_as()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+        let did_key: did_key::DidKey = key_agreement_key(did_document).try_into().unwrap();
+        assert_eq!(vec![did_key.to_string()], recipient_keys(did_document));
+    }
+
+    #[tokio::test]
+    async fn test_create_numalgo1_unsupported() {
+        let (_, wallet) = dev_build_featured_wallet(TRUSTEE_SEED).await;
+        let options = DidRegistrationOptions::new(PeerDidCreateOptions::new().set_numalgo('1'));
+
+        let err = PeerDidRegistrar::new()
+            .create(&wallet, &options)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<DidPeerError>(),
+            Some(DidPeerError::UnsupportedOperation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_update_unsupported() {
+        let (_, wallet) = dev_build_featured_wallet(TRUSTEE_SEED).await;
+        let did =
+            Did::parse("did:peer:2.Ez6LSbysY2xFMRpGMhb7tFTLMpeuPRaqaWM1yECx2AtzE3KCc".to_string())
+                .unwrap();
+
+        let err = PeerDidRegistrar::new()
+            .update(&wallet, &did, &DidRegistrationOptions::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<DidPeerError>(),
+            Some(DidPeerError::UnsupportedOperation(_))
+        ));
+    }
+}
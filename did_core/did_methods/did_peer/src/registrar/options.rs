@@ -0,0 +1,50 @@
+use did_doc::schema::types::url::Url;
+use serde::Deserialize;
+
+use crate::peer_did::numalgos::{numalgo2::Numalgo2, Numalgo};
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerDidCreateOptions {
+    /// DIDComm endpoint, encoded into the DID as its service.
+    #[serde(default)]
+    service_endpoint: Option<Url>,
+    #[serde(default)]
+    routing_keys: Vec<String>,
+    /// Numalgo of the created DID, 2 or 4, numalgo 2 unless set.
+    #[serde(default)]
+    numalgo: Option<char>,
+}
+
+impl PeerDidCreateOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_service_endpoint(mut self, service_endpoint: Url) -> Self {
+        self.service_endpoint = Some(service_endpoint);
+        self
+    }
+
+    pub fn set_routing_keys(mut self, routing_keys: Vec<String>) -> Self {
+        self.routing_keys = routing_keys;
+        self
+    }
+
+    pub fn set_numalgo(mut self, numalgo: char) -> Self {
+        self.numalgo = Some(numalgo);
+        self
+    }
+
+    pub fn service_endpoint(&self) -> Option<&Url> {
+        self.service_endpoint.as_ref()
+    }
+
+    pub fn routing_keys(&self) -> &[String] {
+        &self.routing_keys
+    }
+
+    pub fn numalgo(&self) -> char {
+        self.numalgo.unwrap_or(Numalgo2::NUMALGO_CHAR)
+    }
+}
@@ -3,9 +3,10 @@ use did_doc_sov::extra_fields::ExtraFieldsSov;
 use did_parser::Did;
 use did_resolver::{
     error::GenericError,
+    shared_types::media_type::MediaType,
     traits::resolvable::{
-        resolution_metadata::DidResolutionMetadata, resolution_options::DidResolutionOptions,
-        resolution_output::DidResolutionOutput, DidResolvable,
+        resolution_options::DidResolutionOptions, resolution_output::DidResolutionOutput,
+        DidResolvable,
     },
 };
 
@@ -43,12 +44,9 @@ impl DidResolvable for PeerDidResolver {
                     resolve_numalgo2(peer_did.did(), options.extra().public_key_encoding())?
                         .add_also_known_as(peer_did.to_numalgo3()?.to_string().parse()?)
                         .build();
-                let resolution_metadata = DidResolutionMetadata::builder()
-                    .content_type("application/did+json".to_string())
-                    .build();
-                let builder = DidResolutionOutput::builder(did_doc)
-                    .did_resolution_metadata(resolution_metadata);
-                Ok(builder.build())
+                Ok(DidResolutionOutput::builder(did_doc)
+                    .build()
+                    .into_representation(options.accept().unwrap_or(&MediaType::DidJson)))
             }
             n => Err(Box::new(DidPeerError::UnsupportedNumalgo(n.numalgo()))),
         }
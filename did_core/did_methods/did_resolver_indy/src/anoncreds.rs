@@ -0,0 +1,177 @@
+use std::fmt::{self, Display, Formatter};
+
+use did_resolver::did_parser::Did;
+
+use crate::{error::DidIndyError, resolution::parse_did_indy};
+
+const ANONCREDS_PATH: &str = "anoncreds/v0";
+
+/// AnonCreds object referenced by a did:indy object identifier.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IndyAnoncredsObject {
+    Schema {
+        name: String,
+        version: String,
+    },
+    CredentialDefinition {
+        schema_seq_no: u32,
+        tag: String,
+    },
+    RevocationRegistryDefinition {
+        schema_seq_no: u32,
+        cred_def_tag: String,
+        tag: String,
+    },
+    RevocationRegistryEntry {
+        schema_seq_no: u32,
+        cred_def_tag: String,
+        tag: String,
+    },
+}
+
+/// did:indy style AnonCreds object identifier, e.g.
+/// `did:indy:sovrin:F72i3Y3Q4i466efjYJYCHM/anoncreds/v0/SCHEMA/npdb/4.3.4`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndyAnoncredsObjectId {
+    pub namespace: String,
+    /// Unqualified DID of the object's author.
+    pub nsid: String,
+    pub object: IndyAnoncredsObject,
+}
+
+impl IndyAnoncredsObjectId {
+    pub fn parse(object_id: &str) -> Result<Self, DidIndyError> {
+        let invalid = || DidIndyError::InvalidObjectId(object_id.to_string());
+
+        let (did, path) = object_id.split_once('/').ok_or_else(invalid)?;
+        let did = Did::parse(did.to_string())?;
+        let (namespace, nsid) = parse_did_indy(&did)?;
+        let path = path
+            .strip_prefix(ANONCREDS_PATH)
+            .and_then(|path| path.strip_prefix('/'))
+            .ok_or_else(invalid)?;
+        let segments: Vec<&str> = path.split('/').collect();
+
+        let seq_no = |value: &str| value.parse::<u32>().map_err(|_| invalid());
+        let object = match segments.as_slice() {
+            ["SCHEMA", name, version] => IndyAnoncredsObject::Schema {
+                name: name.to_string(),
+                version: version.to_string(),
+            },
+            ["CLAIM_DEF", schema_seq_no, tag] => IndyAnoncredsObject::CredentialDefinition {
+                schema_seq_no: seq_no(schema_seq_no)?,
+                tag: tag.to_string(),
+            },
+            ["REV_REG_DEF", schema_seq_no, cred_def_tag, tag] => {
+                IndyAnoncredsObject::RevocationRegistryDefinition {
+                    schema_seq_no: seq_no(schema_seq_no)?,
+                    cred_def_tag: cred_def_tag.to_string(),
+                    tag: tag.to_string(),
+                }
+            }
+            ["REV_REG_ENTRY", schema_seq_no, cred_def_tag, tag] => {
+                IndyAnoncredsObject::RevocationRegistryEntry {
+                    schema_seq_no: seq_no(schema_seq_no)?,
+                    cred_def_tag: cred_def_tag.to_string(),
+                    tag: tag.to_string(),
+                }
+            }
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self {
+            namespace,
+            nsid,
+            object,
+        })
+    }
+
+    /// Legacy Indy identifier of the object, as used in ledger requests. Revocation registry
+    /// entries map to the identifier of their registry definition.
+    pub fn legacy_id(&self) -> String {
+        let nsid = &self.nsid;
+        match &self.object {
+            IndyAnoncredsObject::Schema { name, version } => format!("{nsid}:2:{name}:{version}"),
+            IndyAnoncredsObject::CredentialDefinition { schema_seq_no, tag } => {
+                format!("{nsid}:3:CL:{schema_seq_no}:{tag}")
+            }
+            IndyAnoncredsObject::RevocationRegistryDefinition {
+                schema_seq_no,
+                cred_def_tag,
+                tag,
+            }
+            | IndyAnoncredsObject::RevocationRegistryEntry {
+                schema_seq_no,
+                cred_def_tag,
+                tag,
+            } => format!("{nsid}:4:{nsid}:3:CL:{schema_seq_no}:{cred_def_tag}:CL_ACCUM:{tag}"),
+        }
+    }
+}
+
+impl Display for IndyAnoncredsObjectId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "did:indy:{}:{}/{ANONCREDS_PATH}/",
+            self.namespace, self.nsid
+        )?;
+        match &self.object {
+            IndyAnoncredsObject::Schema { name, version } => write!(f, "SCHEMA/{name}/{version}"),
+            IndyAnoncredsObject::CredentialDefinition { schema_seq_no, tag } => {
+                write!(f, "CLAIM_DEF/{schema_seq_no}/{tag}")
+            }
+            IndyAnoncredsObject::RevocationRegistryDefinition {
+                schema_seq_no,
+                cred_def_tag,
+                tag,
+            } => write!(f, "REV_REG_DEF/{schema_seq_no}/{cred_def_tag}/{tag}"),
+            IndyAnoncredsObject::RevocationRegistryEntry {
+                schema_seq_no,
+                cred_def_tag,
+                tag,
+            } => write!(f, "REV_REG_ENTRY/{schema_seq_no}/{cred_def_tag}/{tag}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_schema_id() {
+        let id = "did:indy:sovrin:F72i3Y3Q4i466efjYJYCHM/anoncreds/v0/SCHEMA/npdb/4.3.4";
+        let object_id = IndyAnoncredsObjectId::parse(id).unwrap();
+
+        assert_eq!("sovrin", object_id.namespace);
+        assert_eq!("F72i3Y3Q4i466efjYJYCHM:2:npdb:4.3.4", object_id.legacy_id());
+        assert_eq!(id, object_id.to_string());
+    }
+
+    #[test]
+    fn test_parse_rev_reg_def_id() {
+        let id = "did:indy:sovrin:staging:5nDyJVP1NrcPAttP3xwMB9/anoncreds/v0/REV_REG_DEF/56495/\
+                  npdb/TAG1";
+        let object_id = IndyAnoncredsObjectId::parse(id).unwrap();
+
+        assert_eq!("sovrin:staging", object_id.namespace);
+        assert_eq!(
+            "5nDyJVP1NrcPAttP3xwMB9:4:5nDyJVP1NrcPAttP3xwMB9:3:CL:56495:npdb:CL_ACCUM:TAG1",
+            object_id.legacy_id()
+        );
+        assert_eq!(id, object_id.to_string());
+    }
+
+    #[test]
+    fn test_parse_invalid_object_id() {
+        for id in [
+            "did:indy:sovrin:F72i3Y3Q4i466efjYJYCHM",
+            "did:indy:sovrin:F72i3Y3Q4i466efjYJYCHM/anoncreds/v0/SCHEMA/npdb",
+            "did:indy:sovrin:F72i3Y3Q4i466efjYJYCHM/anoncreds/v0/CLAIM_DEF/abc/npdb",
+            "did:sov:F72i3Y3Q4i466efjYJYCHM/anoncreds/v0/SCHEMA/npdb/4.3.4",
+        ] {
+            assert!(IndyAnoncredsObjectId::parse(id).is_err(), "{id}");
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use did_resolver::{
+    did_doc::error::DidDocumentBuilderError,
+    did_parser,
+    traits::resolvable::{
+        resolution_error::DidResolutionError, resolution_metadata::DidResolutionMetadata,
+    },
+};
+use did_resolver_sov::error::DidSovError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum DidIndyError {
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("DID method not supported: {0}")]
+    MethodNotSupported(String),
+    #[error("Representation not supported: {0}")]
+    RepresentationNotSupported(String),
+    #[error("Invalid DID: {0}")]
+    InvalidDid(String),
+    #[error("No ledger configured for namespace: {0}")]
+    NamespaceNotConfigured(String),
+    #[error("Invalid AnonCreds object identifier: {0}")]
+    InvalidObjectId(String),
+    #[error("Ledger response parsing error: {0}")]
+    LedgerResponseParsingError(String),
+    #[error("DID parsing error: {0}")]
+    DidParsingError(#[from] did_parser::ParseError),
+    #[error("Serde error: {0}")]
+    SerdeError(#[from] serde_json::Error),
+    #[error("DID Document Builder Error: {0}")]
+    DidDocumentBuilderError(#[from] DidDocumentBuilderError),
+    #[error("Ledger error: {0}")]
+    LedgerError(#[from] DidSovError),
+}
+
+impl From<&DidIndyError> for DidResolutionError {
+    fn from(err: &DidIndyError) -> Self {
+        match err {
+            DidIndyError::NotFound(_) | DidIndyError::NamespaceNotConfigured(_) => {
+                DidResolutionError::NotFound
+            }
+            DidIndyError::MethodNotSupported(_) => DidResolutionError::MethodNotSupported,
+            DidIndyError::RepresentationNotSupported(_) => {
+                DidResolutionError::RepresentationNotSupported
+            }
+            DidIndyError::InvalidDid(_)
+            | DidIndyError::InvalidObjectId(_)
+            | DidIndyError::DidParsingError(_) => DidResolutionError::InvalidDid,
+            DidIndyError::LedgerError(err) => err.into(),
+            _ => DidResolutionError::InternalError,
+        }
+    }
+}
+
+impl From<&DidIndyError> for DidResolutionMetadata {
+    fn from(err: &DidIndyError) -> Self {
+        DidResolutionMetadata::builder().error(err.into()).build()
+    }
+}
@@ -0,0 +1,5 @@
+mod resolver;
+mod utils;
+
+pub use resolver::DidIndyResolver;
+pub(crate) use utils::parse_did_indy;
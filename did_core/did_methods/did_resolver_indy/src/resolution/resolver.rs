@@ -0,0 +1,204 @@
+use std::{borrow::Borrow, collections::HashMap, marker::PhantomData};
+
+use async_trait::async_trait;
+use did_doc_sov::extra_fields::ExtraFieldsSov;
+use did_resolver::{
+    did_parser::Did,
+    error::GenericError,
+    shared_types::media_type::MediaType,
+    traits::resolvable::{
+        resolution_options::DidResolutionOptions, resolution_output::DidResolutionOutput,
+        DidResolvable,
+    },
+};
+use did_resolver_sov::reader::AttrReader;
+
+use super::utils::{ledger_response_to_ddo, parse_did_indy};
+use crate::{anoncreds::IndyAnoncredsObjectId, error::DidIndyError};
+
+/// Resolver of did:indy DIDs, reading each DID from the ledger of its namespace. Ledgers are
+/// configured per namespace, e.g. `sovrin` or `sovrin:staging`.
+pub struct DidIndyResolver<T, A>
+where
+    T: Borrow<A> + Sync + Send,
+    A: AttrReader,
+{
+    ledgers: HashMap<String, T>,
+    _marker: PhantomData<A>,
+}
+
+impl<T, A> DidIndyResolver<T, A>
+where
+    T: Borrow<A> + Sync + Send,
+    A: AttrReader,
+{
+    pub fn new(ledgers: HashMap<String, T>) -> Self {
+        DidIndyResolver {
+            ledgers,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn add_ledger(mut self, namespace: &str, ledger: T) -> Self {
+        self.ledgers.insert(namespace.to_string(), ledger);
+        self
+    }
+
+    pub fn namespaces(&self) -> impl Iterator<Item = &str> {
+        self.ledgers.keys().map(String::as_str)
+    }
+
+    /// Returns the ledger configured for the namespace.
+    pub fn ledger(&self, namespace: &str) -> Result<&A, DidIndyError> {
+        self.ledgers
+            .get(namespace)
+            .map(Borrow::borrow)
+            .ok_or_else(|| DidIndyError::NamespaceNotConfigured(namespace.to_string()))
+    }
+
+    /// Parses a did:indy AnonCreds object identifier, returning the ledger of its namespace
+    /// along with the object's legacy identifier to fetch it with.
+    pub fn route_anoncreds_object(
+        &self,
+        object_id: &str,
+    ) -> Result<(&A, IndyAnoncredsObjectId), DidIndyError> {
+        let object_id = IndyAnoncredsObjectId::parse(object_id)?;
+        Ok((self.ledger(&object_id.namespace)?, object_id))
+    }
+}
+
+#[async_trait]
+impl<T, A> DidResolvable for DidIndyResolver<T, A>
+where
+    T: Borrow<A> + Sync + Send,
+    A: AttrReader,
+{
+    type ExtraFieldsService = ExtraFieldsSov;
+    type ExtraFieldsOptions = ();
+
+    async fn resolve(
+        &self,
+        parsed_did: &Did,
+        options: &DidResolutionOptions<()>,
+    ) -> Result<DidResolutionOutput<Self::ExtraFieldsService>, GenericError> {
+        let media_type = options.accept().unwrap_or(&MediaType::DidJson);
+        if !matches!(media_type, MediaType::DidJson | MediaType::DidLdJson) {
+            return Err(Box::new(DidIndyError::RepresentationNotSupported(
+                media_type.to_string(),
+            )));
+        }
+        let (namespace, nsid) = parse_did_indy(parsed_did)?;
+        let ledger = self.ledger(&namespace)?;
+        let unqualified_did = Did::parse(nsid.clone())?;
+
+        let nym_response = ledger
+            .get_nym(&unqualified_did)
+            .await
+            .map_err(DidIndyError::from)?;
+        // the endpoint ATTRIB is only used by DIDs without diddocContent, and is optional
+        let endpoint_response = ledger.get_attr(&unqualified_did, "endpoint").await.ok();
+
+        Ok(ledger_response_to_ddo(
+            parsed_did,
+            &nsid,
+            &nym_response,
+            endpoint_response.as_deref(),
+        )?
+        .into_representation(media_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use did_resolver_sov::error::DidSovError;
+    use serde_json::json;
+
+    use super::*;
+
+    const NSID: &str = "WRfXPg8dantKVubE3HX8pw";
+
+    struct TestLedger {
+        verkey: &'static str,
+    }
+
+    #[async_trait]
+    impl AttrReader for TestLedger {
+        async fn get_attr(
+            &self,
+            _target_did: &Did,
+            _attr_name: &str,
+        ) -> Result<String, DidSovError> {
+            Ok(json!({ "result": { "data": null } }).to_string())
+        }
+
+        async fn get_nym(&self, did: &Did) -> Result<String, DidSovError> {
+            assert_eq!(NSID, did.did());
+            Ok(
+                json!({ "result": { "data": json!({ "verkey": self.verkey }).to_string() } })
+                    .to_string(),
+            )
+        }
+
+        async fn get_attr_version(
+            &self,
+            target_did: &Did,
+            attr_name: &str,
+            _seq_no: Option<i32>,
+            _timestamp: Option<u64>,
+        ) -> Result<String, DidSovError> {
+            self.get_attr(target_did, attr_name).await
+        }
+
+        async fn get_nym_version(
+            &self,
+            did: &Did,
+            _seq_no: Option<i32>,
+            _timestamp: Option<u64>,
+        ) -> Result<String, DidSovError> {
+            self.get_nym(did).await
+        }
+
+        async fn get_ledger_txn(&self, seq_no: i32) -> Result<String, DidSovError> {
+            Err(DidSovError::NotFound(format!("transaction {seq_no}")))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_routes_by_namespace() {
+        let resolver = DidIndyResolver::<TestLedger, TestLedger>::new(HashMap::new())
+            .add_ledger(
+                "sovrin",
+                TestLedger {
+                    verkey: "H3C2AVvLMv6gmMNam3uVAjZpfkcJCwDwnZn6z3wXmqPV",
+                },
+            )
+            .add_ledger(
+                "sovrin:staging",
+                TestLedger {
+                    verkey: "8HH5gYEeNc3z7PYXmd54d4x6qAfCNrqQqEB3nS7Zfu7K",
+                },
+            );
+
+        let did = Did::parse(format!("did:indy:sovrin:staging:{NSID}")).unwrap();
+        let output = resolver
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(&did, output.did_document().id());
+        assert_eq!(
+            json!("8HH5gYEeNc3z7PYXmd54d4x6qAfCNrqQqEB3nS7Zfu7K"),
+            serde_json::to_value(output.did_document()).unwrap()["verificationMethod"][0]
+                ["publicKeyBase58"]
+        );
+
+        let did = Did::parse(format!("did:indy:bcovrin:{NSID}")).unwrap();
+        let err = resolver
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DidIndyError>(),
+            Some(DidIndyError::NamespaceNotConfigured(_))
+        ));
+    }
+}
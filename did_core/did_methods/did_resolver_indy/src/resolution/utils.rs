@@ -0,0 +1,315 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use did_doc_sov::extra_fields::ExtraFieldsSov;
+use did_resolver::{
+    did_doc::schema::did_doc::DidDocument,
+    did_parser::Did,
+    shared_types::did_document_metadata::DidDocumentMetadata,
+    traits::resolvable::{
+        resolution_metadata::DidResolutionMetadata, resolution_output::DidResolutionOutput,
+    },
+};
+use serde_json::{json, Map, Value};
+
+use crate::error::DidIndyError;
+
+const DID_CORE_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+const ED25519_2018_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2018/v1";
+
+const BASE58_CHARS: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Splits a did:indy DID into the namespace of the ledger and the namespace specific
+/// identifier, e.g. `sovrin:staging` and `WRfXPg8dantKVubE3HX8pw`.
+pub(crate) fn parse_did_indy(did: &Did) -> Result<(String, String), DidIndyError> {
+    let method = did
+        .method()
+        .ok_or_else(|| DidIndyError::InvalidDid("Attempted to resolve unqualified did".into()))?;
+    if method != "indy" {
+        return Err(DidIndyError::MethodNotSupported(method.to_string()));
+    }
+    let invalid = || DidIndyError::InvalidDid(did.to_string());
+    let did_indy = did_parser_nom::Did::parse(did.did().to_string()).map_err(|_| invalid())?;
+    let namespace = did_indy.namespace().ok_or_else(invalid)?;
+    let nsid = did_indy.id();
+    let valid_namespace = namespace.split(':').count() <= 2
+        && namespace.split(':').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        });
+    let valid_nsid =
+        (21..=22).contains(&nsid.len()) && nsid.chars().all(|c| BASE58_CHARS.contains(c));
+    if !valid_namespace || !valid_nsid {
+        return Err(invalid());
+    }
+    Ok((namespace.to_string(), nsid.to_string()))
+}
+
+/// Expands an abbreviated verkey (`~` followed by its last 16 bytes), whose first 16 bytes are
+/// given by the namespace specific identifier.
+pub(crate) fn expand_verkey(nsid: &str, verkey: &str) -> Result<String, DidIndyError> {
+    let Some(abbreviated) = verkey.strip_prefix('~') else {
+        return Ok(verkey.to_string());
+    };
+    let decode = |value: &str| {
+        bs58::decode(value).into_vec().map_err(|err| {
+            DidIndyError::LedgerResponseParsingError(format!("Invalid base58 value: {err}"))
+        })
+    };
+    let mut key = decode(nsid)?;
+    key.extend(decode(abbreviated)?);
+    Ok(bs58::encode(key).into_string())
+}
+
+/// Returns the data of a GET_NYM or GET_ATTRIB ledger response, which is `None` if the ledger
+/// has no such transaction.
+pub(crate) fn get_data_from_response(resp: &str) -> Result<Option<Value>, DidIndyError> {
+    let resp: Value = serde_json::from_str(resp)?;
+    match &resp["result"]["data"] {
+        Value::String(data) => Ok(Some(serde_json::from_str(data)?)),
+        Value::Null => Ok(None),
+        data => Err(DidIndyError::LedgerResponseParsingError(format!(
+            "Unexpected data format in ledger response: {data}"
+        ))),
+    }
+}
+
+fn get_txn_time_from_response(resp: &str) -> Result<Option<i64>, DidIndyError> {
+    let resp: Value = serde_json::from_str(resp)?;
+    Ok(resp["result"]["txnTime"].as_i64())
+}
+
+fn unix_to_datetime(posix_timestamp: i64) -> Option<DateTime<Utc>> {
+    NaiveDateTime::from_timestamp_opt(posix_timestamp, 0)
+        .map(|date_time| DateTime::<Utc>::from_naive_utc_and_offset(date_time, Utc))
+}
+
+/// DID document assembled from the NYM of the DID, as per the did:indy method specification.
+/// The `diddocContent` of the NYM is merged into the base document; legacy NYMs without it get
+/// a DIDComm service built from their `endpoint` ATTRIB, if any.
+pub(crate) fn nym_to_did_doc(
+    did: &Did,
+    nsid: &str,
+    nym_data: &Value,
+    endpoint_data: Option<&Value>,
+) -> Result<Value, DidIndyError> {
+    let verkey = nym_data["verkey"].as_str().ok_or_else(|| {
+        DidIndyError::LedgerResponseParsingError("Failed to parse verkey from nym data".into())
+    })?;
+    let verkey_id = format!("{did}#verkey");
+
+    let mut ddo = json!({
+        "@context": [DID_CORE_CONTEXT, ED25519_2018_CONTEXT],
+        "id": did.to_string(),
+        "verificationMethod": [{
+            "id": verkey_id,
+            "type": "Ed25519VerificationKey2018",
+            "controller": did.to_string(),
+            "publicKeyBase58": expand_verkey(nsid, verkey)?,
+        }],
+        "authentication": [verkey_id],
+    });
+
+    let diddoc_content = match &nym_data["diddocContent"] {
+        Value::String(content) => Some(serde_json::from_str(content)?),
+        Value::Object(content) => Some(Value::Object(content.clone())),
+        _ => None,
+    };
+
+    match (diddoc_content, endpoint_data) {
+        (Some(Value::Object(content)), _) => merge_diddoc_content(&mut ddo, content)?,
+        (Some(_), _) => {
+            return Err(DidIndyError::LedgerResponseParsingError(
+                "diddocContent is not a JSON object".into(),
+            ))
+        }
+        (None, Some(endpoint_data)) => {
+            if let Some(endpoint) = endpoint_data["endpoint"]["endpoint"].as_str() {
+                ddo["service"] = json!([{
+                    "id": format!("{did}#did-communication"),
+                    "type": "did-communication",
+                    "serviceEndpoint": endpoint,
+                    "recipientKeys": [verkey_id],
+                    "routingKeys": endpoint_data["endpoint"]["routingKeys"]
+                        .as_array()
+                        .cloned()
+                        .unwrap_or_default(),
+                    "priority": 0,
+                    "accept": ["didcomm/aip2;env=rfc19"],
+                }]);
+            }
+        }
+        (None, None) => {}
+    }
+
+    Ok(ddo)
+}
+
+fn merge_diddoc_content(ddo: &mut Value, content: Map<String, Value>) -> Result<(), DidIndyError> {
+    for (key, value) in content {
+        if key == "id" {
+            return Err(DidIndyError::LedgerResponseParsingError(
+                "diddocContent must not override the DID document id".into(),
+            ));
+        }
+        let value = match value {
+            Value::String(context) if key == "@context" => json!([context]),
+            value => value,
+        };
+        match (&mut ddo[&key], value) {
+            (Value::Array(existing), Value::Array(items)) => {
+                for item in items {
+                    if !existing.contains(&item) {
+                        existing.push(item);
+                    }
+                }
+            }
+            (existing, value) => *existing = value,
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn ledger_response_to_ddo(
+    did: &Did,
+    nsid: &str,
+    nym_resp: &str,
+    endpoint_resp: Option<&str>,
+) -> Result<DidResolutionOutput<ExtraFieldsSov>, DidIndyError> {
+    let nym_data = get_data_from_response(nym_resp)?
+        .ok_or_else(|| DidIndyError::NotFound(format!("DID {did} not found")))?;
+    let endpoint_data = endpoint_resp
+        .map(get_data_from_response)
+        .transpose()?
+        .flatten();
+
+    let ddo = nym_to_did_doc(did, nsid, &nym_data, endpoint_data.as_ref())?;
+    let ddo: DidDocument<ExtraFieldsSov> = serde_json::from_value(ddo)?;
+
+    let ddo_metadata = {
+        let mut metadata_builder = DidDocumentMetadata::builder().deactivated(false);
+        if let Some(datetime) = get_txn_time_from_response(nym_resp)?.and_then(unix_to_datetime) {
+            metadata_builder = metadata_builder.updated(datetime);
+        };
+        metadata_builder.build()
+    };
+
+    let resolution_metadata = DidResolutionMetadata::builder()
+        .content_type("application/did+json".to_string())
+        .build();
+
+    Ok(DidResolutionOutput::builder(ddo)
+        .did_document_metadata(ddo_metadata)
+        .did_resolution_metadata(resolution_metadata)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &str = "did:indy:sovrin:staging:WRfXPg8dantKVubE3HX8pw";
+    const VERKEY: &str = "H3C2AVvLMv6gmMNam3uVAjZpfkcJCwDwnZn6z3wXmqPV";
+
+    #[test]
+    fn test_parse_did_indy() {
+        let did = Did::parse(DID.to_string()).unwrap();
+        assert_eq!(
+            (
+                "sovrin:staging".to_string(),
+                "WRfXPg8dantKVubE3HX8pw".to_string()
+            ),
+            parse_did_indy(&did).unwrap()
+        );
+
+        let did = Did::parse("did:sov:WRfXPg8dantKVubE3HX8pw".to_string()).unwrap();
+        assert!(matches!(
+            parse_did_indy(&did),
+            Err(DidIndyError::MethodNotSupported(_))
+        ));
+
+        let did = Did::parse("did:indy:Sovrin:WRfXPg8dantKVubE3HX8pw".to_string()).unwrap();
+        assert!(matches!(
+            parse_did_indy(&did),
+            Err(DidIndyError::InvalidDid(_))
+        ));
+    }
+
+    #[test]
+    fn test_expand_verkey() {
+        let nsid = bs58::encode([1u8; 16]).into_string();
+        let abbreviated = format!("~{}", bs58::encode([2u8; 16]).into_string());
+        let mut expected = vec![1u8; 16];
+        expected.extend([2u8; 16]);
+
+        assert_eq!(
+            bs58::encode(expected).into_string(),
+            expand_verkey(&nsid, &abbreviated).unwrap()
+        );
+        assert_eq!(VERKEY, expand_verkey(&nsid, VERKEY).unwrap());
+    }
+
+    #[test]
+    fn test_nym_to_did_doc_merges_diddoc_content() {
+        let did = Did::parse(DID.to_string()).unwrap();
+        let content = json!({
+            "@context": ["https://didcomm.org/messaging/contexts/v2"],
+            "service": [{
+                "id": format!("{DID}#didcomm-1"),
+                "type": "DIDCommMessaging",
+                "serviceEndpoint": "https://example.com"
+            }]
+        });
+        let nym_data = json!({ "verkey": VERKEY, "diddocContent": content.to_string() });
+
+        let ddo = nym_to_did_doc(&did, "WRfXPg8dantKVubE3HX8pw", &nym_data, None).unwrap();
+
+        assert_eq!(3, ddo["@context"].as_array().unwrap().len());
+        assert_eq!(content["service"], ddo["service"]);
+        assert_eq!(VERKEY, ddo["verificationMethod"][0]["publicKeyBase58"]);
+    }
+
+    #[test]
+    fn test_ledger_response_to_ddo_with_endpoint() {
+        let did = Did::parse(DID.to_string()).unwrap();
+        let nym_resp = json!({
+            "result": {
+                "data": json!({ "verkey": VERKEY }).to_string(),
+                "txnTime": 1629272938
+            }
+        })
+        .to_string();
+        let endpoint_resp = json!({
+            "result": {
+                "data": json!({ "endpoint": { "endpoint": "https://example.com" } }).to_string()
+            }
+        })
+        .to_string();
+
+        let output = ledger_response_to_ddo(
+            &did,
+            "WRfXPg8dantKVubE3HX8pw",
+            &nym_resp,
+            Some(&endpoint_resp),
+        )
+        .unwrap();
+
+        let ddo = output.did_document();
+        assert_eq!(DID, ddo.id().to_string());
+        assert_eq!(
+            "https://example.com/",
+            ddo.service()[0].service_endpoint().as_ref()
+        );
+        assert!(output.did_document_metadata().updated().is_some());
+    }
+
+    #[test]
+    fn test_ledger_response_to_ddo_not_found() {
+        let did = Did::parse(DID.to_string()).unwrap();
+        let nym_resp = json!({ "result": { "data": null } }).to_string();
+
+        let res = ledger_response_to_ddo(&did, "WRfXPg8dantKVubE3HX8pw", &nym_resp, None);
+
+        assert!(matches!(res, Err(DidIndyError::NotFound(_))));
+    }
+}
@@ -15,6 +15,8 @@ use self::parsing::ParsingErrorSource;
 pub enum DidSovError {
     #[error("Not found: {0}")]
     NotFound(String),
+    #[error("DID document version not found: {0}")]
+    VersionNotFound(String),
     #[error("DID method not supported: {0}")]
     MethodNotSupported(String),
     #[error("Representation not supported: {0}")]
@@ -29,6 +31,8 @@ pub enum DidSovError {
     DidDocumentBuilderError(#[from] DidDocumentBuilderError),
     #[error("Parsing error: {0}")]
     ParsingError(#[from] ParsingErrorSource),
+    #[error("Ledger request rejected: {0}")]
+    RequestRejected(String),
     #[error("Invalid configuration: {0}")]
     InvalidConfiguration(String),
     #[error(transparent)]
@@ -7,7 +7,9 @@ use super::DidSovError;
 impl From<&DidSovError> for DidResolutionError {
     fn from(err: &DidSovError) -> Self {
         match err {
-            DidSovError::NotFound(_) => DidResolutionError::NotFound,
+            DidSovError::NotFound(_) | DidSovError::VersionNotFound(_) => {
+                DidResolutionError::NotFound
+            }
             DidSovError::MethodNotSupported(_) => DidResolutionError::MethodNotSupported,
             _ => DidResolutionError::InternalError,
         }
@@ -3,5 +3,6 @@ pub extern crate did_resolver;
 pub mod dereferencing;
 pub mod error;
 pub mod reader;
+pub mod registration;
 pub mod resolution;
 pub mod service;
@@ -9,6 +9,22 @@ use crate::error::DidSovError;
 pub trait AttrReader: Send + Sync {
     async fn get_attr(&self, target_did: &Did, attr_name: &str) -> Result<String, DidSovError>;
     async fn get_nym(&self, did: &Did) -> Result<String, DidSovError>;
+    /// Reads the attribute as of the transaction with `seq_no`, or as of `timestamp`.
+    async fn get_attr_version(
+        &self,
+        target_did: &Did,
+        attr_name: &str,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> Result<String, DidSovError>;
+    /// Reads the NYM as of the transaction with `seq_no`, or as of `timestamp`.
+    async fn get_nym_version(
+        &self,
+        did: &Did,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> Result<String, DidSovError>;
+    async fn get_ledger_txn(&self, seq_no: i32) -> Result<String, DidSovError>;
 }
 
 #[async_trait]
@@ -27,4 +43,33 @@ where
             .await
             .map_err(|err| err.into())
     }
+
+    async fn get_attr_version(
+        &self,
+        target_did: &Did,
+        attr_name: &str,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> Result<String, DidSovError> {
+        IndyLedgerRead::get_attr_version(self, target_did, attr_name, seq_no, timestamp)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    async fn get_nym_version(
+        &self,
+        did: &Did,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> Result<String, DidSovError> {
+        IndyLedgerRead::get_nym_version(self, did, seq_no, timestamp)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    async fn get_ledger_txn(&self, seq_no: i32) -> Result<String, DidSovError> {
+        IndyLedgerRead::get_ledger_txn(self, seq_no, None)
+            .await
+            .map_err(|err| err.into())
+    }
 }
@@ -0,0 +1,5 @@
+mod options;
+mod registrar;
+
+pub use options::{DidSovCreateOptions, DidSovUpdateOptions};
+pub use registrar::DidSovRegistrar;
@@ -0,0 +1,74 @@
+use serde::Deserialize;
+
+use crate::service::EndpointDidSov;
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidSovCreateOptions {
+    /// Ledger role of the DID, e.g. `ENDORSER`.
+    #[serde(default)]
+    role: Option<String>,
+    /// Written as the `endpoint` ATTRIB of the DID.
+    #[serde(default)]
+    endpoint: Option<EndpointDidSov>,
+}
+
+impl DidSovCreateOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_role(mut self, role: String) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    pub fn set_endpoint(mut self, endpoint: EndpointDidSov) -> Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    pub fn role(&self) -> Option<&str> {
+        self.role.as_deref()
+    }
+
+    pub fn endpoint(&self) -> Option<&EndpointDidSov> {
+        self.endpoint.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidSovUpdateOptions {
+    /// Replaces the verkey of the DID with a new key, derived from the seed of the options if
+    /// given.
+    #[serde(default)]
+    rotate_key: bool,
+    /// Replaces the `endpoint` ATTRIB of the DID.
+    #[serde(default)]
+    endpoint: Option<EndpointDidSov>,
+}
+
+impl DidSovUpdateOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_rotate_key(mut self, rotate_key: bool) -> Self {
+        self.rotate_key = rotate_key;
+        self
+    }
+
+    pub fn set_endpoint(mut self, endpoint: EndpointDidSov) -> Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    pub fn rotate_key(&self) -> bool {
+        self.rotate_key
+    }
+
+    pub fn endpoint(&self) -> Option<&EndpointDidSov> {
+        self.endpoint.as_ref()
+    }
+}
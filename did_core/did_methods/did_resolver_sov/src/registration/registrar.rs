@@ -0,0 +1,200 @@
+use std::{borrow::Borrow, marker::PhantomData};
+
+use aries_vcx_core::{ledger::base_ledger::IndyLedgerWrite, wallet::base_wallet::BaseWallet};
+use async_trait::async_trait;
+use did_doc_sov::extra_fields::ExtraFieldsSov;
+use did_registrar::{
+    registration_options::DidRegistrationOptions, registration_output::DidRegistrationOutput,
+    DidRegistrar,
+};
+use did_resolver::{
+    did_parser::Did, error::GenericError, shared_types::did_document_metadata::DidDocumentMetadata,
+};
+use serde_json::{json, Value};
+
+use super::options::{DidSovCreateOptions, DidSovUpdateOptions};
+use crate::{
+    error::DidSovError,
+    resolution::utils::{did_sov_ddo, is_valid_sovrin_did_id},
+    service::EndpointDidSov,
+};
+
+/// Registrar of did:sov DIDs, writing their verkey as a NYM and their endpoint as an ATTRIB.
+/// New DIDs are written to the ledger by the submitter DID, which must be an endorser.
+pub struct DidSovRegistrar<T, L>
+where
+    T: Borrow<L> + Sync + Send,
+    L: IndyLedgerWrite,
+{
+    ledger: T,
+    submitter_did: Did,
+    _marker: PhantomData<L>,
+}
+
+impl<T, L> DidSovRegistrar<T, L>
+where
+    T: Borrow<L> + Sync + Send,
+    L: IndyLedgerWrite,
+{
+    pub fn new(ledger: T, submitter_did: Did) -> Self {
+        DidSovRegistrar {
+            ledger,
+            submitter_did,
+            _marker: PhantomData,
+        }
+    }
+
+    async fn write_endpoint(
+        &self,
+        wallet: &impl BaseWallet,
+        did: &Did,
+        endpoint: &EndpointDidSov,
+    ) -> Result<(), DidSovError> {
+        let attrib_json = json!({ "endpoint": endpoint }).to_string();
+        check_response(
+            &self
+                .ledger
+                .borrow()
+                .add_attr(wallet, did, &attrib_json)
+                .await?,
+        )
+    }
+}
+
+#[async_trait]
+impl<T, L> DidRegistrar for DidSovRegistrar<T, L>
+where
+    T: Borrow<L> + Sync + Send,
+    L: IndyLedgerWrite,
+{
+    type ExtraFieldsService = ExtraFieldsSov;
+    type CreateOptions = DidSovCreateOptions;
+    type UpdateOptions = DidSovUpdateOptions;
+    type DeactivateOptions = ();
+
+    async fn create(
+        &self,
+        wallet: &impl BaseWallet,
+        options: &DidRegistrationOptions<Self::CreateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError> {
+        let did_data = wallet
+            .create_and_store_my_did(options.seed(), None)
+            .await
+            .map_err(DidSovError::from)?;
+        let nym_did = Did::parse(did_data.did().to_string()).map_err(DidSovError::from)?;
+        check_response(
+            &self
+                .ledger
+                .borrow()
+                .publish_nym(
+                    wallet,
+                    &self.submitter_did,
+                    &nym_did,
+                    Some(did_data.verkey()),
+                    None,
+                    options.extra().role(),
+                )
+                .await
+                .map_err(DidSovError::from)?,
+        )?;
+        if let Some(endpoint) = options.extra().endpoint() {
+            self.write_endpoint(wallet, &nym_did, endpoint).await?;
+        }
+
+        let did = format!("did:sov:{}", did_data.did());
+        let ddo = did_sov_ddo(
+            &did,
+            did_data.verkey().base58(),
+            options.extra().endpoint().cloned(),
+        )?;
+        Ok(
+            DidRegistrationOutput::builder(Did::parse(did).map_err(DidSovError::from)?)
+                .did_document(ddo)
+                .did_document_metadata(DidDocumentMetadata::builder().deactivated(false).build())
+                .build(),
+        )
+    }
+
+    async fn update(
+        &self,
+        wallet: &impl BaseWallet,
+        did: &Did,
+        options: &DidRegistrationOptions<Self::UpdateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError> {
+        let nym_did = to_nym_did(did)?;
+        if options.extra().rotate_key() {
+            let new_key = wallet
+                .replace_did_key_start(nym_did.did(), options.seed())
+                .await
+                .map_err(DidSovError::from)?;
+            // the NYM is signed with the current key, which stays in use until it is replaced
+            check_response(
+                &self
+                    .ledger
+                    .borrow()
+                    .publish_nym(wallet, &nym_did, &nym_did, Some(&new_key), None, None)
+                    .await
+                    .map_err(DidSovError::from)?,
+            )?;
+            wallet
+                .replace_did_key_apply(nym_did.did())
+                .await
+                .map_err(DidSovError::from)?;
+        }
+        if let Some(endpoint) = options.extra().endpoint() {
+            self.write_endpoint(wallet, &nym_did, endpoint).await?;
+        }
+
+        Ok(DidRegistrationOutput::builder(did.to_owned())
+            .did_document_metadata(DidDocumentMetadata::builder().deactivated(false).build())
+            .build())
+    }
+
+    async fn deactivate(
+        &self,
+        wallet: &impl BaseWallet,
+        did: &Did,
+        _options: &DidRegistrationOptions<Self::DeactivateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError> {
+        let nym_did = to_nym_did(did)?;
+        check_response(
+            &self
+                .ledger
+                .borrow()
+                .deactivate_nym(wallet, &nym_did)
+                .await
+                .map_err(DidSovError::from)?,
+        )?;
+
+        Ok(DidRegistrationOutput::builder(did.to_owned())
+            .did_document_metadata(DidDocumentMetadata::builder().deactivated(true).build())
+            .build())
+    }
+}
+
+/// Unqualified DID identifying the NYM of a did:sov DID on the ledger.
+fn to_nym_did(did: &Did) -> Result<Did, DidSovError> {
+    match did.method() {
+        Some("sov") => {}
+        Some(method) => return Err(DidSovError::MethodNotSupported(method.to_string())),
+        None => {
+            return Err(DidSovError::InvalidDid(
+                "Attempted to register unqualified did".to_string(),
+            ))
+        }
+    }
+    if !is_valid_sovrin_did_id(did.id()) {
+        return Err(DidSovError::InvalidDid(did.id().to_string()));
+    }
+    Ok(Did::parse(did.id().to_string())?)
+}
+
+fn check_response(response: &str) -> Result<(), DidSovError> {
+    let response: Value = serde_json::from_str(response)?;
+    match response["op"].as_str() {
+        Some("REJECT") | Some("REQNACK") => Err(DidSovError::RequestRejected(
+            response["reason"].as_str().unwrap_or_default().to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
@@ -1,4 +1,5 @@
 mod resolver;
-mod utils;
+pub(crate) mod utils;
+mod version;
 
 pub use resolver::DidSovResolver;
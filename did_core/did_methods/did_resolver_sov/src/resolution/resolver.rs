@@ -11,13 +11,16 @@ use did_resolver::{
         DidResolvable,
     },
 };
-use serde_json::Value;
 
-use super::utils::{is_valid_sovrin_did_id, ledger_response_to_ddo};
-use crate::{
-    error::{parsing::ParsingErrorSource, DidSovError},
-    reader::AttrReader,
+use super::{
+    utils::{
+        get_data_from_response, get_txn_time_from_response, is_valid_sovrin_did_id, version_to_ddo,
+    },
+    version::{DidSovVersion, LedgerEntry, LedgerTxn},
 };
+use crate::{error::DidSovError, reader::AttrReader, service::EndpointDidSov};
+
+const ENDPOINT_ATTR: &str = "endpoint";
 
 pub struct DidSovResolver<T, A>
 where
@@ -58,12 +61,11 @@ where
         parsed_did: &Did,
         options: &DidResolutionOptions<()>,
     ) -> Result<DidResolutionOutput<Self::ExtraFieldsService>, GenericError> {
-        if let Some(accept) = options.accept() {
-            if accept != &MediaType::DidJson {
-                return Err(Box::new(DidSovError::RepresentationNotSupported(
-                    accept.to_string(),
-                )));
-            }
+        let media_type = options.accept().unwrap_or(&MediaType::DidJson);
+        if !matches!(media_type, MediaType::DidJson | MediaType::DidLdJson) {
+            return Err(Box::new(DidSovError::RepresentationNotSupported(
+                media_type.to_string(),
+            )));
         }
         let method = parsed_did.method().ok_or_else(|| {
             DidSovError::InvalidDid("Attempted to resolve unqualified did".to_string())
@@ -78,15 +80,62 @@ where
                 parsed_did.id().to_string(),
             )));
         }
-        let ledger_response = self
-            .ledger
-            .borrow()
-            .get_attr(parsed_did, "endpoint")
-            .await?;
-        let verkey = self.get_verkey(parsed_did).await?;
-        ledger_response_to_ddo(parsed_did.did(), &ledger_response, verkey)
-            .await
-            .map_err(|err| err.into())
+        let ledger = self.ledger.borrow();
+        let nym = LedgerEntry::verkey_from_response(&ledger.get_nym(parsed_did).await?)?;
+        let endpoint =
+            LedgerEntry::endpoint_from_response(&ledger.get_attr(parsed_did, ENDPOINT_ATTR).await?);
+
+        let (version, nym_then) = match (options.version_id(), options.version_time()) {
+            (Some(version_id), _) => {
+                let seq_no = version_id
+                    .parse()
+                    .map_err(|_| DidSovError::VersionNotFound(version_id.to_string()))?;
+                let txn = LedgerTxn::from_get_txn_response(
+                    &ledger.get_ledger_txn(seq_no).await?,
+                    parsed_did.id(),
+                )?;
+                let (nym_then, endpoint_then) =
+                    self.read_version(parsed_did, Some(seq_no), None).await?;
+                let version = DidSovVersion::at_txn(&txn, &nym_then, endpoint_then.as_ref());
+                (version, nym_then)
+            }
+            (None, Some(version_time)) => {
+                let timestamp = u64::try_from(version_time.timestamp())
+                    .map_err(|_| DidSovError::VersionNotFound(version_time.to_string()))?;
+                let (nym_then, endpoint_then) =
+                    self.read_version(parsed_did, None, Some(timestamp)).await?;
+                let version = DidSovVersion::latest(&nym_then, endpoint_then.as_ref());
+                (version, nym_then)
+            }
+            (None, None) => {
+                // the creation and next update times take a ledger read per update of the DID,
+                // so they are only looked up for versions
+                let version = DidSovVersion::latest(&nym, Some(&endpoint?));
+                return Ok(
+                    version_to_ddo(parsed_did.did(), version)?.into_representation(media_type)
+                );
+            }
+        };
+
+        let mut next_txns = Vec::new();
+        if let Some(seq_no) = version.seq_no {
+            next_txns.extend(
+                self.first_update_after(parsed_did, None, seq_no, nym.txn())
+                    .await?,
+            );
+            let current_endpoint = optional(endpoint)?.and_then(|endpoint| endpoint.txn());
+            next_txns.extend(
+                self.first_update_after(parsed_did, Some(ENDPOINT_ATTR), seq_no, current_endpoint)
+                    .await?,
+            );
+        }
+        let created = self.created_time(parsed_did, &nym_then).await?;
+
+        Ok(version_to_ddo(
+            parsed_did.did(),
+            version.with_next_update(next_txns).with_created(created),
+        )?
+        .into_representation(media_type))
     }
 }
 
@@ -95,24 +144,200 @@ where
     T: Borrow<A> + Sync + Send,
     A: AttrReader,
 {
-    async fn get_verkey(&self, did: &Did) -> Result<String, DidSovError> {
-        let nym_response = self.ledger.borrow().get_nym(did).await?;
-        let nym_json: Value = serde_json::from_str(&nym_response)?;
-        let nym_data = nym_json["result"]["data"]
-            .as_str()
-            .ok_or(DidSovError::ParsingError(
-                ParsingErrorSource::LedgerResponseParsingError(
-                    "Failed to parse nym data".to_string(),
-                ),
-            ))?;
-        let nym_data: Value = serde_json::from_str(nym_data)?;
-        let verkey = nym_data["verkey"]
-            .as_str()
-            .ok_or(DidSovError::ParsingError(
-                ParsingErrorSource::LedgerResponseParsingError(
-                    "Failed to parse verkey from nym data".to_string(),
-                ),
-            ))?;
-        Ok(verkey.to_string())
+    /// Reads the NYM and endpoint of the DID as of the transaction with `seq_no`, or as of
+    /// `timestamp`. The endpoint is `None` if it was not set yet.
+    async fn read_version(
+        &self,
+        did: &Did,
+        seq_no: Option<i32>,
+        timestamp: Option<u64>,
+    ) -> Result<(LedgerEntry<String>, Option<LedgerEntry<EndpointDidSov>>), DidSovError> {
+        let ledger = self.ledger.borrow();
+        let not_found = |err: DidSovError| match err {
+            DidSovError::NotFound(msg) => DidSovError::VersionNotFound(msg),
+            err => err,
+        };
+        let nym = LedgerEntry::verkey_from_response(
+            &ledger.get_nym_version(did, seq_no, timestamp).await?,
+        )
+        .map_err(not_found)?;
+        let endpoint = optional(LedgerEntry::endpoint_from_response(
+            &ledger
+                .get_attr_version(did, ENDPOINT_ATTR, seq_no, timestamp)
+                .await?,
+        ))?;
+        Ok((nym, endpoint))
+    }
+
+    /// First transaction updating the NYM, or the ATTRIB `attr_name`, after the transaction with
+    /// `seq_no`, found by walking back from the transaction which last updated it.
+    async fn first_update_after(
+        &self,
+        did: &Did,
+        attr_name: Option<&str>,
+        seq_no: i64,
+        last: Option<LedgerTxn>,
+    ) -> Result<Option<LedgerTxn>, DidSovError> {
+        let ledger = self.ledger.borrow();
+        let mut first = None;
+        let mut txn = last;
+        while let Some(later) = txn.take().filter(|txn| txn.seq_no > seq_no) {
+            let Ok(before) = i32::try_from(later.seq_no - 1) else {
+                break;
+            };
+            let resp = match attr_name {
+                Some(attr_name) => {
+                    ledger
+                        .get_attr_version(did, attr_name, Some(before), None)
+                        .await?
+                }
+                None => ledger.get_nym_version(did, Some(before), None).await?,
+            };
+            txn = LedgerTxn::last_from_response(&resp)?;
+            first = Some(later);
+        }
+        Ok(first)
+    }
+
+    /// Time the DID was created at, found by walking back through the states of its NYM, starting
+    /// from `nym`, until the ledger has none.
+    async fn created_time(
+        &self,
+        did: &Did,
+        nym: &LedgerEntry<String>,
+    ) -> Result<Option<i64>, DidSovError> {
+        let ledger = self.ledger.borrow();
+        let mut created = nym.txn_time;
+        while let Some(txn_time) = created {
+            let Ok(before) = u64::try_from(txn_time - 1) else {
+                break;
+            };
+            let resp = ledger.get_nym_version(did, None, Some(before)).await?;
+            match get_data_from_response(&resp) {
+                Ok(_) => {}
+                Err(DidSovError::NotFound(_)) => break,
+                Err(err) => return Err(err),
+            }
+            match get_txn_time_from_response(&resp) {
+                Ok(earlier) if earlier < txn_time => created = Some(earlier),
+                _ => break,
+            }
+        }
+        Ok(created)
+    }
+}
+
+/// Treats a value the ledger has no record of as absent.
+fn optional<T>(entry: Result<T, DidSovError>) -> Result<Option<T>, DidSovError> {
+    match entry {
+        Ok(entry) => Ok(Some(entry)),
+        Err(DidSovError::NotFound(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const DID: &str = "did:sov:2wJPyULfLLnYTEFYzByfUR";
+
+    /// (seqNo, txnTime, data) of the transactions setting a ledger value.
+    type Txns = Vec<(i64, i64, Value)>;
+
+    /// Ledger holding the NYM and endpoint ATTRIB transactions of a single DID.
+    struct FakeLedger {
+        nym: Txns,
+        endpoint: Txns,
+    }
+
+    impl FakeLedger {
+        fn read(txns: &Txns, seq_no: Option<i32>, timestamp: Option<u64>) -> String {
+            let txn = txns
+                .iter()
+                .filter(|(txn_seq_no, txn_time, _)| {
+                    seq_no.map_or(true, |seq_no| *txn_seq_no <= i64::from(seq_no))
+                        && timestamp.map_or(true, |timestamp| *txn_time as u64 <= timestamp)
+                })
+                .last();
+            match txn {
+                Some((seq_no, txn_time, data)) => json!({
+                    "result": { "seqNo": seq_no, "txnTime": txn_time, "data": data.to_string() }
+                }),
+                None => json!({ "result": { "seqNo": null, "txnTime": null, "data": null } }),
+            }
+            .to_string()
+        }
+    }
+
+    #[async_trait]
+    impl AttrReader for FakeLedger {
+        async fn get_attr(&self, _: &Did, _: &str) -> Result<String, DidSovError> {
+            Ok(Self::read(&self.endpoint, None, None))
+        }
+
+        async fn get_nym(&self, _: &Did) -> Result<String, DidSovError> {
+            Ok(Self::read(&self.nym, None, None))
+        }
+
+        async fn get_attr_version(
+            &self,
+            _: &Did,
+            _: &str,
+            seq_no: Option<i32>,
+            timestamp: Option<u64>,
+        ) -> Result<String, DidSovError> {
+            Ok(Self::read(&self.endpoint, seq_no, timestamp))
+        }
+
+        async fn get_nym_version(
+            &self,
+            _: &Did,
+            seq_no: Option<i32>,
+            timestamp: Option<u64>,
+        ) -> Result<String, DidSovError> {
+            Ok(Self::read(&self.nym, seq_no, timestamp))
+        }
+
+        async fn get_ledger_txn(&self, _: i32) -> Result<String, DidSovError> {
+            unimplemented!()
+        }
+    }
+
+    fn endpoint(url: &str) -> Value {
+        json!({ "endpoint": { "endpoint": url } })
+    }
+
+    #[tokio::test]
+    async fn test_resolve_version_before_endpoint() {
+        let resolver: DidSovResolver<FakeLedger, FakeLedger> = DidSovResolver::new(FakeLedger {
+            nym: vec![(10, 1000, json!({ "verkey": "verkey" }))],
+            endpoint: vec![
+                (20, 2000, endpoint("https://example.com/first")),
+                (30, 3000, endpoint("https://example.com/second")),
+            ],
+        });
+        let options =
+            DidResolutionOptions::default().set_version_time(Utc.timestamp_opt(1500, 0).unwrap());
+
+        let output = resolver
+            .resolve(&Did::parse(DID.to_string()).unwrap(), &options)
+            .await
+            .unwrap();
+
+        assert!(output.did_document().service().is_empty());
+        let metadata = output.did_document_metadata();
+        assert_eq!(Some("10"), metadata.version_id().map(String::as_str));
+        assert_eq!(
+            Utc.timestamp_opt(1000, 0).unwrap(),
+            metadata.created().unwrap()
+        );
+        assert_eq!(
+            Utc.timestamp_opt(2000, 0).unwrap(),
+            metadata.next_update().unwrap()
+        );
     }
 }
@@ -14,6 +14,7 @@ use did_resolver::{
 };
 use serde_json::Value;
 
+use super::version::DidSovVersion;
 use crate::{
     error::{parsing::ParsingErrorSource, DidSovError},
     service::{DidSovServiceType, EndpointDidSov},
@@ -25,7 +26,7 @@ fn prepare_ids(did: &str) -> Result<(Uri, Did), DidSovError> {
     Ok((service_id, ddo_id))
 }
 
-fn get_data_from_response(resp: &str) -> Result<Value, DidSovError> {
+pub(super) fn get_data_from_response(resp: &str) -> Result<Value, DidSovError> {
     let resp: serde_json::Value = serde_json::from_str(resp)?;
     match &resp["result"]["data"] {
         Value::String(ref data) => serde_json::from_str(data).map_err(|err| err.into()),
@@ -38,7 +39,7 @@ fn get_data_from_response(resp: &str) -> Result<Value, DidSovError> {
     }
 }
 
-fn get_txn_time_from_response(resp: &str) -> Result<i64, DidSovError> {
+pub(super) fn get_txn_time_from_response(resp: &str) -> Result<i64, DidSovError> {
     let resp: serde_json::Value = serde_json::from_str(resp)?;
     let txn_time = resp["result"]["txnTime"]
         .as_i64()
@@ -53,7 +54,7 @@ fn unix_to_datetime(posix_timestamp: i64) -> Option<DateTime<Utc>> {
         .map(|date_time| DateTime::<Utc>::from_naive_utc_and_offset(date_time, Utc))
 }
 
-pub(super) fn is_valid_sovrin_did_id(id: &str) -> bool {
+pub(crate) fn is_valid_sovrin_did_id(id: &str) -> bool {
     if id.len() < 21 || id.len() > 22 {
         return false;
     }
@@ -61,56 +62,72 @@ pub(super) fn is_valid_sovrin_did_id(id: &str) -> bool {
     id.chars().all(|c| base58_chars.contains(c))
 }
 
-pub(super) async fn ledger_response_to_ddo<E: Default>(
+/// DID document of a did:sov DID, with a service if the DID has an endpoint.
+pub(crate) fn did_sov_ddo<E: Default>(
     did: &str,
-    resp: &str,
     verkey: String,
-) -> Result<DidResolutionOutput<E>, DidSovError> {
+    endpoint: Option<EndpointDidSov>,
+) -> Result<DidDocument<E>, DidSovError> {
     let (service_id, ddo_id) = prepare_ids(did)?;
 
-    let service_data = get_data_from_response(resp)?;
-    let endpoint: EndpointDidSov = serde_json::from_value(service_data["endpoint"].clone())?;
-
-    let txn_time = get_txn_time_from_response(resp)?;
-    let datetime = unix_to_datetime(txn_time);
+    // TODO: Use multibase instead of base58
+    let verification_method = VerificationMethod::builder(
+        did.to_string().try_into()?,
+        did.to_string().try_into()?,
+        VerificationMethodType::Ed25519VerificationKey2018,
+    )
+    .add_public_key_base58(verkey)
+    .build();
 
-    let service = {
+    let mut builder = DidDocument::builder(ddo_id).add_verification_method(verification_method);
+    if let Some(endpoint) = endpoint {
         let service_types: Vec<String> = endpoint
             .types
             .into_iter()
             .filter(|t| *t != DidSovServiceType::Unknown)
             .map(|t| t.to_string())
             .collect();
-        let mut builder = Service::builder(
+        let mut service_builder = Service::builder(
             service_id,
             endpoint.endpoint.as_str().try_into()?,
             Default::default(),
         );
         for service_type in service_types {
-            builder = builder.add_service_type(service_type)?;
+            service_builder = service_builder.add_service_type(service_type)?;
         }
-        builder.build()
-    };
-
-    // TODO: Use multibase instead of base58
-    let verification_method = VerificationMethod::builder(
-        did.to_string().try_into()?,
-        did.to_string().try_into()?,
-        VerificationMethodType::Ed25519VerificationKey2018,
-    )
-    .add_public_key_base58(verkey)
-    .build();
+        builder = builder.add_service(service_builder.build());
+    }
+    Ok(builder.build())
+}
 
-    let ddo = DidDocument::builder(ddo_id)
-        .add_service(service)
-        .add_verification_method(verification_method)
-        .build();
+pub(super) fn version_to_ddo<E: Default>(
+    did: &str,
+    version: DidSovVersion,
+) -> Result<DidResolutionOutput<E>, DidSovError> {
+    let DidSovVersion {
+        verkey,
+        endpoint,
+        seq_no,
+        txn_time,
+        next_update,
+        created,
+    } = version;
+    let ddo = did_sov_ddo(did, verkey, endpoint)?;
 
     let ddo_metadata = {
         let mut metadata_builder = DidDocumentMetadata::builder().deactivated(false);
-        if let Some(datetime) = datetime {
+        if let Some(datetime) = created.and_then(unix_to_datetime) {
+            metadata_builder = metadata_builder.created(datetime);
+        };
+        if let Some(datetime) = txn_time.and_then(unix_to_datetime) {
             metadata_builder = metadata_builder.updated(datetime);
         };
+        if let Some(seq_no) = seq_no {
+            metadata_builder = metadata_builder.version_id(seq_no.to_string());
+        }
+        if let Some(datetime) = next_update.and_then(unix_to_datetime) {
+            metadata_builder = metadata_builder.next_update(datetime);
+        }
         metadata_builder.build()
     };
 
@@ -174,19 +191,18 @@ mod tests {
         );
     }
 
-    #[tokio::test]
-    async fn test_resolve_ddo() {
+    #[test]
+    fn test_resolve_ddo() {
         let did = "did:example:1234567890";
-        let resp = r#"{
-            "result": {
-                "data": "{\"endpoint\":{\"endpoint\":\"https://example.com\"}}",
-                "txnTime": 1629272938
-            }
-        }"#;
-        let verkey = "9wvq2i4xUa5umXoThe83CDgx1e5bsjZKJL4DEWvTP9qe".to_string();
-        let resolution_output = ledger_response_to_ddo::<()>(did, resp, verkey)
-            .await
-            .unwrap();
+        let version = DidSovVersion {
+            verkey: "9wvq2i4xUa5umXoThe83CDgx1e5bsjZKJL4DEWvTP9qe".to_string(),
+            endpoint: Some(serde_json::from_str(r#"{"endpoint":"https://example.com"}"#).unwrap()),
+            seq_no: Some(42),
+            txn_time: Some(1629272938),
+            next_update: None,
+            created: Some(1629270000),
+        };
+        let resolution_output = version_to_ddo::<()>(did, version).unwrap();
         let ddo = resolution_output.did_document();
         assert_eq!(ddo.id().to_string(), "did:example:1234567890");
         assert_eq!(ddo.service()[0].id().to_string(), "did:example:1234567890");
@@ -198,6 +214,17 @@ mod tests {
             resolution_output.did_document_metadata().updated().unwrap(),
             chrono::Utc.timestamp_opt(1629272938, 0).unwrap()
         );
+        assert_eq!(
+            resolution_output.did_document_metadata().created().unwrap(),
+            chrono::Utc.timestamp_opt(1629270000, 0).unwrap()
+        );
+        assert_eq!(
+            resolution_output
+                .did_document_metadata()
+                .version_id()
+                .map(String::as_str),
+            Some("42")
+        );
         assert_eq!(
             resolution_output
                 .did_resolution_metadata()
@@ -0,0 +1,324 @@
+use serde_json::Value;
+
+use super::utils::{get_data_from_response, get_txn_time_from_response};
+use crate::{
+    error::{parsing::ParsingErrorSource, DidSovError},
+    service::EndpointDidSov,
+};
+
+const NYM_TXN_TYPE: &str = "1";
+const ATTRIB_TXN_TYPE: &str = "100";
+
+/// Value of the NYM or endpoint ATTRIB of a DID, along with the transaction which last set it.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct LedgerEntry<T> {
+    pub value: T,
+    pub seq_no: Option<i64>,
+    pub txn_time: Option<i64>,
+}
+
+impl<T> LedgerEntry<T> {
+    fn from_response(resp: &str, value: T) -> Result<Self, DidSovError> {
+        let resp_json: Value = serde_json::from_str(resp)?;
+        Ok(Self {
+            value,
+            seq_no: resp_json["result"]["seqNo"].as_i64(),
+            txn_time: get_txn_time_from_response(resp).ok(),
+        })
+    }
+
+    pub fn txn(&self) -> Option<LedgerTxn> {
+        Some(LedgerTxn {
+            seq_no: self.seq_no?,
+            txn_time: self.txn_time,
+        })
+    }
+}
+
+impl LedgerEntry<String> {
+    /// Verkey of the DID, from a GET_NYM response.
+    pub fn verkey_from_response(resp: &str) -> Result<Self, DidSovError> {
+        let nym_data = get_data_from_response(resp)?;
+        let verkey = nym_data["verkey"]
+            .as_str()
+            .ok_or(DidSovError::ParsingError(
+                ParsingErrorSource::LedgerResponseParsingError(
+                    "Failed to parse verkey from nym data".to_string(),
+                ),
+            ))?;
+        Self::from_response(resp, verkey.to_string())
+    }
+}
+
+impl LedgerEntry<EndpointDidSov> {
+    /// Endpoint of the DID, from a GET_ATTRIB response.
+    pub fn endpoint_from_response(resp: &str) -> Result<Self, DidSovError> {
+        let service_data = get_data_from_response(resp)?;
+        let endpoint = serde_json::from_value(service_data["endpoint"].clone())?;
+        Self::from_response(resp, endpoint)
+    }
+}
+
+/// NYM or ATTRIB transaction of a DID, from a GET_TXN response. Its content is not used, as
+/// the ledger only records the hash of raw ATTRIB values.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct LedgerTxn {
+    pub seq_no: i64,
+    pub txn_time: Option<i64>,
+}
+
+impl LedgerTxn {
+    pub fn from_get_txn_response(resp: &str, did_id: &str) -> Result<Self, DidSovError> {
+        let resp: Value = serde_json::from_str(resp)?;
+        let data = &resp["result"]["data"];
+        let txn = &data["txn"];
+        let seq_no = data["txnMetadata"]["seqNo"]
+            .as_i64()
+            .ok_or_else(|| DidSovError::VersionNotFound("transaction not found".to_string()))?;
+        if txn["data"]["dest"].as_str() != Some(did_id) {
+            return Err(DidSovError::VersionNotFound(format!(
+                "transaction {seq_no} does not update DID {did_id}"
+            )));
+        }
+        if !matches!(txn["type"].as_str(), Some(NYM_TXN_TYPE | ATTRIB_TXN_TYPE)) {
+            return Err(DidSovError::VersionNotFound(format!(
+                "transaction {seq_no} is neither a NYM nor an ATTRIB"
+            )));
+        }
+
+        Ok(Self {
+            seq_no,
+            txn_time: data["txnMetadata"]["txnTime"].as_i64(),
+        })
+    }
+
+    /// Transaction which last set the value of a GET_NYM or GET_ATTRIB response, if the ledger
+    /// has any.
+    pub fn last_from_response(resp: &str) -> Result<Option<Self>, DidSovError> {
+        match get_data_from_response(resp) {
+            Ok(_) => {}
+            Err(DidSovError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err),
+        }
+        let resp_json: Value = serde_json::from_str(resp)?;
+        Ok(resp_json["result"]["seqNo"].as_i64().map(|seq_no| Self {
+            seq_no,
+            txn_time: get_txn_time_from_response(resp).ok(),
+        }))
+    }
+}
+
+/// State of a did:sov DID document as of a NYM or ATTRIB transaction, which identifies the
+/// version by its seqNo.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct DidSovVersion {
+    pub verkey: String,
+    /// Endpoint of the DID, unless it had none as of the version.
+    pub endpoint: Option<EndpointDidSov>,
+    pub seq_no: Option<i64>,
+    pub txn_time: Option<i64>,
+    /// Time of the first later update of the document.
+    pub next_update: Option<i64>,
+    /// Time of the NYM transaction which created the DID.
+    pub created: Option<i64>,
+}
+
+impl DidSovVersion {
+    /// Version made up of the NYM and endpoint, as read at the time of the version.
+    pub fn latest(
+        nym: &LedgerEntry<String>,
+        endpoint: Option<&LedgerEntry<EndpointDidSov>>,
+    ) -> Self {
+        let (seq_no, txn_time) = match endpoint {
+            Some(endpoint) if endpoint.seq_no > nym.seq_no => (endpoint.seq_no, endpoint.txn_time),
+            _ => (nym.seq_no, nym.txn_time),
+        };
+        Self {
+            verkey: nym.value.clone(),
+            endpoint: endpoint.map(|endpoint| endpoint.value.clone()),
+            seq_no,
+            txn_time,
+            next_update: None,
+            created: None,
+        }
+    }
+
+    /// Version set by the transaction, from the NYM and endpoint read as of its seqNo.
+    pub fn at_txn(
+        txn: &LedgerTxn,
+        nym: &LedgerEntry<String>,
+        endpoint: Option<&LedgerEntry<EndpointDidSov>>,
+    ) -> Self {
+        Self {
+            seq_no: Some(txn.seq_no),
+            txn_time: txn.txn_time,
+            ..Self::latest(nym, endpoint)
+        }
+    }
+
+    pub fn with_created(self, created: Option<i64>) -> Self {
+        Self { created, ..self }
+    }
+
+    /// Sets the time of the next update from the first transactions updating the NYM and the
+    /// endpoint after this version.
+    pub fn with_next_update(self, next_txns: impl IntoIterator<Item = LedgerTxn>) -> Self {
+        let next_update = next_txns
+            .into_iter()
+            .filter(|txn| Some(txn.seq_no) > self.seq_no)
+            .min_by_key(|txn| txn.seq_no)
+            .and_then(|txn| txn.txn_time);
+        Self {
+            next_update,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const DID_ID: &str = "2wJPyULfLLnYTEFYzByfUR";
+
+    fn endpoint(url: &str) -> EndpointDidSov {
+        serde_json::from_value(json!({ "endpoint": url })).unwrap()
+    }
+
+    fn entries() -> (LedgerEntry<String>, LedgerEntry<EndpointDidSov>) {
+        (
+            LedgerEntry {
+                value: "verkey".to_string(),
+                seq_no: Some(10),
+                txn_time: Some(1000),
+            },
+            LedgerEntry {
+                value: endpoint("https://example.com/new"),
+                seq_no: Some(20),
+                txn_time: Some(2000),
+            },
+        )
+    }
+
+    fn attrib_txn_response(seq_no: i64) -> String {
+        json!({
+            "result": {
+                "data": {
+                    "txn": {
+                        "type": ATTRIB_TXN_TYPE,
+                        "data": {
+                            "dest": DID_ID,
+                            "raw": "9e8d1e1fd5b3a5e2fd2f0ad6bbd8a3bd4f5e1b8e1c1f0e0b0e9e2e0a1f2c3d4e"
+                        }
+                    },
+                    "txnMetadata": { "seqNo": seq_no, "txnTime": seq_no * 100 }
+                }
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_latest_version() {
+        let (nym, endpoint) = entries();
+        let version = DidSovVersion::latest(&nym, Some(&endpoint));
+
+        assert_eq!(Some(20), version.seq_no);
+        assert_eq!(Some(2000), version.txn_time);
+        assert_eq!(None, version.next_update);
+    }
+
+    #[test]
+    fn test_version_without_endpoint() {
+        let (nym, _) = entries();
+        let version = DidSovVersion::latest(&nym, None);
+
+        assert_eq!(None, version.endpoint);
+        assert_eq!(Some(10), version.seq_no);
+        assert_eq!(Some(1000), version.txn_time);
+    }
+
+    #[test]
+    fn test_version_at_txn() {
+        let (nym, current_endpoint) = entries();
+        let old_endpoint = LedgerEntry {
+            value: endpoint("https://example.com/old"),
+            seq_no: Some(15),
+            txn_time: Some(1500),
+        };
+        let txn = LedgerTxn::from_get_txn_response(&attrib_txn_response(15), DID_ID).unwrap();
+
+        let version = DidSovVersion::at_txn(&txn, &nym, Some(&old_endpoint))
+            .with_next_update(nym.txn().into_iter().chain(current_endpoint.txn()));
+
+        assert_eq!("verkey", version.verkey);
+        assert_eq!(
+            "https://example.com/old",
+            version.endpoint.unwrap().endpoint.as_str()
+        );
+        assert_eq!(Some(15), version.seq_no);
+        assert_eq!(Some(1500), version.txn_time);
+        assert_eq!(Some(2000), version.next_update);
+    }
+
+    #[test]
+    fn test_txn_of_other_did() {
+        assert!(matches!(
+            LedgerTxn::from_get_txn_response(&attrib_txn_response(15), "VbPQNHsvoLZdaNU7fTBeFx"),
+            Err(DidSovError::VersionNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_next_update() {
+        let (nym, current_endpoint) = entries();
+        let old_endpoint = LedgerEntry {
+            value: endpoint("https://example.com/old"),
+            seq_no: Some(5),
+            txn_time: Some(500),
+        };
+        let version = DidSovVersion::latest(&nym, Some(&old_endpoint));
+        let next_endpoint = LedgerTxn {
+            seq_no: 15,
+            txn_time: Some(1500),
+        };
+
+        // the first update after the version, not the latest one, is reported
+        assert_eq!(
+            Some(1500),
+            version
+                .clone()
+                .with_next_update([current_endpoint.txn().unwrap(), next_endpoint])
+                .next_update
+        );
+        assert_eq!(
+            None,
+            version.with_next_update(nym.txn()).next_update,
+            "the version itself is no later update"
+        );
+    }
+
+    #[test]
+    fn test_last_txn_from_response() {
+        let resp = json!({
+            "result": {
+                "seqNo": 15,
+                "txnTime": 1500,
+                "data": r#"{"endpoint":{"endpoint":"https://example.com"}}"#
+            }
+        })
+        .to_string();
+        assert_eq!(
+            Some(LedgerTxn {
+                seq_no: 15,
+                txn_time: Some(1500)
+            }),
+            LedgerTxn::last_from_response(&resp).unwrap()
+        );
+
+        let resp = json!({ "result": { "seqNo": null, "data": null } }).to_string();
+        assert_eq!(None, LedgerTxn::last_from_response(&resp).unwrap());
+    }
+}
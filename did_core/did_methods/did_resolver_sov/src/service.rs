@@ -1,9 +1,9 @@
 use std::{collections::HashSet, fmt::Display};
 
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize};
 use url::Url;
 
-#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct EndpointDidSov {
     pub endpoint: Url,
@@ -16,7 +16,17 @@ pub struct EndpointDidSov {
     pub types: HashSet<DidSovServiceType>,
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Hash)]
+impl EndpointDidSov {
+    pub fn new(endpoint: Url) -> Self {
+        Self {
+            endpoint,
+            routing_keys: Vec::new(),
+            types: default_didsov_service_types(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum DidSovServiceType {
     #[serde(rename = "endpoint")] // AIP 1.0
     Endpoint,
@@ -0,0 +1,38 @@
+use std::{thread, time::Duration};
+
+use did_registrar::{registration_options::DidRegistrationOptions, DidRegistrar};
+use did_resolver::traits::resolvable::{resolution_options::DidResolutionOptions, DidResolvable};
+use did_resolver_sov::{
+    registration::{DidSovCreateOptions, DidSovRegistrar},
+    resolution::DidSovResolver,
+    service::EndpointDidSov,
+};
+use test_utils::devsetup::build_setup_profile;
+
+#[tokio::test]
+async fn create_did_and_resolve_did_doc() {
+    let profile = build_setup_profile().await;
+    let registrar = DidSovRegistrar::new(profile.ledger_write, profile.institution_did.clone());
+    let options = DidRegistrationOptions::new(DidSovCreateOptions::new().set_endpoint(
+        EndpointDidSov::new("http://localhost:8080".parse().unwrap()),
+    ));
+
+    let output = registrar.create(&profile.wallet, &options).await.unwrap();
+    thread::sleep(Duration::from_millis(50));
+
+    let resolver = DidSovResolver::new(profile.ledger_read);
+    let resolution_output = resolver
+        .resolve(output.did(), &DidResolutionOptions::default())
+        .await
+        .unwrap();
+    let did_doc = resolution_output.did_document();
+    assert_eq!(output.did(), did_doc.id());
+    assert_eq!(
+        "http://localhost:8080/",
+        did_doc.service()[0].service_endpoint().as_ref()
+    );
+    assert_eq!(
+        output.did_document().unwrap().verification_method()[0].public_key_field(),
+        did_doc.verification_method()[0].public_key_field()
+    );
+}
@@ -20,6 +20,12 @@ pub enum DidWebError {
     HttpError(#[from] hyper::Error),
     #[error("Non-success server response: {0}")]
     NonSuccessResponse(StatusCode),
+    #[error("Invalid registration options: {0}")]
+    InvalidOptions(String),
+    #[error("DID parser error: {0}")]
+    DidParserError(#[from] did_resolver::did_parser::ParseError),
+    #[error("Wallet error: {0}")]
+    WalletError(#[from] aries_vcx_core::errors::error::AriesVcxCoreError),
     #[error(transparent)]
     Other(#[from] Box<dyn std::error::Error + Send + Sync>),
 }
@@ -0,0 +1,5 @@
+mod options;
+mod registrar;
+
+pub use options::{DidWebCreateOptions, DidWebUpdateOptions};
+pub use registrar::DidWebRegistrar;
@@ -0,0 +1,65 @@
+use did_resolver::did_doc::schema::{did_doc::DidDocument, service::Service};
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidWebCreateOptions {
+    /// Host serving the DID document, optionally with a port, e.g. `example.com:8443`.
+    domain: String,
+    /// Path to the DID document, which is served from `/.well-known` if empty.
+    #[serde(default)]
+    path: Vec<String>,
+    #[serde(default)]
+    services: Vec<Service<()>>,
+}
+
+impl DidWebCreateOptions {
+    pub fn new(domain: String) -> Self {
+        Self {
+            domain,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_path(mut self, path: Vec<String>) -> Self {
+        self.path = path;
+        self
+    }
+
+    pub fn add_service(mut self, service: Service<()>) -> Self {
+        self.services.push(service);
+        self
+    }
+
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    pub fn services(&self) -> &[Service<()>] {
+        &self.services
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidWebUpdateOptions {
+    /// DID document to publish in place of the current one.
+    #[serde(default)]
+    did_document: Option<DidDocument<()>>,
+}
+
+impl DidWebUpdateOptions {
+    pub fn new(did_document: DidDocument<()>) -> Self {
+        Self {
+            did_document: Some(did_document),
+        }
+    }
+
+    pub fn did_document(&self) -> Option<&DidDocument<()>> {
+        self.did_document.as_ref()
+    }
+}
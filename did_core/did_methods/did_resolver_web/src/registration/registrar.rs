@@ -0,0 +1,202 @@
+use aries_vcx_core::wallet::{base_wallet::BaseWallet, record_tags::RecordTags};
+use async_trait::async_trait;
+use did_registrar::{
+    registration_options::DidRegistrationOptions,
+    registration_output::{DidRegistrationAction, DidRegistrationOutput, DidRegistrationState},
+    DidRegistrar,
+};
+use did_resolver::{
+    did_doc::schema::{
+        did_doc::DidDocument,
+        verification_method::{VerificationMethod, VerificationMethodType},
+    },
+    did_parser::{Did, DidUrl},
+    error::GenericError,
+    shared_types::did_document_metadata::DidDocumentMetadata,
+};
+use public_key::KeyType;
+
+use super::options::{DidWebCreateOptions, DidWebUpdateOptions};
+use crate::{error::DidWebError, resolution::resolver::document_location};
+
+/// Registrar of did:web DIDs. The registrar prepares the DID documents, which only take effect
+/// once the client publishes them at the URL returned in the action of the output.
+#[derive(Default)]
+pub struct DidWebRegistrar;
+
+impl DidWebRegistrar {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl DidRegistrar for DidWebRegistrar {
+    type ExtraFieldsService = ();
+    type CreateOptions = DidWebCreateOptions;
+    type UpdateOptions = DidWebUpdateOptions;
+    type DeactivateOptions = ();
+
+    async fn create(
+        &self,
+        wallet: &impl BaseWallet,
+        options: &DidRegistrationOptions<Self::CreateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError> {
+        let extra = options.extra();
+        if extra.domain().is_empty() {
+            return Err(Box::new(DidWebError::InvalidOptions(
+                "domain of the DID is missing".to_string(),
+            )));
+        }
+        let id = std::iter::once(extra.domain().replace(':', "%3A"))
+            .chain(extra.path().iter().cloned())
+            .collect::<Vec<_>>()
+            .join(":");
+        let did = Did::parse(format!("did:web:{id}")).map_err(DidWebError::from)?;
+
+        let key = wallet
+            .create_key(KeyType::Ed25519, options.seed(), RecordTags::default())
+            .await
+            .map_err(DidWebError::from)?
+            .key()
+            .clone();
+        let vm_id = DidUrl::parse(format!("{did}#{}", key.short_prefixless_fingerprint()))
+            .map_err(DidWebError::from)?;
+        let verification_method = VerificationMethod::builder(
+            vm_id.clone(),
+            did.clone(),
+            VerificationMethodType::Ed25519VerificationKey2020,
+        )
+        .add_public_key_multibase(key.fingerprint())
+        .build();
+        let mut builder = DidDocument::builder(did.clone())
+            .add_verification_method(verification_method)
+            .add_authentication_reference(vm_id.clone())
+            .add_assertion_method_reference(vm_id);
+        for service in extra.services() {
+            builder = builder.add_service(service.clone());
+        }
+
+        publish(&did, builder.build(), DidDocumentMetadata::default())
+    }
+
+    async fn update(
+        &self,
+        _wallet: &impl BaseWallet,
+        did: &Did,
+        options: &DidRegistrationOptions<Self::UpdateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError> {
+        validate_did(did)?;
+        let did_document = options.extra().did_document().ok_or_else(|| {
+            DidWebError::InvalidOptions("DID document to publish is missing".to_string())
+        })?;
+        if did_document.id() != did {
+            return Err(Box::new(DidWebError::InvalidOptions(format!(
+                "DID document {} does not belong to {did}",
+                did_document.id()
+            ))));
+        }
+
+        publish(did, did_document.clone(), DidDocumentMetadata::default())
+    }
+
+    async fn deactivate(
+        &self,
+        _wallet: &impl BaseWallet,
+        did: &Did,
+        _options: &DidRegistrationOptions<Self::DeactivateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError> {
+        validate_did(did)?;
+        let action = DidRegistrationAction::Unpublish {
+            url: document_url(did)?,
+        };
+
+        Ok(DidRegistrationOutput::builder(did.to_owned())
+            .state(DidRegistrationState::Action(action))
+            .did_document_metadata(DidDocumentMetadata::builder().deactivated(true).build())
+            .build())
+    }
+}
+
+fn validate_did(did: &Did) -> Result<(), DidWebError> {
+    match did.method() {
+        Some("web") => Ok(()),
+        Some(method) => Err(DidWebError::MethodNotSupported(method.to_string())),
+        None => Err(DidWebError::InvalidDid(
+            "Attempted to register unqualified did".to_string(),
+        )),
+    }
+}
+
+fn document_url(did: &Did) -> Result<String, DidWebError> {
+    let (domain, path) = document_location(did)?;
+    Ok(format!("https://{domain}{path}"))
+}
+
+fn publish(
+    did: &Did,
+    did_document: DidDocument<()>,
+    did_document_metadata: DidDocumentMetadata,
+) -> Result<DidRegistrationOutput<()>, GenericError> {
+    let action = DidRegistrationAction::Publish {
+        url: document_url(did)?,
+        content: serde_json::to_string_pretty(&did_document).map_err(DidWebError::from)?,
+    };
+
+    Ok(DidRegistrationOutput::builder(did.to_owned())
+        .state(DidRegistrationState::Action(action))
+        .did_document(did_document)
+        .did_document_metadata(did_document_metadata)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::{constants::TRUSTEE_SEED, devsetup::dev_build_featured_wallet};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_with_path() {
+        let (_, wallet) = dev_build_featured_wallet(TRUSTEE_SEED).await;
+        let options = DidRegistrationOptions::new(
+            DidWebCreateOptions::new("example.com:8443".to_string())
+                .set_path(vec!["users".to_string(), "alice".to_string()]),
+        );
+
+        let output = DidWebRegistrar::new()
+            .create(&wallet, &options)
+            .await
+            .unwrap();
+
+        assert_eq!("did:web:example.com%3A8443:users:alice", output.did().did());
+        let DidRegistrationState::Action(DidRegistrationAction::Publish { url, content }) =
+            output.state()
+        else {
+            panic!("Expected a publish action, got {:?}", output.state());
+        };
+        assert_eq!("https://example.com:8443/users/alice/did.json", url);
+        let published: DidDocument<()> = serde_json::from_str(content).unwrap();
+        assert_eq!(Some(&published), output.did_document());
+        assert_eq!(1, published.authentication().len());
+    }
+
+    #[tokio::test]
+    async fn test_deactivate() {
+        let (_, wallet) = dev_build_featured_wallet(TRUSTEE_SEED).await;
+        let did = Did::parse("did:web:example.com".to_string()).unwrap();
+
+        let output = DidWebRegistrar::new()
+            .deactivate(&wallet, &did, &DidRegistrationOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            &DidRegistrationState::Action(DidRegistrationAction::Unpublish {
+                url: "https://example.com/.well-known/did.json".to_string()
+            }),
+            output.state()
+        );
+        assert_eq!(Some(true), output.did_document_metadata().deactivated());
+    }
+}
@@ -0,0 +1,13 @@
+use hyper::{client::HttpConnector, Body, Client};
+use hyper_tls::HttpsConnector;
+
+/// Client fetching DID documents and resolution results over plain HTTP, e.g. from a local
+/// deployment.
+pub fn http_client() -> Client<HttpConnector> {
+    Client::builder().build::<_, Body>(HttpConnector::new())
+}
+
+/// Client fetching DID documents and resolution results over HTTPS.
+pub fn https_client() -> Client<HttpsConnector<HttpConnector>> {
+    Client::builder().build::<_, Body>(HttpsConnector::new())
+}
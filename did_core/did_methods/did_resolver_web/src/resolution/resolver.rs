@@ -11,11 +11,14 @@ use did_resolver::{
 use hyper::{
     client::{connect::Connect, HttpConnector},
     http::uri::{self, Scheme},
-    Body, Client, Uri,
+    Client, Uri,
 };
 use hyper_tls::HttpsConnector;
 
-use crate::error::DidWebError;
+use crate::{
+    error::DidWebError,
+    resolution::client::{http_client, https_client},
+};
 
 pub struct DidWebResolver<C>
 where
@@ -28,7 +31,7 @@ where
 impl DidWebResolver<HttpConnector> {
     pub fn http() -> DidWebResolver<HttpConnector> {
         DidWebResolver {
-            client: Client::builder().build::<_, Body>(HttpConnector::new()),
+            client: http_client(),
             scheme: Scheme::HTTP,
         }
     }
@@ -37,7 +40,7 @@ impl DidWebResolver<HttpConnector> {
 impl DidWebResolver<HttpsConnector<HttpConnector>> {
     pub fn https() -> DidWebResolver<HttpsConnector<HttpConnector>> {
         DidWebResolver {
-            client: Client::builder().build::<_, Body>(HttpsConnector::new()),
+            client: https_client(),
             scheme: Scheme::HTTPS,
         }
     }
@@ -60,6 +63,26 @@ where
     }
 }
 
+/// Host and path of the DID document of a did:web DID.
+pub(crate) fn document_location(did: &Did) -> Result<(String, String), DidWebError> {
+    let did_parts: Vec<&str> = did.id().split(':').collect();
+
+    if did_parts.is_empty() {
+        return Err(DidWebError::InvalidDid(did.id().to_string()));
+    }
+
+    let domain = did_parts[0].replace("%3A", ":");
+
+    let path_parts = &did_parts[1..];
+    let path_and_query = if path_parts.is_empty() {
+        "/.well-known/did.json".to_string()
+    } else {
+        let path = path_parts.join("/");
+        format!("/{}/did.json", path)
+    };
+    Ok((domain, path_and_query))
+}
+
 #[async_trait]
 impl<C> DidResolvable for DidWebResolver<C>
 where
@@ -82,29 +105,14 @@ where
             )));
         }
 
-        if let Some(accept) = options.accept() {
-            if accept != &MediaType::DidJson {
-                return Err(Box::new(DidWebError::RepresentationNotSupported(
-                    accept.to_string(),
-                )));
-            }
-        }
-
-        let did_parts: Vec<&str> = did.id().split(':').collect();
-
-        if did_parts.is_empty() {
-            return Err(Box::new(DidWebError::InvalidDid(did.id().to_string())));
+        let media_type = options.accept().unwrap_or(&MediaType::DidJson);
+        if !matches!(media_type, MediaType::DidJson | MediaType::DidLdJson) {
+            return Err(Box::new(DidWebError::RepresentationNotSupported(
+                media_type.to_string(),
+            )));
         }
 
-        let domain = did_parts[0].replace("%3A", ":");
-
-        let path_parts = &did_parts[1..];
-        let path_and_query = if path_parts.is_empty() {
-            "/.well-known/did.json".to_string()
-        } else {
-            let path = path_parts.join("/");
-            format!("/{}/did.json", path)
-        };
+        let (domain, path_and_query) = document_location(did)?;
         let url = uri::Builder::new()
             .scheme(self.scheme.clone())
             .authority(domain.as_str())
@@ -118,6 +126,6 @@ where
             .did_document_metadata(DidDocumentMetadata::default())
             .build();
 
-        Ok(did_resolution_output)
+        Ok(did_resolution_output.into_representation(media_type))
     }
 }
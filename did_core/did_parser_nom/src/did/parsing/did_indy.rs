@@ -0,0 +1,107 @@
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{char, satisfy},
+    combinator::{not, opt, peek, recognize},
+    sequence::{delimited, preceded, terminated, tuple},
+    IResult,
+};
+
+use super::{did_sov::parse_unqualified_sovrin_did, DidPart};
+
+// namespace-identifier = 1*(%x61-7A / DIGIT / "-")
+fn namespace_identifier(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')(input)
+}
+
+// namespace = namespace-identifier [":" namespace-identifier] ":"
+fn indy_namespace(input: &str) -> IResult<&str, &str> {
+    // the sub-namespace is only taken if followed by ":", so that an nsid starting with lowercase
+    // letters or digits is not mistaken for one
+    terminated(
+        recognize(tuple((
+            namespace_identifier,
+            opt(preceded(
+                char(':'),
+                terminated(namespace_identifier, peek(char(':'))),
+            )),
+        ))),
+        char(':'),
+    )(input)
+}
+
+// nsid = 21*22(base58char), not followed by further idchars
+fn nsid(input: &str) -> IResult<&str, &str> {
+    terminated(
+        parse_unqualified_sovrin_did,
+        peek(not(satisfy(|c| {
+            c.is_ascii_alphanumeric() || "._-%:".contains(c)
+        }))),
+    )(input)
+}
+
+// indy-did = "did:indy:" namespace nsid
+pub(super) fn parse_did_indy(input: &str) -> IResult<&str, DidPart> {
+    fn did_indy_method(input: &str) -> IResult<&str, &str> {
+        delimited(char(':'), tag("indy"), char(':'))(input)
+    }
+
+    let (input, (did, method, namespace, id)) =
+        tuple((tag("did"), did_indy_method, indy_namespace, nsid))(input)?;
+    Ok((input, (did, method, Some(namespace), id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_did_indy() {
+        assert_eq!(
+            Ok((
+                "",
+                ("did", "indy", Some("sovrin"), "7Tqg6BwSSWapxgUDm9KKgg")
+            )),
+            parse_did_indy("did:indy:sovrin:7Tqg6BwSSWapxgUDm9KKgg")
+        );
+        assert_eq!(
+            Ok((
+                "",
+                ("did", "indy", Some("sovrin"), "2wJPyULfLLnYTEFYzByfUR")
+            )),
+            parse_did_indy("did:indy:sovrin:2wJPyULfLLnYTEFYzByfUR")
+        );
+        assert_eq!(
+            Ok((
+                "/anoncreds/v0/SCHEMA/gvt/1.0",
+                (
+                    "did",
+                    "indy",
+                    Some("sovrin:staging"),
+                    "WRfXPg8dantKVubE3HX8pw"
+                )
+            )),
+            parse_did_indy(
+                "did:indy:sovrin:staging:WRfXPg8dantKVubE3HX8pw/anoncreds/v0/SCHEMA/gvt/1.0"
+            )
+        );
+        assert_eq!(
+            Ok((
+                "",
+                (
+                    "did",
+                    "indy",
+                    Some("bcovrin-test:2"),
+                    "5nDyJVP1NrcPAttP3xwMB9"
+                )
+            )),
+            parse_did_indy("did:indy:bcovrin-test:2:5nDyJVP1NrcPAttP3xwMB9")
+        );
+    }
+
+    #[test]
+    fn test_parse_did_indy_rejects_non_indy_ids() {
+        assert!(parse_did_indy("did:indy:sovrin:alpha:%0Aqg6BwS.Wapxg-Dm9K_gg").is_err());
+        assert!(parse_did_indy("did:indy:sovrin:a:b:7Tqg6BwSSWapxgUDm9KKgg").is_err());
+        assert!(parse_did_indy("did:indy:Sovrin:7Tqg6BwSSWapxgUDm9KKgg").is_err());
+    }
+}
@@ -25,6 +25,12 @@ test_cases_negative! {
         "did:indy:s@vrin:7Tqg6BwSSWapxgUDm9KKgg"
     indy_multiple_namespaces_invalid_char_in_method_specific_id:
         "did:indy:sovrin:alpha:%0zqg6BwS.Wapxg-Dm9K_gg"
+    indy_empty_namespace:
+        "did:indy::7Tqg6BwSSWapxgUDm9KKgg"
+    indy_no_nsid:
+        "did:indy:sovrin:"
+    indy_invalid_char_in_nsid:
+        "did:indy:sovrin:7Tqg6BwSSWapxg UDm9KKgg"
     sov_invalid_len:
         "did:sov:2wJPyULfLLnYTEFYzByf"
     sov_invalid_char:
@@ -66,6 +66,24 @@ test_cases_positive! {
         Some("indy"),
         Some("sovrin:alpha"),
         "%0Aqg6BwS.Wapxg-Dm9K_gg"
+    test_case8_1:
+        "did:indy:bcovrin-test:WRfXPg8dantKVubE3HX8pw",
+        "did:indy:bcovrin-test:WRfXPg8dantKVubE3HX8pw",
+        Some("indy"),
+        Some("bcovrin-test"),
+        "WRfXPg8dantKVubE3HX8pw"
+    test_case8_2:
+        "did:indy:sovrin:staging:WRfXPg8dantKVubE3HX8pw",
+        "did:indy:sovrin:staging:WRfXPg8dantKVubE3HX8pw",
+        Some("indy"),
+        Some("sovrin:staging"),
+        "WRfXPg8dantKVubE3HX8pw"
+    test_case8_3:
+        "did:indy:sovrin:staging:2wJPyULfLLnYTEFYzByfUR",
+        "did:indy:sovrin:staging:2wJPyULfLLnYTEFYzByfUR",
+        Some("indy"),
+        Some("sovrin:staging"),
+        "2wJPyULfLLnYTEFYzByfUR"
     test_case9:
         "did:sov:builder:VbPQNHsvoLZdaNU7fTBeFx",
         "did:sov:builder:VbPQNHsvoLZdaNU7fTBeFx",
@@ -0,0 +1,43 @@
+pub extern crate did_resolver;
+
+pub mod registration_options;
+pub mod registration_output;
+
+use aries_vcx_core::wallet::base_wallet::BaseWallet;
+use async_trait::async_trait;
+use did_resolver::{did_parser::Did, error::GenericError};
+
+use self::{
+    registration_options::DidRegistrationOptions, registration_output::DidRegistrationOutput,
+};
+
+/// Counterpart of [`DidResolvable`](did_resolver::traits::resolvable::DidResolvable) for writing
+/// DIDs of a DID method. Keys of the DIDs are created in and used from the wallet passed to each
+/// operation; they are never part of the output.
+#[async_trait]
+pub trait DidRegistrar {
+    type ExtraFieldsService: Default;
+    type CreateOptions: Default + Send + Sync;
+    type UpdateOptions: Default + Send + Sync;
+    type DeactivateOptions: Default + Send + Sync;
+
+    async fn create(
+        &self,
+        wallet: &impl BaseWallet,
+        options: &DidRegistrationOptions<Self::CreateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError>;
+
+    async fn update(
+        &self,
+        wallet: &impl BaseWallet,
+        did: &Did,
+        options: &DidRegistrationOptions<Self::UpdateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError>;
+
+    async fn deactivate(
+        &self,
+        wallet: &impl BaseWallet,
+        did: &Did,
+        options: &DidRegistrationOptions<Self::DeactivateOptions>,
+    ) -> Result<DidRegistrationOutput<Self::ExtraFieldsService>, GenericError>;
+}
@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidRegistrationOptions<E> {
+    /// Seed of the keys generated by the operation, which are random otherwise.
+    #[serde(default)]
+    seed: Option<String>,
+    extra: E,
+}
+
+impl<E> DidRegistrationOptions<E> {
+    pub fn new(extra: E) -> Self {
+        Self { seed: None, extra }
+    }
+
+    pub fn set_seed(mut self, seed: String) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn seed(&self) -> Option<&str> {
+        self.seed.as_deref()
+    }
+
+    pub fn extra(&self) -> &E {
+        &self.extra
+    }
+}
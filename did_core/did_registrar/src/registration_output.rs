@@ -0,0 +1,92 @@
+use did_resolver::{
+    did_doc::schema::did_doc::DidDocument, did_parser::Did,
+    shared_types::did_document_metadata::DidDocumentMetadata,
+};
+use serde::{Deserialize, Serialize};
+
+/// Action the client has to take for an operation to take effect.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "action")]
+pub enum DidRegistrationAction {
+    /// Serve `content` at `url`, e.g. the `did.json` of a did:web DID.
+    Publish { url: String, content: String },
+    /// Stop serving the resource at `url`.
+    Unpublish { url: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase", tag = "state")]
+pub enum DidRegistrationState {
+    Finished,
+    Action(DidRegistrationAction),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidRegistrationOutput<E: Default> {
+    pub did: Did,
+    pub state: DidRegistrationState,
+    pub did_document: Option<DidDocument<E>>,
+    pub did_document_metadata: DidDocumentMetadata,
+}
+
+impl<E: Default> DidRegistrationOutput<E> {
+    pub fn builder(did: Did) -> DidRegistrationOutputBuilder<E> {
+        DidRegistrationOutputBuilder {
+            did,
+            state: DidRegistrationState::Finished,
+            did_document: None,
+            did_document_metadata: None,
+        }
+    }
+
+    pub fn did(&self) -> &Did {
+        &self.did
+    }
+
+    pub fn state(&self) -> &DidRegistrationState {
+        &self.state
+    }
+
+    /// DID document as of the completed operation, if the registrar can tell.
+    pub fn did_document(&self) -> Option<&DidDocument<E>> {
+        self.did_document.as_ref()
+    }
+
+    pub fn did_document_metadata(&self) -> &DidDocumentMetadata {
+        &self.did_document_metadata
+    }
+}
+
+pub struct DidRegistrationOutputBuilder<E: Default> {
+    did: Did,
+    state: DidRegistrationState,
+    did_document: Option<DidDocument<E>>,
+    did_document_metadata: Option<DidDocumentMetadata>,
+}
+
+impl<E: Default> DidRegistrationOutputBuilder<E> {
+    pub fn state(mut self, state: DidRegistrationState) -> Self {
+        self.state = state;
+        self
+    }
+
+    pub fn did_document(mut self, did_document: DidDocument<E>) -> Self {
+        self.did_document = Some(did_document);
+        self
+    }
+
+    pub fn did_document_metadata(mut self, did_document_metadata: DidDocumentMetadata) -> Self {
+        self.did_document_metadata = Some(did_document_metadata);
+        self
+    }
+
+    pub fn build(self) -> DidRegistrationOutput<E> {
+        DidRegistrationOutput {
+            did: self.did,
+            state: self.state,
+            did_document: self.did_document,
+            did_document_metadata: self.did_document_metadata.unwrap_or_default(),
+        }
+    }
+}
@@ -1,8 +1,14 @@
+use serde::{Deserialize, Serialize};
+
 use super::dereferencing_error::DidDereferencingError;
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+#[serde(rename_all = "camelCase")]
 pub struct DidDereferencingMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
     content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<DidDereferencingError>,
 }
 
@@ -3,9 +3,16 @@ use serde::{Deserialize, Serialize};
 use super::resolution_error::DidResolutionError;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+#[serde(default)]
+#[serde(rename_all = "camelCase")]
 pub struct DidResolutionMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
     content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<DidResolutionError>,
+    /// Name of the resolver which produced the result, if resolved through a registry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolver: Option<String>,
 }
 
 impl DidResolutionMetadata {
@@ -20,12 +27,27 @@ impl DidResolutionMetadata {
     pub fn error(&self) -> Option<&DidResolutionError> {
         self.error.as_ref()
     }
+
+    pub fn resolver(&self) -> Option<&str> {
+        self.resolver.as_deref()
+    }
+
+    pub fn set_content_type(mut self, content_type: String) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    pub fn set_resolver(mut self, resolver: String) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
 }
 
 #[derive(Default)]
 pub struct DidResolutionMetadataBuilder {
     content_type: Option<String>,
     error: Option<DidResolutionError>,
+    resolver: Option<String>,
 }
 
 impl DidResolutionMetadataBuilder {
@@ -39,10 +61,16 @@ impl DidResolutionMetadataBuilder {
         self
     }
 
+    pub fn resolver(mut self, resolver: String) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
     pub fn build(self) -> DidResolutionMetadata {
         DidResolutionMetadata {
             content_type: self.content_type,
             error: self.error,
+            resolver: self.resolver,
         }
     }
 }
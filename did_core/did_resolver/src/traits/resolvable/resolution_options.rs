@@ -1,10 +1,18 @@
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 use crate::shared_types::media_type::MediaType;
 
 #[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DidResolutionOptions<E> {
     accept: Option<MediaType>,
+    /// Method specific identifier of the DID document version to resolve.
+    #[serde(default)]
+    version_id: Option<String>,
+    /// Resolves the DID document version which was current at the given time.
+    #[serde(default)]
+    version_time: Option<DateTime<Utc>>,
     extra: E,
 }
 
@@ -12,6 +20,8 @@ impl<E> DidResolutionOptions<E> {
     pub fn new(extra: E) -> Self {
         Self {
             accept: None,
+            version_id: None,
+            version_time: None,
             extra,
         }
     }
@@ -21,11 +31,39 @@ impl<E> DidResolutionOptions<E> {
         self
     }
 
+    pub fn set_version_id(mut self, version_id: String) -> Self {
+        self.version_id = Some(version_id);
+        self
+    }
+
+    pub fn set_version_time(mut self, version_time: DateTime<Utc>) -> Self {
+        self.version_time = Some(version_time);
+        self
+    }
+
     pub fn accept(&self) -> Option<&MediaType> {
         self.accept.as_ref()
     }
 
+    pub fn version_id(&self) -> Option<&str> {
+        self.version_id.as_deref()
+    }
+
+    pub fn version_time(&self) -> Option<DateTime<Utc>> {
+        self.version_time
+    }
+
     pub fn extra(&self) -> &E {
         &self.extra
     }
+
+    /// Replaces the method specific options, keeping the generic ones.
+    pub fn with_extra<F>(&self, extra: F) -> DidResolutionOptions<F> {
+        DidResolutionOptions {
+            accept: self.accept.clone(),
+            version_id: self.version_id.clone(),
+            version_time: self.version_time,
+            extra,
+        }
+    }
 }
@@ -2,7 +2,7 @@ use did_doc::schema::did_doc::DidDocument;
 use serde::{Deserialize, Serialize};
 
 use super::resolution_metadata::DidResolutionMetadata;
-use crate::shared_types::did_document_metadata::DidDocumentMetadata;
+use crate::shared_types::{did_document_metadata::DidDocumentMetadata, media_type::MediaType};
 
 // This struct is only returned in the happy case. In the error case, user may convert
 // DidSovError into DidResolutionMetadata, as DidResolutionMetadata is be the only
@@ -35,6 +35,19 @@ impl<E: Default> DidResolutionOutput<E> {
     pub fn did_document_metadata(&self) -> &DidDocumentMetadata {
         &self.did_document_metadata
     }
+
+    /// Output with the DID document in the given representation, reported as the content type
+    /// of the resolution result. The JSON-LD representation carries the contexts the document
+    /// requires.
+    pub fn into_representation(mut self, media_type: &MediaType) -> Self {
+        if media_type == &MediaType::DidLdJson {
+            self.did_document = self.did_document.with_json_ld_context();
+        }
+        self.did_resolution_metadata = self
+            .did_resolution_metadata
+            .set_content_type(media_type.to_string());
+        self
+    }
 }
 
 pub struct DidResolutionOutputBuilder<E: Default> {
@@ -1,9 +1,14 @@
 use std::error::Error;
 
+use did_resolver::error::GenericError;
+
 #[derive(Debug)]
 pub enum DidResolverRegistryError {
     UnsupportedMethod,
     UnqualifiedDid,
+    Timeout(String),
+    /// Errors of each resolver tried, by resolver name, in the order they were tried.
+    ResolversFailed(Vec<(String, GenericError)>),
 }
 
 impl std::fmt::Display for DidResolverRegistryError {
@@ -13,8 +18,27 @@ impl std::fmt::Display for DidResolverRegistryError {
             DidResolverRegistryError::UnqualifiedDid => {
                 write!(f, "Attempted to resolve unqualified DID")
             }
+            DidResolverRegistryError::Timeout(resolver) => {
+                write!(f, "Resolver {resolver} timed out")
+            }
+            DidResolverRegistryError::ResolversFailed(errors) => {
+                write!(f, "All resolvers failed:")?;
+                for (resolver, err) in errors {
+                    write!(f, " {resolver}: {err};")?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
-impl Error for DidResolverRegistryError {}
+impl Error for DidResolverRegistryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DidResolverRegistryError::ResolversFailed(errors) => errors
+                .last()
+                .map(|(_, err)| err.as_ref() as &(dyn Error + 'static)),
+            _ => None,
+        }
+    }
+}
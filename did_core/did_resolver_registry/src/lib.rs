@@ -1,6 +1,6 @@
 pub mod error;
 
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use async_trait::async_trait;
 use did_resolver::{
@@ -8,8 +8,8 @@ use did_resolver::{
     did_parser::Did,
     error::GenericError,
     traits::resolvable::{
-        resolution_options::DidResolutionOptions, resolution_output::DidResolutionOutput,
-        DidResolvable,
+        resolution_error::DidResolutionError, resolution_options::DidResolutionOptions,
+        resolution_output::DidResolutionOutput, DidResolvable,
     },
 };
 use error::DidResolverRegistryError;
@@ -19,10 +19,50 @@ use serde_json::Value;
 // TODO: Use serde_json::Map instead
 pub type GenericMap = HashMap<String, Value>;
 pub type GenericResolver = dyn DidResolvableAdaptorTrait + Send + Sync;
+pub type ErrorClassifier = fn(&GenericError) -> DidResolutionError;
 
+/// Registry of DID resolvers by method. Resolvers registered for the same method are tried in
+/// order of registration, followed by those registered for wildcard patterns matching the
+/// method, and fallback resolvers handle methods no resolver is registered for.
 #[derive(Default)]
 pub struct ResolverRegistry {
-    resolvers: HashMap<String, Box<GenericResolver>>,
+    resolvers: HashMap<String, Vec<RegisteredResolver>>,
+    wildcard_patterns: Vec<String>,
+    fallback_resolvers: Vec<RegisteredResolver>,
+    timeouts: HashMap<String, Duration>,
+    error_classifier: Option<ErrorClassifier>,
+}
+
+struct RegisteredResolver {
+    name: String,
+    resolver: Box<GenericResolver>,
+}
+
+impl RegisteredResolver {
+    fn new<T>(resolver: T) -> Self
+    where
+        T: DidResolvable + 'static + Send + Sync,
+        for<'de> <T as DidResolvable>::ExtraFieldsService:
+            Send + Sync + Serialize + Deserialize<'de>,
+        for<'de> <T as DidResolvable>::ExtraFieldsOptions:
+            Send + Sync + Serialize + Deserialize<'de>,
+    {
+        Self {
+            name: resolver_name::<T>(),
+            resolver: Box::new(DidResolvableAdaptor { inner: resolver }),
+        }
+    }
+}
+
+/// Type name of the resolver without its module path and type parameters, e.g. `DidSovResolver`.
+fn resolver_name<T>() -> String {
+    let type_name = std::any::type_name::<T>();
+    let type_name = type_name.split('<').next().unwrap_or(type_name);
+    type_name
+        .rsplit("::")
+        .next()
+        .unwrap_or(type_name)
+        .to_string()
 }
 
 pub struct DidResolvableAdaptor<T: DidResolvable> {
@@ -56,7 +96,7 @@ where
         };
         let result_inner = self
             .inner
-            .resolve(did, &DidResolutionOptions::new(options_inner))
+            .resolve(did, &options.with_extra(options_inner))
             .await?;
 
         let did_document_inner_hashmap = serde_json::to_value(result_inner.did_document())
@@ -80,6 +120,9 @@ impl ResolverRegistry {
         Self::default()
     }
 
+    /// Registers a resolver for the method, to be tried after the ones registered before it. The
+    /// method may be a wildcard pattern, in which `*` matches any characters, e.g. `*` for all
+    /// methods.
     pub fn register_resolver<T>(mut self, method: String, resolver: T) -> Self
     where
         T: DidResolvable + 'static + Send + Sync,
@@ -88,16 +131,64 @@ impl ResolverRegistry {
         for<'de> <T as DidResolvable>::ExtraFieldsOptions:
             Send + Sync + Serialize + Deserialize<'de>,
     {
-        let adaptor = DidResolvableAdaptor { inner: resolver };
-        self.resolvers.insert(method, Box::new(adaptor));
+        if method.contains('*') && !self.wildcard_patterns.contains(&method) {
+            self.wildcard_patterns.push(method.clone());
+        }
+        self.resolvers
+            .entry(method)
+            .or_default()
+            .push(RegisteredResolver::new(resolver));
         self
     }
 
+    /// Registers a resolver for DIDs of methods with no resolver registered, e.g. a universal
+    /// resolver. Fallback resolvers are tried in order of registration.
+    pub fn register_fallback_resolver<T>(mut self, resolver: T) -> Self
+    where
+        T: DidResolvable + 'static + Send + Sync,
+        for<'de> <T as DidResolvable>::ExtraFieldsService:
+            Send + Sync + Serialize + Deserialize<'de>,
+        for<'de> <T as DidResolvable>::ExtraFieldsOptions:
+            Send + Sync + Serialize + Deserialize<'de>,
+    {
+        self.fallback_resolvers
+            .push(RegisteredResolver::new(resolver));
+        self
+    }
+
+    /// Unregisters all resolvers of the method, or of the wildcard pattern.
     pub fn unregister_resolver(mut self, method: &str) -> Self {
         self.resolvers.remove(method);
+        self.wildcard_patterns.retain(|pattern| pattern != method);
         self
     }
 
+    /// Limits the time each resolver may take to resolve a DID of the method.
+    ///
+    /// The timeout is driven by the Tokio timer, so DIDs of the method must be resolved within a
+    /// Tokio runtime with time enabled, e.g. one built with `enable_all`, or resolution panics.
+    pub fn set_timeout(mut self, method: String, timeout: Duration) -> Self {
+        self.timeouts.insert(method, timeout);
+        self
+    }
+
+    /// Sets how errors of the resolvers are classified, which decides whether the next resolver
+    /// is tried. Unless set, only errors which are a bare [`DidResolutionError`] are told apart
+    /// and any other error is taken for an internal error.
+    pub fn set_error_classifier(mut self, error_classifier: ErrorClassifier) -> Self {
+        self.error_classifier = Some(error_classifier);
+        self
+    }
+
+    /// Resolves the DID with the resolvers of its method and those of matching wildcard
+    /// patterns or, if there are none, with the fallback resolvers, returning the first result.
+    /// The name of the resolver which produced it is reported in its resolution metadata.
+    ///
+    /// The next resolver is only tried if a resolver does not support the DID, times out or
+    /// fails internally, e.g. on a transport error; it is not if the DID is invalid or not
+    /// found, nor if the DID is resolved as deactivated. The error returned is that of the only
+    /// resolver tried or, if several were, [`DidResolverRegistryError::ResolversFailed`] with
+    /// the errors of all of them.
     pub async fn resolve(
         &self,
         did: &Did,
@@ -105,14 +196,94 @@ impl ResolverRegistry {
     ) -> Result<DidResolutionOutput<GenericMap>, GenericError> {
         let method = did
             .method()
-            .ok_or(DidResolverRegistryError::UnsupportedMethod)?;
-        match self.resolvers.get(method) {
-            Some(resolver) => resolver.resolve(did, options).await,
-            None => Err(Box::new(DidResolverRegistryError::UnsupportedMethod)),
+            .ok_or(DidResolverRegistryError::UnqualifiedDid)?;
+        let mut resolvers = self
+            .resolvers
+            .get(method)
+            .into_iter()
+            .chain(
+                self.wildcard_patterns
+                    .iter()
+                    .filter(|pattern| *pattern != method && matches_pattern(pattern, method))
+                    .filter_map(|pattern| self.resolvers.get(pattern)),
+            )
+            .flatten()
+            .collect::<Vec<_>>();
+        if resolvers.is_empty() {
+            resolvers = self.fallback_resolvers.iter().collect();
+        }
+
+        let mut errors = Vec::new();
+        for RegisteredResolver { name, resolver } in resolvers {
+            let result = match self.timeouts.get(method) {
+                Some(timeout) => {
+                    match tokio::time::timeout(*timeout, resolver.resolve(did, options)).await {
+                        Ok(result) => result,
+                        Err(_) => Err(DidResolverRegistryError::Timeout(name.clone()).into()),
+                    }
+                }
+                None => resolver.resolve(did, options).await,
+            };
+            match result {
+                Ok(mut output) => {
+                    output.did_resolution_metadata =
+                        output.did_resolution_metadata.set_resolver(name.clone());
+                    return Ok(output);
+                }
+                Err(err) => {
+                    let definitive = !matches!(
+                        self.classify_error(&err),
+                        DidResolutionError::MethodNotSupported
+                            | DidResolutionError::RepresentationNotSupported
+                            | DidResolutionError::InternalError
+                    );
+                    errors.push((name.clone(), err));
+                    if definitive {
+                        break;
+                    }
+                }
+            }
+        }
+        match errors.len() {
+            0 => Err(Box::new(DidResolverRegistryError::UnsupportedMethod)),
+            1 => Err(errors.remove(0).1),
+            _ => Err(Box::new(DidResolverRegistryError::ResolversFailed(errors))),
+        }
+    }
+
+    fn classify_error(&self, err: &GenericError) -> DidResolutionError {
+        match err.downcast_ref::<DidResolverRegistryError>() {
+            Some(DidResolverRegistryError::Timeout(_)) => DidResolutionError::InternalError,
+            _ => match self.error_classifier {
+                Some(error_classifier) => error_classifier(err),
+                None => err
+                    .downcast_ref::<DidResolutionError>()
+                    .cloned()
+                    .unwrap_or(DidResolutionError::InternalError),
+            },
         }
     }
 }
 
+/// Whether the method matches the wildcard pattern, in which `*` matches any characters.
+fn matches_pattern(pattern: &str, method: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = parts.next().and_then(|prefix| method.strip_prefix(prefix)) else {
+        return false;
+    };
+    let mut parts = parts.collect::<Vec<_>>();
+    let Some(suffix) = parts.pop() else {
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(suffix)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{error::Error, pin::Pin};
@@ -301,4 +472,180 @@ mod tests {
             .await;
         assert!(result_after.is_ok());
     }
+
+    struct SlowDidResolver;
+
+    #[async_trait]
+    impl DidResolvable for SlowDidResolver {
+        type ExtraFieldsService = ();
+        type ExtraFieldsOptions = ();
+
+        async fn resolve(
+            &self,
+            did: &Did,
+            _options: &DidResolutionOptions<()>,
+        ) -> Result<DidResolutionOutput<()>, GenericError> {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            Ok(DidResolutionOutput::builder(DidDocumentBuilder::new(did.clone()).build()).build())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_with_fallback_resolver() {
+        let did = Did::parse("did:unknown:1234".to_string()).unwrap();
+
+        let registry = ResolverRegistry::new()
+            .register_resolver("example".to_string(), SlowDidResolver)
+            .register_fallback_resolver(DummyDidResolver);
+        let output = registry
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(&did, output.did_document().id());
+        assert_eq!(
+            Some("DummyDidResolver"),
+            output.did_resolution_metadata().resolver()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_tries_resolvers_in_order() {
+        let did = Did::parse("did:example:1234".to_string()).unwrap();
+        let method = did.method().unwrap().to_string();
+
+        let mut mock_resolver = MockDummyDidResolver::new();
+        mock_resolver
+            .expect_resolve()
+            .times(1)
+            .return_once(move |_, _| {
+                let future = async move {
+                    Err::<DidResolutionOutput<()>, GenericError>(Box::new(DummyResolverError))
+                };
+                Pin::from(Box::new(future))
+            });
+
+        let registry = ResolverRegistry::new()
+            .register_resolver(method.clone(), mock_resolver)
+            .register_resolver(method, DummyDidResolver);
+        let output = registry
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some("DummyDidResolver"),
+            output.did_resolution_metadata().resolver()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_timeout() {
+        let did = Did::parse("did:example:1234".to_string()).unwrap();
+        let method = did.method().unwrap().to_string();
+
+        let registry = ResolverRegistry::new()
+            .register_resolver(method.clone(), SlowDidResolver)
+            .set_timeout(method, Duration::from_millis(10));
+        let error = registry
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(
+                error.downcast_ref::<DidResolverRegistryError>(),
+                Some(DidResolverRegistryError::Timeout(resolver)) if resolver == "SlowDidResolver"
+            ),
+            "Error is not a timeout of SlowDidResolver"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_stops_on_not_found() {
+        let did = Did::parse("did:example:1234".to_string()).unwrap();
+        let method = did.method().unwrap().to_string();
+
+        let mut mock_resolver = MockDummyDidResolver::new();
+        mock_resolver
+            .expect_resolve()
+            .times(1)
+            .return_once(move |_, _| {
+                let future = async move {
+                    Err::<DidResolutionOutput<()>, GenericError>(Box::new(
+                        DidResolutionError::NotFound,
+                    ))
+                };
+                Pin::from(Box::new(future))
+            });
+        let mut next_resolver = MockDummyDidResolver::new();
+        next_resolver.expect_resolve().never();
+
+        let registry = ResolverRegistry::new()
+            .register_resolver(method.clone(), mock_resolver)
+            .register_resolver(method, next_resolver);
+        let error = registry
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.downcast_ref::<DidResolutionError>(),
+            Some(DidResolutionError::NotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_keeps_errors_of_all_resolvers() {
+        let did = Did::parse("did:example:1234".to_string()).unwrap();
+        let method = did.method().unwrap().to_string();
+
+        let registry = ResolverRegistry::new()
+            .register_resolver(method.clone(), SlowDidResolver)
+            .register_resolver(method.clone(), SlowDidResolver)
+            .set_timeout(method, Duration::from_millis(10));
+        let error = registry
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+            .unwrap_err();
+
+        match error.downcast_ref::<DidResolverRegistryError>() {
+            Some(DidResolverRegistryError::ResolversFailed(errors)) => {
+                assert_eq!(2, errors.len());
+                assert!(errors.iter().all(|(name, _)| name == "SlowDidResolver"));
+            }
+            _ => panic!("Error is not a failure of all resolvers: {error}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_with_wildcard_resolver() {
+        let did = Did::parse("did:example:1234".to_string()).unwrap();
+
+        let registry = ResolverRegistry::new()
+            .register_resolver("ex*le".to_string(), DummyDidResolver)
+            .register_fallback_resolver(SlowDidResolver);
+        let output = registry
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            Some("DummyDidResolver"),
+            output.did_resolution_metadata().resolver()
+        );
+
+        let registry = registry.unregister_resolver("ex*le");
+        assert!(registry.wildcard_patterns.is_empty());
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("*", "example"));
+        assert!(matches_pattern("ex*", "example"));
+        assert!(matches_pattern("*ple", "example"));
+        assert!(matches_pattern("e*a*e", "example"));
+        assert!(!matches_pattern("ex*", "web"));
+        assert!(!matches_pattern("e*x*x", "example"));
+        assert!(!matches_pattern("example*e", "example"));
+    }
 }
@@ -0,0 +1,33 @@
+use did_resolver_service::config::ServiceConfig;
+use log::info;
+
+#[tokio::main]
+async fn main() {
+    load_dot_env();
+    setup_logging();
+    let config = ServiceConfig::from_env();
+    info!(
+        "Starting DID resolver service for methods {:?} on {}",
+        config.methods, config.endpoint_root
+    );
+    let registry = config.build_registry().unwrap();
+    let app_router = did_resolver_service::http_routes::build_router(registry);
+    axum::Server::bind(
+        &config
+            .endpoint_root
+            .parse()
+            .expect("Pass an address to listen on like IP:PORT"),
+    )
+    .serve(app_router.into_make_service())
+    .await
+    .unwrap();
+}
+
+fn setup_logging() {
+    let env = env_logger::Env::default().default_filter_or("info");
+    env_logger::init_from_env(env);
+}
+
+fn load_dot_env() {
+    let _ = dotenvy::dotenv();
+}
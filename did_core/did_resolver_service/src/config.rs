@@ -0,0 +1,86 @@
+use std::{sync::Arc, time::Duration};
+
+use aries_vcx_core::ledger::indy_vdr_ledger::{
+    build_ledger_components, DefaultIndyLedgerRead, VcxPoolConfig,
+};
+use did_jwk::resolver::DidJwkResolver;
+use did_peer::resolver::PeerDidResolver;
+use did_resolver_registry::ResolverRegistry;
+use did_resolver_sov::resolution::DidSovResolver;
+use did_resolver_universal::resolver::UniversalResolver;
+use did_resolver_web::resolution::resolver::DidWebResolver;
+
+use crate::error::{resolution_error, ServiceError};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ServiceConfig {
+    pub endpoint_root: String,
+    pub methods: Vec<String>,
+    pub genesis_file_path: Option<String>,
+    pub universal_resolver_url: Option<String>,
+    pub resolution_timeout: Option<Duration>,
+}
+
+impl ServiceConfig {
+    pub fn from_env() -> Self {
+        let endpoint_root = std::env::var("ENDPOINT_ROOT").unwrap_or("127.0.0.1:8080".into());
+        let methods = std::env::var("DID_METHODS")
+            .unwrap_or("peer,jwk,web".into())
+            .split(',')
+            .map(|method| method.trim().to_string())
+            .filter(|method| !method.is_empty())
+            .collect();
+        let resolution_timeout = std::env::var("RESOLUTION_TIMEOUT_MS")
+            .ok()
+            .and_then(|timeout| timeout.parse().ok())
+            .map(Duration::from_millis);
+        Self {
+            endpoint_root,
+            methods,
+            genesis_file_path: std::env::var("GENESIS_FILE_PATH").ok(),
+            universal_resolver_url: std::env::var("UNIVERSAL_RESOLVER_URL").ok(),
+            resolution_timeout,
+        }
+    }
+
+    /// Registry with a resolver registered for each of the configured methods, and the universal
+    /// resolver, if configured, as the fallback for any other method.
+    pub fn build_registry(&self) -> Result<ResolverRegistry, ServiceError> {
+        let mut registry = ResolverRegistry::new().set_error_classifier(resolution_error);
+        if let Some(universal_resolver_url) = &self.universal_resolver_url {
+            registry = registry.register_fallback_resolver(UniversalResolver::https(
+                universal_resolver_url.clone(),
+            ));
+        }
+        for method in &self.methods {
+            if let Some(timeout) = self.resolution_timeout {
+                registry = registry.set_timeout(method.clone(), timeout);
+            }
+            registry = match method.as_str() {
+                "peer" => registry.register_resolver(method.clone(), PeerDidResolver::new()),
+                "jwk" => registry.register_resolver(method.clone(), DidJwkResolver::new()),
+                "web" => registry.register_resolver(method.clone(), DidWebResolver::https()),
+                "sov" => {
+                    let genesis_file_path = self.genesis_file_path.clone().ok_or_else(|| {
+                        ServiceError::MissingConfiguration(
+                            "GENESIS_FILE_PATH is required to resolve did:sov".to_string(),
+                        )
+                    })?;
+                    let (ledger_read, _) = build_ledger_components(VcxPoolConfig {
+                        genesis_file_path,
+                        indy_vdr_config: None,
+                        response_cache_config: None,
+                    })?;
+                    registry.register_resolver(
+                        method.clone(),
+                        DidSovResolver::<Arc<DefaultIndyLedgerRead>, DefaultIndyLedgerRead>::new(
+                            Arc::new(ledger_read),
+                        ),
+                    )
+                }
+                _ => return Err(ServiceError::UnsupportedMethod(method.clone())),
+            };
+        }
+        Ok(registry)
+    }
+}
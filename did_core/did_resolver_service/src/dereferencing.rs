@@ -0,0 +1,130 @@
+use did_resolver::{
+    did_doc::schema::did_doc::DidDocument, did_parser::DidUrl,
+    traits::dereferenceable::dereferencing_error::DidDereferencingError,
+};
+use did_resolver_registry::GenericMap;
+use serde_json::Value;
+
+/// Resource a DID URL dereferences to within the DID document of its DID.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DereferencedResource {
+    /// The DID document itself, or a verification method or service of it.
+    Resource(Value),
+    /// Service endpoint selected by the `service` parameter, to redirect to.
+    ServiceEndpoint(String),
+}
+
+/// Dereferences the DID URL against the resolved DID document of its DID. Fragments select a
+/// verification method or service by id, the `service` and `relativeRef` parameters select a
+/// service endpoint; paths are not supported.
+pub fn dereference_did_document(
+    did_document: &DidDocument<GenericMap>,
+    did_url: &DidUrl,
+) -> Result<DereferencedResource, DidDereferencingError> {
+    let queries = did_url.queries();
+    if let Some(service_id) = queries.get("service") {
+        let service = did_document
+            .service()
+            .iter()
+            .find(|service| service.id().as_ref().ends_with(&format!("#{service_id}")))
+            .ok_or(DidDereferencingError::NotFound)?;
+        let mut endpoint = url::Url::from(service.service_endpoint().clone());
+        if let Some(relative_ref) = queries.get("relativeRef") {
+            endpoint = endpoint
+                .join(relative_ref)
+                .map_err(|_| DidDereferencingError::InvalidDid)?;
+        }
+        return Ok(DereferencedResource::ServiceEndpoint(endpoint.to_string()));
+    }
+    if did_url.path().is_some() {
+        return Err(DidDereferencingError::NotFound);
+    }
+
+    let Some(fragment) = did_url.fragment() else {
+        return serde_json::to_value(did_document)
+            .map(DereferencedResource::Resource)
+            .map_err(|_| DidDereferencingError::NotFound);
+    };
+    let did_url_string = did_url.to_string();
+    let fragment_string = format!("#{fragment}");
+    let id_matcher = |id: &str| id == did_url_string || id.ends_with(&fragment_string);
+
+    let service = did_document
+        .service()
+        .iter()
+        .find(|service| id_matcher(service.id().as_ref()));
+    let verification_method = did_document
+        .verification_method()
+        .iter()
+        .find(|vm| id_matcher(vm.id().did_url()));
+    let resource = match (service, verification_method) {
+        (Some(service), None) => serde_json::to_value(service),
+        (None, Some(verification_method)) => serde_json::to_value(verification_method),
+        (None, None) => return Err(DidDereferencingError::NotFound),
+        (Some(_), Some(_)) => return Err(DidDereferencingError::InvalidDid),
+    };
+    resource
+        .map(DereferencedResource::Resource)
+        .map_err(|_| DidDereferencingError::NotFound)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const DID: &str = "did:example:123456789abcdefghi";
+
+    fn did_document() -> DidDocument<GenericMap> {
+        serde_json::from_value(json!({
+            "id": DID,
+            "verificationMethod": [{
+                "id": format!("{DID}#keys-1"),
+                "type": "Ed25519VerificationKey2020",
+                "controller": DID,
+                "publicKeyMultibase": "z6MkmjY8GnV5i9YTDtPETC2uUAW6ejw3nk5mXF5yci5ab7th"
+            }],
+            "service": [{
+                "id": format!("{DID}#files"),
+                "type": "LinkedDomains",
+                "serviceEndpoint": "https://example.com/files/"
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_dereference_fragment() {
+        let did_url = DidUrl::parse(format!("{DID}#keys-1")).unwrap();
+
+        let DereferencedResource::Resource(resource) =
+            dereference_did_document(&did_document(), &did_url).unwrap()
+        else {
+            panic!("Expected a resource");
+        };
+        assert_eq!(format!("{DID}#keys-1"), resource["id"]);
+    }
+
+    #[test]
+    fn test_dereference_service_endpoint() {
+        let did_url = DidUrl::parse(format!("{DID}?service=files&relativeRef=report.pdf")).unwrap();
+
+        assert_eq!(
+            DereferencedResource::ServiceEndpoint(
+                "https://example.com/files/report.pdf".to_string()
+            ),
+            dereference_did_document(&did_document(), &did_url).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dereference_not_found() {
+        let did_url = DidUrl::parse(format!("{DID}#keys-2")).unwrap();
+
+        assert_eq!(
+            DidDereferencingError::NotFound,
+            dereference_did_document(&did_document(), &did_url).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use axum::http::StatusCode;
+use did_jwk::error::DidJwkError;
+use did_peer::error::DidPeerError;
+use did_resolver::{
+    did_doc::error::DidDocumentBuilderError,
+    did_parser::ParseError,
+    error::GenericError,
+    traits::{
+        dereferenceable::dereferencing_error::DidDereferencingError,
+        resolvable::resolution_error::DidResolutionError,
+    },
+};
+use did_resolver_registry::error::DidResolverRegistryError;
+use did_resolver_sov::error::DidSovError;
+use did_resolver_universal::error::UniversalResolverError;
+use did_resolver_web::error::DidWebError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ServiceError {
+    #[error("Unsupported DID method: {0}")]
+    UnsupportedMethod(String),
+    #[error("Missing configuration: {0}")]
+    MissingConfiguration(String),
+    #[error("Ledger error: {0}")]
+    LedgerError(#[from] aries_vcx_core::errors::error::AriesVcxCoreError),
+}
+
+/// Classifies an error of the registry or one of the registered resolvers.
+pub fn resolution_error(err: &GenericError) -> DidResolutionError {
+    if let Some(err) = err.downcast_ref::<DidResolverRegistryError>() {
+        return match err {
+            DidResolverRegistryError::UnsupportedMethod => DidResolutionError::MethodNotSupported,
+            DidResolverRegistryError::UnqualifiedDid => DidResolutionError::InvalidDid,
+            DidResolverRegistryError::Timeout(_) => DidResolutionError::InternalError,
+            DidResolverRegistryError::ResolversFailed(errors) => errors
+                .last()
+                .map(|(_, err)| resolution_error(err))
+                .unwrap_or(DidResolutionError::InternalError),
+        };
+    }
+    if err.is::<ParseError>() || err.is::<DidPeerError>() {
+        return DidResolutionError::InvalidDid;
+    }
+    if let Some(err) = err.downcast_ref::<DidSovError>() {
+        return err.into();
+    }
+    if let Some(err) = err.downcast_ref::<DidJwkError>() {
+        return match err {
+            DidJwkError::MethodNotSupported(_) => DidResolutionError::MethodNotSupported,
+            DidJwkError::DidDocumentBuilderError(DidDocumentBuilderError::UnsupportedKeyType(
+                _,
+            )) => DidResolutionError::UnsupportedPublicKeyType,
+            DidJwkError::DidDocumentBuilderError(_) => DidResolutionError::InvalidPublicKey,
+            _ => DidResolutionError::InvalidDid,
+        };
+    }
+    if let Some(UniversalResolverError::ResolutionFailed(err)) =
+        err.downcast_ref::<UniversalResolverError>()
+    {
+        return err.clone();
+    }
+    if let Some(err) = err.downcast_ref::<DidWebError>() {
+        return match err {
+            DidWebError::MethodNotSupported(_) => DidResolutionError::MethodNotSupported,
+            DidWebError::RepresentationNotSupported(_) => {
+                DidResolutionError::RepresentationNotSupported
+            }
+            DidWebError::InvalidDid(_) => DidResolutionError::InvalidDid,
+            DidWebError::NonSuccessResponse(StatusCode::NOT_FOUND) => DidResolutionError::NotFound,
+            _ => DidResolutionError::InternalError,
+        };
+    }
+    DidResolutionError::InternalError
+}
+
+pub fn resolution_status(error: &DidResolutionError) -> StatusCode {
+    match error {
+        DidResolutionError::InvalidDid => StatusCode::BAD_REQUEST,
+        DidResolutionError::NotFound => StatusCode::NOT_FOUND,
+        DidResolutionError::RepresentationNotSupported => StatusCode::NOT_ACCEPTABLE,
+        DidResolutionError::MethodNotSupported => StatusCode::NOT_IMPLEMENTED,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+pub fn dereferencing_status(error: &DidDereferencingError) -> StatusCode {
+    match error {
+        DidDereferencingError::InvalidDid => StatusCode::BAD_REQUEST,
+        DidDereferencingError::NotFound => StatusCode::NOT_FOUND,
+    }
+}
@@ -0,0 +1,332 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, RawQuery, State},
+    http::{
+        header::{ACCEPT, CONTENT_TYPE, LOCATION},
+        HeaderMap, StatusCode,
+    },
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use did_resolver::{
+    did_parser::{Did, DidUrl},
+    shared_types::{did_document_metadata::DidDocumentMetadata, media_type::MediaType},
+    traits::{
+        dereferenceable::{
+            dereferencing_error::DidDereferencingError,
+            dereferencing_metadata::DidDereferencingMetadata,
+        },
+        resolvable::{
+            resolution_error::DidResolutionError, resolution_metadata::DidResolutionMetadata,
+            resolution_options::DidResolutionOptions, resolution_output::DidResolutionOutput,
+        },
+    },
+};
+use did_resolver_registry::{GenericMap, ResolverRegistry};
+use serde_json::{json, Value};
+
+use crate::{
+    dereferencing::{dereference_did_document, DereferencedResource},
+    error::{dereferencing_status, resolution_error, resolution_status},
+};
+
+pub const RESOLUTION_RESULT_MEDIA_TYPE: &str =
+    "application/ld+json;profile=\"https://w3id.org/did-resolution\"";
+pub const DEREFERENCING_RESULT_MEDIA_TYPE: &str =
+    "application/ld+json;profile=\"https://w3id.org/did-url-dereferencing\"";
+
+/// Representation of the response, as negotiated by the `Accept` header of the request.
+#[derive(Clone, Debug, PartialEq)]
+enum Representation {
+    /// DID resolution or dereferencing result, with the document as JSON-LD.
+    Result,
+    /// DID document or dereferenced resource alone.
+    Content(MediaType),
+}
+
+fn negotiate_representation(headers: &HeaderMap) -> Option<Representation> {
+    let Some(accept) = headers.get(ACCEPT) else {
+        return Some(Representation::Result);
+    };
+    accept
+        .to_str()
+        .unwrap_or_default()
+        .split(',')
+        .find_map(|media_range| {
+            let media_type = media_range.split(';').next().unwrap_or_default().trim();
+            match media_type {
+                "application/did+json" => Some(Representation::Content(MediaType::DidJson)),
+                "application/did+ld+json" => Some(Representation::Content(MediaType::DidLdJson)),
+                "application/ld+json" | "application/json" | "application/*" | "*/*" => {
+                    Some(Representation::Result)
+                }
+                _ => None,
+            }
+        })
+}
+
+fn resolution_options(did_url: &DidUrl) -> Option<DidResolutionOptions<GenericMap>> {
+    let queries = did_url.queries();
+    let mut options = DidResolutionOptions::default();
+    if let Some(version_id) = queries.get("versionId") {
+        options = options.set_version_id(version_id.to_string());
+    }
+    if let Some(version_time) = queries.get("versionTime") {
+        let version_time = version_time.parse().ok()?;
+        options = options.set_version_time(version_time);
+    }
+    Some(options)
+}
+
+/// Whether the DID URL identifies the DID document itself, possibly a version of it.
+fn is_plain_did(did_url: &DidUrl) -> bool {
+    did_url.path().is_none()
+        && did_url.fragment().is_none()
+        && did_url
+            .queries()
+            .keys()
+            .all(|key| key == "versionId" || key == "versionTime")
+}
+
+fn document_representation(
+    output: &DidResolutionOutput<GenericMap>,
+    media_type: &MediaType,
+) -> Value {
+    let did_document = match media_type {
+        MediaType::DidLdJson => output.did_document().clone().with_json_ld_context(),
+        _ => output.did_document().clone(),
+    };
+    serde_json::to_value(did_document).unwrap_or_default()
+}
+
+fn with_content_type(status: StatusCode, content_type: &str, body: Value) -> Response {
+    (
+        status,
+        [(CONTENT_TYPE, content_type.to_string())],
+        Json(body),
+    )
+        .into_response()
+}
+
+fn resolution_failure(error: DidResolutionError) -> Response {
+    let status = resolution_status(&error);
+    let body = json!({
+        "didDocument": null,
+        "didResolutionMetadata": DidResolutionMetadata::builder().error(error).build(),
+        "didDocumentMetadata": DidDocumentMetadata::default(),
+    });
+    with_content_type(status, RESOLUTION_RESULT_MEDIA_TYPE, body)
+}
+
+fn dereferencing_failure(error: DidDereferencingError) -> Response {
+    let status = dereferencing_status(&error);
+    let body = json!({
+        "contentStream": null,
+        "dereferencingMetadata": DidDereferencingMetadata::builder().error(error).build(),
+        "contentMetadata": DidDocumentMetadata::default(),
+    });
+    with_content_type(status, DEREFERENCING_RESULT_MEDIA_TYPE, body)
+}
+
+fn resolution_response(
+    output: &DidResolutionOutput<GenericMap>,
+    representation: Representation,
+) -> Response {
+    match representation {
+        Representation::Result => {
+            let media_type = MediaType::DidLdJson;
+            let mut metadata =
+                DidResolutionMetadata::builder().content_type(media_type.to_string());
+            if let Some(resolver) = output.did_resolution_metadata().resolver() {
+                metadata = metadata.resolver(resolver.to_string());
+            }
+            let body = json!({
+                "didDocument": document_representation(output, &media_type),
+                "didResolutionMetadata": metadata.build(),
+                "didDocumentMetadata": output.did_document_metadata(),
+            });
+            with_content_type(StatusCode::OK, RESOLUTION_RESULT_MEDIA_TYPE, body)
+        }
+        Representation::Content(media_type) => with_content_type(
+            StatusCode::OK,
+            &media_type.to_string(),
+            document_representation(output, &media_type),
+        ),
+    }
+}
+
+fn dereferencing_response(
+    output: &DidResolutionOutput<GenericMap>,
+    did_url: &DidUrl,
+    representation: Representation,
+) -> Response {
+    let resource = match dereference_did_document(output.did_document(), did_url) {
+        Ok(DereferencedResource::Resource(resource)) => resource,
+        Ok(DereferencedResource::ServiceEndpoint(endpoint)) => {
+            return (StatusCode::SEE_OTHER, [(LOCATION, endpoint)]).into_response()
+        }
+        Err(err) => return dereferencing_failure(err),
+    };
+    match representation {
+        Representation::Result => {
+            let body = json!({
+                "contentStream": resource,
+                "dereferencingMetadata": DidDereferencingMetadata::builder()
+                    .content_type(MediaType::DidLdJson.to_string())
+                    .build(),
+                "contentMetadata": output.did_document_metadata(),
+            });
+            with_content_type(StatusCode::OK, DEREFERENCING_RESULT_MEDIA_TYPE, body)
+        }
+        Representation::Content(media_type) => {
+            with_content_type(StatusCode::OK, &media_type.to_string(), resource)
+        }
+    }
+}
+
+/// Resolves a DID, or dereferences a DID URL, as per the Universal Resolver HTTP API.
+pub async fn resolve_identifier(
+    State(registry): State<Arc<ResolverRegistry>>,
+    Path(identifier): Path<String>,
+    RawQuery(query): RawQuery,
+    headers: HeaderMap,
+) -> Response {
+    let Some(representation) = negotiate_representation(&headers) else {
+        return resolution_failure(DidResolutionError::RepresentationNotSupported);
+    };
+    let identifier = match query {
+        Some(query) => format!("{identifier}?{query}"),
+        None => identifier,
+    };
+    let Ok(did_url) = DidUrl::parse(identifier) else {
+        return resolution_failure(DidResolutionError::InvalidDid);
+    };
+    let (Some(did), Some(options)) = (
+        did_url
+            .did()
+            .and_then(|did| Did::parse(did.to_string()).ok()),
+        resolution_options(&did_url),
+    ) else {
+        return resolution_failure(DidResolutionError::InvalidDid);
+    };
+
+    match registry.resolve(&did, &options).await {
+        Ok(output) if is_plain_did(&did_url) => resolution_response(&output, representation),
+        Ok(output) => dereferencing_response(&output, &did_url, representation),
+        Err(err) => resolution_failure(resolution_error(&err)),
+    }
+}
+
+pub fn build_router(registry: ResolverRegistry) -> Router {
+    Router::default()
+        .route("/1.0/identifiers/*identifier", get(resolve_identifier))
+        .layer(tower_http::catch_panic::CatchPanicLayer::new())
+        .with_state(Arc::new(registry))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request};
+    use did_jwk::{resolver::DidJwkResolver, DidJwk};
+    use public_key::{Key, KeyType};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn did_jwk() -> DidJwk {
+        DidJwk::try_from(Key::new(vec![9u8; 32], KeyType::Ed25519).unwrap()).unwrap()
+    }
+
+    async fn get(uri: &str, accept: Option<&str>) -> (StatusCode, HeaderMap, Value) {
+        let router = build_router(
+            ResolverRegistry::new().register_resolver("jwk".to_string(), DidJwkResolver::new()),
+        );
+        let mut request = Request::builder().uri(uri);
+        if let Some(accept) = accept {
+            request = request.header(ACCEPT, accept);
+        }
+        let response = router
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (
+            status,
+            headers,
+            serde_json::from_slice(&body).unwrap_or_default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_resolve_result() {
+        let did = did_jwk().to_string();
+
+        let (status, headers, body) = get(&format!("/1.0/identifiers/{did}"), None).await;
+
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(RESOLUTION_RESULT_MEDIA_TYPE, headers[CONTENT_TYPE]);
+        assert_eq!(did, body["didDocument"]["id"]);
+        assert_eq!(
+            "application/did+ld+json",
+            body["didResolutionMetadata"]["contentType"]
+        );
+        assert_eq!("DidJwkResolver", body["didResolutionMetadata"]["resolver"]);
+        assert_eq!(
+            "https://www.w3.org/ns/did/v1",
+            body["didDocument"]["@context"][0]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_did_json() {
+        let did = did_jwk().to_string();
+
+        let (status, headers, body) = get(
+            &format!("/1.0/identifiers/{did}"),
+            Some("application/did+json"),
+        )
+        .await;
+
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!("application/did+json", headers[CONTENT_TYPE]);
+        assert_eq!(did, body["id"]);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_errors() {
+        let (status, _, body) = get("/1.0/identifiers/did:example:123", None).await;
+        assert_eq!(StatusCode::NOT_IMPLEMENTED, status);
+        assert_eq!("methodNotSupported", body["didResolutionMetadata"]["error"]);
+
+        let did = did_jwk().to_string();
+        let (status, _, body) = get(&format!("/1.0/identifiers/{did}"), Some("text/html")).await;
+        assert_eq!(StatusCode::NOT_ACCEPTABLE, status);
+        assert_eq!(
+            "representationNotSupported",
+            body["didResolutionMetadata"]["error"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dereference_fragment() {
+        let did = did_jwk().to_string();
+
+        let (status, _, body) = get(
+            &format!("/1.0/identifiers/{did}%230"),
+            Some("application/did+ld+json"),
+        )
+        .await;
+
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(format!("{did}#0"), body["id"]);
+        assert_eq!("JsonWebKey2020", body["type"]);
+
+        let (status, _, body) = get(&format!("/1.0/identifiers/{did}%231"), None).await;
+        assert_eq!(StatusCode::NOT_FOUND, status);
+        assert_eq!("notFound", body["dereferencingMetadata"]["error"]);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod config;
+pub mod dereferencing;
+pub mod error;
+pub mod http_routes;
@@ -0,0 +1,20 @@
+use did_resolver::traits::resolvable::resolution_error::DidResolutionError;
+use hyper::StatusCode;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum UniversalResolverError {
+    #[error("Invalid DID: {0}")]
+    InvalidDid(String),
+    #[error("Invalid universal resolver URL: {0}")]
+    InvalidUrl(#[from] hyper::http::Error),
+    #[error("HTTP error: {0}")]
+    HttpError(#[from] hyper::Error),
+    #[error("Universal resolver failed to resolve the DID: {0}")]
+    ResolutionFailed(DidResolutionError),
+    #[error("Non-success server response: {0}")]
+    NonSuccessResponse(StatusCode),
+    #[error("JSON parsing error: {0}")]
+    JsonError(#[from] serde_json::Error),
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use did_resolver::{
+    did_doc::schema::did_doc::DidDocument,
+    did_parser::Did,
+    error::GenericError,
+    shared_types::did_document_metadata::DidDocumentMetadata,
+    traits::resolvable::{
+        resolution_error::DidResolutionError, resolution_metadata::DidResolutionMetadata,
+        resolution_options::DidResolutionOptions, resolution_output::DidResolutionOutput,
+        DidResolvable,
+    },
+};
+use did_resolver_web::resolution::client::{http_client, https_client};
+use hyper::{
+    client::{connect::Connect, HttpConnector},
+    header::ACCEPT,
+    Body, Client, Request, StatusCode, Uri,
+};
+use hyper_tls::HttpsConnector;
+use serde_json::Value;
+use url::form_urlencoded;
+
+use crate::error::UniversalResolverError;
+
+const RESOLUTION_RESULT_MEDIA_TYPE: &str =
+    "application/ld+json;profile=\"https://w3id.org/did-resolution\"";
+
+/// Resolver delegating resolution of DIDs of any method to a deployment of the DIF Universal
+/// Resolver, e.g. to register as a fallback for methods without a local resolver.
+pub struct UniversalResolver<C>
+where
+    C: Connect + Send + Sync + Clone + 'static,
+{
+    client: Client<C>,
+    base_url: String,
+}
+
+impl UniversalResolver<HttpConnector> {
+    pub fn http(base_url: String) -> UniversalResolver<HttpConnector> {
+        UniversalResolver {
+            client: http_client(),
+            base_url,
+        }
+    }
+}
+
+impl UniversalResolver<HttpsConnector<HttpConnector>> {
+    pub fn https(base_url: String) -> UniversalResolver<HttpsConnector<HttpConnector>> {
+        UniversalResolver {
+            client: https_client(),
+            base_url,
+        }
+    }
+}
+
+impl<C> UniversalResolver<C>
+where
+    C: Connect + Send + Sync + Clone + 'static,
+{
+    fn resolution_url<E>(
+        &self,
+        did: &Did,
+        options: &DidResolutionOptions<E>,
+    ) -> Result<Uri, UniversalResolverError> {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        if let Some(version_id) = options.version_id() {
+            query.append_pair("versionId", version_id);
+        }
+        if let Some(version_time) = options.version_time() {
+            // serialized as RFC 3339, e.g. 2021-05-10T17:00:00Z
+            let version_time = serde_json::to_value(version_time)?;
+            query.append_pair("versionTime", version_time.as_str().unwrap_or_default());
+        }
+        let query = query.finish();
+        let mut url = format!(
+            "{}/1.0/identifiers/{}",
+            self.base_url.trim_end_matches('/'),
+            did.did()
+        );
+        if !query.is_empty() {
+            url = format!("{url}?{query}");
+        }
+        Uri::try_from(url).map_err(|err| hyper::http::Error::from(err).into())
+    }
+
+    async fn fetch_resolution_result(
+        &self,
+        url: Uri,
+    ) -> Result<(StatusCode, Vec<u8>), UniversalResolverError> {
+        let request = Request::get(url)
+            .header(ACCEPT, RESOLUTION_RESULT_MEDIA_TYPE)
+            .body(Body::empty())?;
+        let res = self.client.request(request).await?;
+        let status = res.status();
+        let body = hyper::body::to_bytes(res.into_body()).await?;
+        Ok((status, body.to_vec()))
+    }
+}
+
+/// Parses the response of the universal resolver, which is a DID resolution result, or the bare
+/// DID document for drivers which do not produce one.
+fn parse_resolution_result(
+    status: StatusCode,
+    body: &[u8],
+) -> Result<DidResolutionOutput<HashMap<String, Value>>, UniversalResolverError> {
+    let result: Option<Value> = serde_json::from_slice(body).ok();
+    let error = result.as_ref().and_then(|result| {
+        serde_json::from_value::<DidResolutionError>(
+            result["didResolutionMetadata"]["error"].clone(),
+        )
+        .ok()
+    });
+    match (status.is_success(), result, error) {
+        (_, _, Some(error)) => Err(UniversalResolverError::ResolutionFailed(error)),
+        (true, Some(mut result), None) if result.get("didDocument").is_some() => {
+            let did_document: DidDocument<HashMap<String, Value>> =
+                serde_json::from_value(result["didDocument"].take())?;
+            let did_resolution_metadata: DidResolutionMetadata =
+                serde_json::from_value(result["didResolutionMetadata"].take()).unwrap_or_default();
+            let did_document_metadata: DidDocumentMetadata =
+                serde_json::from_value(result["didDocumentMetadata"].take()).unwrap_or_default();
+            Ok(DidResolutionOutput::builder(did_document)
+                .did_resolution_metadata(did_resolution_metadata)
+                .did_document_metadata(did_document_metadata)
+                .build())
+        }
+        (true, Some(did_document), None) => {
+            Ok(DidResolutionOutput::builder(serde_json::from_value(did_document)?).build())
+        }
+        _ => Err(UniversalResolverError::NonSuccessResponse(status)),
+    }
+}
+
+#[async_trait]
+impl<C> DidResolvable for UniversalResolver<C>
+where
+    C: Connect + Send + Sync + Clone + 'static,
+{
+    type ExtraFieldsService = HashMap<String, Value>;
+    type ExtraFieldsOptions = ();
+
+    async fn resolve(
+        &self,
+        did: &Did,
+        options: &DidResolutionOptions<Self::ExtraFieldsOptions>,
+    ) -> Result<DidResolutionOutput<Self::ExtraFieldsService>, GenericError> {
+        if did.method().is_none() {
+            return Err(Box::new(UniversalResolverError::InvalidDid(
+                "Attempted to resolve unqualified did".to_string(),
+            )));
+        }
+        let (status, body) = self
+            .fetch_resolution_result(self.resolution_url(did, options)?)
+            .await?;
+        Ok(parse_resolution_result(status, &body)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_resolution_url() {
+        let resolver = UniversalResolver::http("http://localhost:8080/".to_string());
+        let did = Did::parse("did:example:123".to_string()).unwrap();
+        let options = DidResolutionOptions::<()>::default().set_version_id("4".to_string());
+
+        assert_eq!(
+            "http://localhost:8080/1.0/identifiers/did:example:123?versionId=4",
+            resolver.resolution_url(&did, &options).unwrap().to_string()
+        );
+
+        let options = DidResolutionOptions::<()>::default()
+            .set_version_id("4 & 5".to_string())
+            .set_version_time(Utc.timestamp_opt(1620666000, 0).unwrap());
+
+        assert_eq!(
+            "http://localhost:8080/1.0/identifiers/did:example:123\
+             ?versionId=4+%26+5&versionTime=2021-05-10T17%3A00%3A00Z",
+            resolver.resolution_url(&did, &options).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_resolution_error() {
+        let body = json!({
+            "didDocument": null,
+            "didResolutionMetadata": { "error": "notFound" },
+            "didDocumentMetadata": {}
+        })
+        .to_string();
+
+        assert!(matches!(
+            parse_resolution_result(StatusCode::NOT_FOUND, body.as_bytes()),
+            Err(UniversalResolverError::ResolutionFailed(
+                DidResolutionError::NotFound
+            ))
+        ));
+        assert!(matches!(
+            parse_resolution_result(StatusCode::BAD_GATEWAY, b"Bad Gateway"),
+            Err(UniversalResolverError::NonSuccessResponse(
+                StatusCode::BAD_GATEWAY
+            ))
+        ));
+    }
+}
@@ -0,0 +1,102 @@
+use std::{convert::Infallible, net::SocketAddr};
+
+use did_resolver::{
+    did_parser::Did,
+    traits::resolvable::{resolution_options::DidResolutionOptions, DidResolvable},
+};
+use did_resolver_universal::{error::UniversalResolverError, resolver::UniversalResolver};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server,
+};
+use tokio_test::assert_ok;
+
+const RESOLUTION_RESULT: &str = r#"
+{
+  "@context": "https://w3id.org/did-resolution/v1",
+  "didDocument": {
+    "@context": ["https://www.w3.org/ns/did/v1"],
+    "id": "did:example:123",
+    "verificationMethod": [
+      {
+        "id": "did:example:123#key-0",
+        "type": "Ed25519VerificationKey2020",
+        "controller": "did:example:123",
+        "publicKeyMultibase": "z6MkmjY8GnV5i9YTDtPETC2uUAW6ejw3nk5mXF5yci5ab7th"
+      }
+    ],
+    "authentication": ["did:example:123#key-0"]
+  },
+  "didResolutionMetadata": {
+    "contentType": "application/did+ld+json",
+    "pattern": "^(did:example:.+)$",
+    "driverUrl": "http://driver-example:8080/1.0/identifiers/",
+    "duration": 12
+  },
+  "didDocumentMetadata": {
+    "updated": "2023-03-01T12:00:00Z"
+  }
+}"#;
+
+const NOT_FOUND_RESULT: &str = r#"
+{
+  "didDocument": null,
+  "didResolutionMetadata": { "error": "notFound" },
+  "didDocumentMetadata": {}
+}"#;
+
+async fn mock_server_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let response = match req.uri().path() {
+        "/1.0/identifiers/did:example:123" => Response::new(Body::from(RESOLUTION_RESULT)),
+        _ => Response::builder()
+            .status(404)
+            .body(Body::from(NOT_FOUND_RESULT))
+            .unwrap(),
+    };
+
+    Ok(response)
+}
+
+async fn create_mock_server(port: u16) -> String {
+    let make_svc =
+        make_service_fn(|_conn| async { Ok::<_, Infallible>(service_fn(mock_server_handler)) });
+
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let server = Server::bind(&addr).serve(make_svc);
+
+    tokio::spawn(async move {
+        server.await.unwrap();
+    });
+
+    format!("http://localhost:{port}")
+}
+
+#[tokio::test]
+async fn test_universal_resolver() {
+    let base_url = create_mock_server(3010).await;
+    let resolver = UniversalResolver::http(base_url);
+
+    let did = Did::parse("did:example:123".to_string()).unwrap();
+    let output = assert_ok!(
+        resolver
+            .resolve(&did, &DidResolutionOptions::default())
+            .await
+    );
+    assert_eq!(&did, output.did_document().id());
+    assert_eq!(1, output.did_document().verification_method().len());
+    assert_eq!(
+        Some(&"application/did+ld+json".to_string()),
+        output.did_resolution_metadata().content_type()
+    );
+    assert!(output.did_document_metadata().updated().is_some());
+
+    let did = Did::parse("did:example:456".to_string()).unwrap();
+    let err = resolver
+        .resolve(&did, &DidResolutionOptions::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<UniversalResolverError>(),
+        Some(UniversalResolverError::ResolutionFailed(_))
+    ));
+}
@@ -264,4 +264,30 @@ mod tests {
             super::strip_multicodec_prefix_if_present_test(key_bytes(), &KeyType::X25519);
         }
     }
+
+    mod secp256k1 {
+        use super::*;
+
+        const TEST_KEY_BASE58: &str = "23o6Sau8NxxzXcgSc3PLcNxrzrZpbLeBn1izfv3jbKhuv";
+        const TEST_FINGERPRINT: &str = "zQ3shokFTS3brHcDQrn82RUDfCZESWL1ZdCEJwekUDPQiYBme";
+
+        fn key_bytes() -> Vec<u8> {
+            bs58::decode(TEST_KEY_BASE58).into_vec().unwrap()
+        }
+
+        #[test]
+        fn fingerprint_test() {
+            super::fingerprint_test(key_bytes(), KeyType::Secp256k1, TEST_FINGERPRINT);
+        }
+
+        #[test]
+        fn from_fingerprint_test() {
+            super::from_fingerprint_test(key_bytes(), KeyType::Secp256k1, TEST_FINGERPRINT);
+        }
+
+        #[test]
+        fn strip_multicodec_prefix_if_present_test() {
+            super::strip_multicodec_prefix_if_present_test(key_bytes(), &KeyType::Secp256k1);
+        }
+    }
 }
@@ -12,9 +12,11 @@ pub enum KeyType {
     P256,
     P384,
     P521,
+    Secp256k1,
 }
 
 impl KeyType {
+    const C_SECP256K1: u64 = 231;
     const C_BLS12381G1: u64 = 234;
     const C_BLS12381G2: u64 = 235;
     const C_X25519: u64 = 236;
@@ -37,6 +39,7 @@ impl From<&KeyType> for u64 {
             KeyType::P256 => KeyType::C_P256,
             KeyType::P384 => KeyType::C_P384,
             KeyType::P521 => KeyType::C_P521,
+            KeyType::Secp256k1 => KeyType::C_SECP256K1,
         }
     }
 }
@@ -54,6 +57,7 @@ impl TryFrom<u64> for KeyType {
             KeyType::C_P256 => Ok(KeyType::P256),
             KeyType::C_P384 => Ok(KeyType::P384),
             KeyType::C_P521 => Ok(KeyType::P521),
+            KeyType::C_SECP256K1 => Ok(KeyType::Secp256k1),
             p => Err(PublicKeyError::UnsupportedMulticodecDescriptor(p)),
         }
     }
@@ -0,0 +1,107 @@
+use std::{
+    io::ErrorKind,
+    net::TcpListener,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use axum::{
+    body::Bytes,
+    extract::{Path as UrlPath, State},
+    http::StatusCode,
+    routing::get,
+    Router,
+};
+use log::{error, info};
+use tokio::io::AsyncWriteExt;
+
+#[derive(Debug)]
+struct TailsStorage {
+    dir: PathBuf,
+}
+
+impl TailsStorage {
+    fn tails_file(&self, rev_reg_id: &str) -> PathBuf {
+        self.dir.join(encode_file_name(rev_reg_id))
+    }
+}
+
+/// Escapes the characters of a revocation registry id which may not be safe in a file name.
+fn encode_file_name(rev_reg_id: &str) -> String {
+    rev_reg_id
+        .bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' => (byte as char).to_string(),
+            _ => format!("_{byte:02X}"),
+        })
+        .collect()
+}
+
+async fn get_tails(
+    State(storage): State<Arc<TailsStorage>>,
+    UrlPath(rev_reg_id): UrlPath<String>,
+) -> Result<Vec<u8>, StatusCode> {
+    match tokio::fs::read(storage.tails_file(&rev_reg_id)).await {
+        Ok(tails) => Ok(tails),
+        Err(err) if err.kind() == ErrorKind::NotFound => Err(StatusCode::NOT_FOUND),
+        Err(err) => {
+            error!("Failed to read tails file of {rev_reg_id}: {err}");
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// Stores the tails file of a revocation registry. Tails files are immutable, so uploading a
+/// file for a registry which already has one is rejected.
+async fn put_tails(
+    State(storage): State<Arc<TailsStorage>>,
+    UrlPath(rev_reg_id): UrlPath<String>,
+    body: Bytes,
+) -> StatusCode {
+    let path = storage.tails_file(&rev_reg_id);
+    let file = tokio::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .await;
+
+    let res = match file {
+        Ok(mut file) => file.write_all(&body).await,
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => return StatusCode::CONFLICT,
+        Err(err) => Err(err),
+    };
+
+    match res {
+        Ok(()) => {
+            info!("Stored tails file of {rev_reg_id}");
+            StatusCode::CREATED
+        }
+        Err(err) => {
+            error!("Failed to store tails file of {rev_reg_id}: {err}");
+            let _ = tokio::fs::remove_file(&path).await;
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+/// Routes serving tails files at `/{rev_reg_id}`, uploaded with `PUT` and downloaded with `GET`.
+pub fn build_router(storage_dir: impl AsRef<Path>) -> std::io::Result<Router> {
+    std::fs::create_dir_all(storage_dir.as_ref())?;
+    let storage = Arc::new(TailsStorage {
+        dir: storage_dir.as_ref().to_owned(),
+    });
+
+    Ok(Router::new()
+        .route("/:rev_reg_id", get(get_tails).put(put_tails))
+        .with_state(storage))
+}
+
+/// Serves the tails files kept in `storage_dir` on the listener.
+pub async fn serve(listener: TcpListener, storage_dir: impl AsRef<Path>) -> std::io::Result<()> {
+    let router = build_router(storage_dir)?;
+    axum::Server::from_tcp(listener)
+        .map_err(|err| std::io::Error::new(ErrorKind::Other, err))?
+        .serve(router.into_make_service())
+        .await
+        .map_err(|err| std::io::Error::new(ErrorKind::Other, err))
+}
@@ -0,0 +1,14 @@
+use std::net::TcpListener;
+
+use log::info;
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
+
+    let address = std::env::var("TAILS_SERVER_ADDRESS").unwrap_or("0.0.0.0:6543".into());
+    let storage_dir = std::env::var("TAILS_SERVER_DIR").unwrap_or("./tails".into());
+    info!("Serving tails files from {storage_dir} on {address}");
+
+    tails_server::serve(TcpListener::bind(address)?, storage_dir).await
+}